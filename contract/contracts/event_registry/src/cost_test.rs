@@ -0,0 +1,47 @@
+//! Resource budget regression tests for the registry hot paths.
+//!
+//! Each test pins the CPU instruction and memory cost of one invocation and
+//! fails when a change pushes it more than `TOLERANCE_PCT` above the baseline.
+//! When a cost rises on purpose, re-measure and update the baseline in the
+//! same change so the increase is reviewed.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+const TOLERANCE_PCT: u64 = 20;
+
+fn assert_within_budget(env: &Env, name: &str, cpu_baseline: u64, mem_baseline: u64) {
+    let budget = env.cost_estimate().budget();
+    let cpu = budget.cpu_instruction_cost();
+    let mem = budget.memory_bytes_cost();
+    assert!(
+        cpu <= cpu_baseline * (100 + TOLERANCE_PCT) / 100,
+        "{name} CPU cost {cpu} regressed past baseline {cpu_baseline}"
+    );
+    assert!(
+        mem <= mem_baseline * (100 + TOLERANCE_PCT) / 100,
+        "{name} memory cost {mem} regressed past baseline {mem_baseline}"
+    );
+}
+
+#[test]
+fn test_register_event_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+
+    let organizer = Address::generate(&env);
+    client.register_event(
+        &String::from_str(&env, "event_001"),
+        &organizer,
+        &organizer,
+        &String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &100,
+    );
+    assert_within_budget(&env, "register_event", 300_000, 100_000);
+}
@@ -16,6 +16,33 @@ pub enum EventRegistryError {
     MaxSupplyExceeded = 10,
     SupplyOverflow = 11,
     UnauthorizedCaller = 12,
+    AddonNotFound = 13,
+    AddonSoldOut = 14,
+    InvalidAddon = 15,
+    InvalidCoHosts = 16,
+    InvalidHold = 17,
+    HoldNotFound = 18,
+    InvalidEventId = 19,
+    InvalidPlan = 20,
+    PlanNotFound = 21,
+    TenantNotFound = 22,
+    InvalidTenant = 23,
+    InvalidReveal = 24,
+    RevealNotFound = 25,
+    RevealNotDue = 26,
+    RevealMismatch = 27,
+    ImportSignerNotSet = 28,
+    InvalidImport = 29,
+    InvalidSupply = 30,
+    EventHasSales = 31,
+    RestoreNotAvailable = 32,
+    TokenDenied = 33,
+    CompletionAttestorNotSet = 34,
+    EventCompleted = 35,
+    InvalidNonce = 36,
+    InvalidTags = 37,
+    FeeNotRecorded = 38,
+    ReservedEventId = 39,
 }
 
 impl core::fmt::Display for EventRegistryError {
@@ -41,7 +68,84 @@ impl core::fmt::Display for EventRegistryError {
                 write!(f, "Supply counter overflow")
             }
             EventRegistryError::UnauthorizedCaller => {
-                write!(f, "Caller is not the authorized TicketPayment contract")
+                write!(
+                    f,
+                    "Caller is not the authorized TicketPayment contract or was revoked"
+                )
+            }
+            EventRegistryError::AddonNotFound => write!(f, "Add-on not found for event"),
+            EventRegistryError::AddonSoldOut => {
+                write!(f, "Add-on has reached its maximum supply")
+            }
+            EventRegistryError::InvalidAddon => {
+                write!(f, "Add-on price, supply or quantity is invalid")
+            }
+            EventRegistryError::InvalidCoHosts => {
+                write!(f, "Co-host list is invalid or shares exceed 100%")
+            }
+            EventRegistryError::InvalidHold => write!(f, "Hold quantity or duration is invalid"),
+            EventRegistryError::HoldNotFound => write!(f, "No active inventory hold"),
+            EventRegistryError::InvalidEventId => {
+                write!(f, "Event ID is empty, too long or has invalid characters")
+            }
+            EventRegistryError::InvalidPlan => {
+                write!(f, "Plan fee, price or period is invalid")
+            }
+            EventRegistryError::PlanNotFound => write!(f, "Subscription plan not found"),
+            EventRegistryError::TenantNotFound => write!(f, "Event ID prefix names no tenant"),
+            EventRegistryError::InvalidTenant => {
+                write!(f, "Tenant ID or fee is invalid, or the tenant exists")
+            }
+            EventRegistryError::InvalidReveal => {
+                write!(
+                    f,
+                    "Reveal already committed or revealed, or tickets already sold"
+                )
+            }
+            EventRegistryError::RevealNotFound => write!(f, "No metadata reveal for event"),
+            EventRegistryError::RevealNotDue => write!(f, "Reveal time has not passed"),
+            EventRegistryError::RevealMismatch => {
+                write!(f, "Revealed CID and salt do not match the commitment")
+            }
+            EventRegistryError::ImportSignerNotSet => write!(f, "Import signer key not configured"),
+            EventRegistryError::InvalidImport => {
+                write!(
+                    f,
+                    "Export comes from this registry or the event already exists"
+                )
+            }
+            EventRegistryError::InvalidSupply => {
+                write!(f, "Supply counts must be non-negative and within the cap")
+            }
+            EventRegistryError::EventHasSales => {
+                write!(f, "Event has sold tickets, add-ons or active holds")
+            }
+            EventRegistryError::RestoreNotAvailable => {
+                write!(
+                    f,
+                    "No deleted event to restore, or its restore window has closed"
+                )
+            }
+            EventRegistryError::TokenDenied => {
+                write!(f, "Token is on the payment contract's denial-list")
+            }
+            EventRegistryError::CompletionAttestorNotSet => {
+                write!(f, "Completion attestor not configured")
+            }
+            EventRegistryError::EventCompleted => {
+                write!(f, "Event has been attested as completed")
+            }
+            EventRegistryError::InvalidNonce => {
+                write!(f, "Admin nonce is stale or out of order")
+            }
+            EventRegistryError::InvalidTags => {
+                write!(f, "Too many, duplicate or malformed event tags")
+            }
+            EventRegistryError::FeeNotRecorded => {
+                write!(f, "No platform fee is recorded for that time")
+            }
+            EventRegistryError::ReservedEventId => {
+                write!(f, "Event ID has the form reserved for auto-generated IDs")
             }
         }
     }
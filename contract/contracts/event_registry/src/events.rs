@@ -1,5 +1,8 @@
-use soroban_sdk::{contracttype, Address, String};
+use crate::types::DeactivationReason;
+use soroban_sdk::{contracttype, Address, BytesN, String};
 
+// Events that affect an organizer carry the organizer's address as the second topic,
+// so organizer notification services can subscribe with one topic filter.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgoraEvent {
@@ -10,6 +13,14 @@ pub enum AgoraEvent {
     ContractUpgraded,
     MetadataUpdated,
     InventoryIncremented,
+    InventoryHeld,
+    InventoryReleased,
+    EventDeactivated,
+    MetadataRevealed,
+    EventImported,
+    EventDeleted,
+    EventRestored,
+    CallerRevoked,
 }
 
 #[contracttype]
@@ -52,6 +63,14 @@ pub struct RegistryUpgradedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallerRevokedEvent {
+    pub caller: Address,
+    pub revoked_by: Address,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MetadataUpdatedEvent {
@@ -69,3 +88,62 @@ pub struct InventoryIncrementedEvent {
     pub max_supply: i128,
     pub timestamp: u64,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InventoryHeldEvent {
+    pub event_id: String,
+    pub buyer: Address,
+    pub quantity: u32,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InventoryReleasedEvent {
+    pub event_id: String,
+    pub quantity: u32,
+    pub new_supply: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventDeactivatedEvent {
+    pub event_id: String,
+    pub reason: DeactivationReason,
+    pub deactivated_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataRevealedEvent {
+    pub event_id: String,
+    pub revealed_cid: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventImportedEvent {
+    pub event_id: String,
+    pub source_registry: Address,
+    pub source_network_id: BytesN<32>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventDeletedEvent {
+    pub event_id: String,
+    pub restore_until: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventRestoredEvent {
+    pub event_id: String,
+    pub timestamp: u64,
+}
@@ -1,18 +1,69 @@
 #![no_std]
 
 use crate::events::{
-    AgoraEvent, EventRegisteredEvent, EventStatusUpdatedEvent, FeeUpdatedEvent,
-    InitializationEvent, InventoryIncrementedEvent, MetadataUpdatedEvent, RegistryUpgradedEvent,
+    AgoraEvent, CallerRevokedEvent, EventDeactivatedEvent, EventDeletedEvent, EventImportedEvent,
+    EventRegisteredEvent, EventRestoredEvent, EventStatusUpdatedEvent, FeeUpdatedEvent,
+    InitializationEvent, InventoryHeldEvent, InventoryIncrementedEvent, InventoryReleasedEvent,
+    MetadataRevealedEvent, MetadataUpdatedEvent, RegistryUpgradedEvent,
+};
+use crate::types::{
+    AddonLineItem, AddonSelection, AddonViolation, BuildInfo, CoHost, DeactivationReason,
+    DeletedEvent, EventAddon, EventExport, EventInfo, FeeChange, InventoryHold, MetadataReveal,
+    OrganizerDashboardEntry, OrganizerSubscription, PaymentInfo, RegistryConfig, RegistryStats,
+    SubscriptionPlan, Tenant, COHOST_CAN_CANCEL, COHOST_CAN_EDIT,
+};
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, token, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal,
+    String, Val, Vec,
 };
-use crate::types::{EventInfo, PaymentInfo};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 pub mod error;
 pub mod events;
+pub mod quantity;
 pub mod storage;
+pub mod supply;
 pub mod types;
 
+// Ticket Payment interface
+pub mod ticket_payment {
+    use crate::types::EventSales;
+    use soroban_sdk::{contractclient, Address, Env, String};
+
+    #[contractclient(name = "Client")]
+    pub trait TicketPaymentInterface {
+        fn get_event_sales(env: Env, event_id: String) -> EventSales;
+        fn is_token_denied(env: Env, token: Address) -> bool;
+    }
+}
+
 use crate::error::EventRegistryError;
+use crate::quantity::Quantity;
+use crate::supply::Supply;
+
+// Keep `binver` in step with the crate version reported by `get_build_info`
+contractmeta!(key = "binver", val = "0.1.0");
+contractmeta!(key = "source_repo", val = "github:utilityjnr/agora");
+
+/// Maximum number of records returned by a single paginated query.
+pub const MAX_PAGE_SIZE: u32 = 50;
+/// Longest a checkout hold may last, in seconds.
+pub const MAX_HOLD_SECS: u64 = 900;
+/// How long a deleted event can be restored, in seconds.
+pub const RESTORE_WINDOW_SECS: u64 = 7 * 86_400;
+/// Approximate ledger close time, used to size temporary storage TTLs.
+const LEDGER_CLOSE_SECS: u64 = 5;
+/// Maximum length of an event ID.
+pub const MAX_EVENT_ID_LEN: u32 = 64;
+/// Maximum length of a metadata CID.
+pub const MAX_CID_LEN: u32 = 128;
+/// Maximum length of a tenant ID.
+pub const MAX_TENANT_ID_LEN: u32 = 16;
+/// Maximum number of tags on an event.
+pub const MAX_EVENT_TAGS: u32 = 5;
+/// Maximum length of an event tag.
+pub const MAX_TAG_LEN: u32 = 16;
+/// Most candidate IDs `register_event_auto_id` tries before giving up.
+pub const MAX_AUTO_ID_ATTEMPTS: u32 = 8;
 
 #[contract]
 pub struct EventRegistry;
@@ -51,6 +102,14 @@ impl EventRegistry {
         storage::set_admin(&env, &admin);
         storage::set_platform_wallet(&env, &platform_wallet);
         storage::set_platform_fee(&env, initial_fee);
+        storage::record_fee_change(
+            &env,
+            &FeeChange {
+                fee_percent: initial_fee,
+                effective_from: env.ledger().timestamp(),
+                changed_by: admin.clone(),
+            },
+        );
         storage::set_initialized(&env, true);
 
         env.events().publish(
@@ -73,6 +132,9 @@ impl EventRegistry {
     /// * `payment_address` - The address where payments should be routed
     /// * `metadata_cid` - IPFS CID for event metadata
     /// * `max_supply` - Maximum number of tickets (0 = unlimited)
+    ///
+    /// IDs of the form `e<8 hex>-<n>` are reserved for
+    /// `register_event_auto_id` and rejected with `ReservedEventId`.
     pub fn register_event(
         env: Env,
         event_id: String,
@@ -81,51 +143,108 @@ impl EventRegistry {
         metadata_cid: String,
         max_supply: i128,
     ) -> Result<(), EventRegistryError> {
-        if !storage::is_initialized(&env) {
-            return Err(EventRegistryError::NotInitialized);
-        }
-        // Verify organizer signature
-        organizer_address.require_auth();
-
-        // Validate metadata CID
-        validate_metadata_cid(&env, &metadata_cid)?;
-
-        // Check if event already exists
-        if storage::event_exists(&env, event_id.clone()) {
-            return Err(EventRegistryError::EventAlreadyExists);
-        }
-
-        // Get current platform fee
-        let platform_fee_percent = storage::get_platform_fee(&env);
-
-        // Create event info with current timestamp
-        let event_info = EventInfo {
-            event_id: event_id.clone(),
-            organizer_address: organizer_address.clone(),
-            payment_address: payment_address.clone(),
-            platform_fee_percent,
-            is_active: true,
-            created_at: env.ledger().timestamp(),
+        store_new_event(
+            &env,
+            event_id,
+            organizer_address,
+            payment_address,
             metadata_cid,
             max_supply,
-            current_supply: 0,
-        };
+            &mut Err,
+        )
+    }
 
-        // Store the event
-        storage::store_event(&env, event_info);
+    /// Registers a new event under an ID derived by the registry, so clients
+    /// never have to pick one or handle collisions. IDs have the form
+    /// `e<8 hex>-<n>`: the hex prefix comes from the organizer's address and
+    /// `n` counts the organizer's auto-ID events. The form is reserved, so
+    /// only another organizer sharing the hex prefix can hold a candidate;
+    /// taken candidates are skipped up to `MAX_AUTO_ID_ATTEMPTS` times.
+    ///
+    /// # Arguments
+    /// * `organizer_address` - The wallet address of the event organizer
+    /// * `payment_address` - The address where payments should be routed
+    /// * `metadata_cid` - IPFS CID for event metadata
+    /// * `max_supply` - Maximum number of tickets (0 = unlimited)
+    ///
+    /// # Returns
+    /// The generated event ID.
+    ///
+    /// # Errors
+    /// * `EventAlreadyExists` - If every candidate tried is taken.
+    pub fn register_event_auto_id(
+        env: Env,
+        organizer_address: Address,
+        payment_address: Address,
+        metadata_cid: String,
+        max_supply: i128,
+    ) -> Result<String, EventRegistryError> {
+        let mut counter = storage::get_organizer_id_counter(&env, &organizer_address);
+        let mut attempts = 0;
+        let event_id = loop {
+            counter += 1;
+            attempts += 1;
+            let candidate = auto_event_id(&env, &organizer_address, counter);
+            if !storage::event_exists(&env, candidate.clone()) {
+                break candidate;
+            }
+            if attempts == MAX_AUTO_ID_ATTEMPTS {
+                return Err(EventRegistryError::EventAlreadyExists);
+            }
+        };
+        storage::set_organizer_id_counter(&env, &organizer_address, counter);
 
-        // Emit registration event using contract event type
-        env.events().publish(
-            (AgoraEvent::EventRegistered,),
-            EventRegisteredEvent {
-                event_id: event_id.clone(),
-                organizer_address: organizer_address.clone(),
-                payment_address: payment_address.clone(),
-                timestamp: env.ledger().timestamp(),
+        store_new_event(
+            &env,
+            event_id.clone(),
+            organizer_address,
+            payment_address,
+            metadata_cid,
+            max_supply,
+            &mut |e| match e {
+                EventRegistryError::ReservedEventId => Ok(()),
+                e => Err(e),
             },
-        );
+        )?;
+        Ok(event_id)
+    }
 
-        Ok(())
+    /// Dry-runs the checks `register_event` applies to its input without
+    /// writing state. Returns the `EventRegistryError` codes of every
+    /// violation found; an empty result means the registration should
+    /// succeed once the organizer signs it.
+    pub fn validate_registration(
+        env: Env,
+        event_id: String,
+        metadata_cid: String,
+        max_supply: i128,
+    ) -> Vec<u32> {
+        let mut violations = Vec::new(&env);
+        let _ = check_registration(&env, &event_id, &metadata_cid, max_supply, &mut |e| {
+            violations.push_back(e as u32);
+            Ok(())
+        });
+        violations
+    }
+
+    /// Returns the version, git commit and build profile this WASM was built
+    /// from. CI sets `AGORA_GIT_COMMIT`; local builds report "unknown".
+    pub fn get_build_info(env: Env) -> BuildInfo {
+        BuildInfo {
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            git_commit: String::from_str(
+                &env,
+                option_env!("AGORA_GIT_COMMIT").unwrap_or("unknown"),
+            ),
+            profile: String::from_str(
+                &env,
+                if cfg!(debug_assertions) {
+                    "debug"
+                } else {
+                    "release"
+                },
+            ),
+        }
     }
 
     /// Get event payment information
@@ -133,50 +252,386 @@ impl EventRegistry {
         env: Env,
         event_id: String,
     ) -> Result<PaymentInfo, EventRegistryError> {
-        match storage::get_event(&env, event_id) {
+        match storage::get_event(&env, event_id.clone()) {
             Some(event_info) => {
                 if !event_info.is_active {
                     return Err(EventRegistryError::EventInactive);
                 }
+                let plan_fee_percent = effective_fee_percent(&env, &event_info.organizer_address);
+                let tenant = event_tenant(&env, &event_id).ok().flatten();
+                let custom_fee =
+                    tenant.is_some() || plan_fee_percent < event_info.platform_fee_percent;
                 Ok(PaymentInfo {
                     payment_address: event_info.payment_address,
-                    platform_fee_percent: event_info.platform_fee_percent,
+                    platform_fee_percent: plan_fee_percent.min(event_info.platform_fee_percent),
+                    metadata_cid: event_info.metadata_cid,
+                    cohosts: storage::get_event_cohosts(&env, event_id.clone()),
+                    accepted_tokens: tenant
+                        .map_or_else(|| Vec::new(&env), |tenant| tenant.accepted_tokens),
+                    created_at: event_info.created_at,
+                    partner_key: storage::get_partner_key(&env, event_id),
+                    custom_fee,
                 })
             }
             None => Err(EventRegistryError::EventNotFound),
         }
     }
 
-    /// Update event status (only by organizer)
+    /// Registers a white-label tenant (only by admin, with the current
+    /// `get_admin_nonce`). Events registered as `<tenant_id>:<name>` then use
+    /// the tenant's fee and accepted tokens.
+    ///
+    /// # Errors
+    /// * `InvalidNonce` - If `nonce` is not the current admin nonce.
+    /// * `InvalidTenant` - If the ID is not 1..=MAX_TENANT_ID_LEN lowercase
+    ///   letters and digits, the fee exceeds 10000 bps, or the tenant exists.
+    /// * `TokenDenied` - If an accepted token is on the denial-list.
+    pub fn register_tenant(env: Env, tenant: Tenant, nonce: u64) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        validate_tenant_id(&tenant.tenant_id)?;
+        if tenant.platform_fee_percent > 10000
+            || storage::get_tenant(&env, tenant.tenant_id.clone()).is_some()
+        {
+            return Err(EventRegistryError::InvalidTenant);
+        }
+        ensure_tokens_not_denied(&env, &tenant.accepted_tokens)?;
+        storage::set_tenant(&env, &tenant);
+        Ok(())
+    }
+
+    /// Updates a tenant's fee and accepted tokens (only by the tenant admin).
+    /// The fee applies to events registered afterwards; token changes apply
+    /// to purchases immediately.
+    ///
+    /// # Errors
+    /// * `TenantNotFound` - If no tenant with `tenant_id` exists.
+    /// * `InvalidTenant` - If the fee exceeds 10000 bps.
+    /// * `TokenDenied` - If an accepted token is on the denial-list.
+    pub fn update_tenant(
+        env: Env,
+        tenant_id: String,
+        platform_fee_percent: u32,
+        accepted_tokens: Vec<Address>,
+    ) -> Result<(), EventRegistryError> {
+        let mut tenant =
+            storage::get_tenant(&env, tenant_id).ok_or(EventRegistryError::TenantNotFound)?;
+        tenant.admin.require_auth();
+        if platform_fee_percent > 10000 {
+            return Err(EventRegistryError::InvalidTenant);
+        }
+        ensure_tokens_not_denied(&env, &accepted_tokens)?;
+        tenant.platform_fee_percent = platform_fee_percent;
+        tenant.accepted_tokens = accepted_tokens;
+        storage::set_tenant(&env, &tenant);
+        Ok(())
+    }
+
+    /// Get a tenant by ID
+    pub fn get_tenant(env: Env, tenant_id: String) -> Option<Tenant> {
+        storage::get_tenant(&env, tenant_id)
+    }
+
+    /// Creates or replaces a subscription plan (only by admin, with the
+    /// current `get_admin_nonce`). Organizers on an active plan pay its fee
+    /// instead of their events' fee, when lower.
+    ///
+    /// # Errors
+    /// * `InvalidNonce` - If `nonce` is not the current admin nonce.
+    /// * `InvalidPlan` - If the fee exceeds 10000 bps or the price or period is zero.
+    pub fn set_subscription_plan(
+        env: Env,
+        plan: SubscriptionPlan,
+        nonce: u64,
+    ) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        if plan.fee_percent > 10000 || plan.price <= 0 || plan.period_secs == 0 {
+            return Err(EventRegistryError::InvalidPlan);
+        }
+        storage::set_subscription_plan(&env, &plan);
+        Ok(())
+    }
+
+    /// Get a subscription plan by ID
+    pub fn get_subscription_plan(env: Env, plan_id: u32) -> Option<SubscriptionPlan> {
+        storage::get_subscription_plan(&env, plan_id)
+    }
+
+    /// Pays for one period of a plan, transferring its price from the organizer
+    /// to the platform wallet. Renewing the current plan extends it from its
+    /// expiry; switching plans starts the new one now. Plans are never renewed
+    /// automatically.
+    ///
+    /// # Errors
+    /// * `PlanNotFound` - If no plan with `plan_id` exists.
+    pub fn subscribe(
+        env: Env,
+        organizer: Address,
+        plan_id: u32,
+    ) -> Result<OrganizerSubscription, EventRegistryError> {
+        organizer.require_auth();
+        let plan = storage::get_subscription_plan(&env, plan_id)
+            .ok_or(EventRegistryError::PlanNotFound)?;
+        let platform_wallet =
+            storage::get_platform_wallet(&env).ok_or(EventRegistryError::NotInitialized)?;
+
+        token::Client::new(&env, &plan.token).transfer(&organizer, &platform_wallet, &plan.price);
+
+        let now = env.ledger().timestamp();
+        let starts_at = match storage::get_organizer_subscription(&env, &organizer) {
+            Some(current) if current.plan_id == plan_id && current.expires_at > now => {
+                current.expires_at
+            }
+            _ => now,
+        };
+        let subscription = OrganizerSubscription {
+            plan_id,
+            expires_at: starts_at.saturating_add(plan.period_secs),
+        };
+        storage::set_organizer_subscription(&env, &organizer, &subscription);
+        Ok(subscription)
+    }
+
+    /// Get an organizer's subscription, including an expired one
+    pub fn get_organizer_subscription(
+        env: Env,
+        organizer: Address,
+    ) -> Option<OrganizerSubscription> {
+        storage::get_organizer_subscription(&env, &organizer)
+    }
+
+    /// Get the live metadata CID of an event, whether or not it is active
+    pub fn get_event_metadata(env: Env, event_id: String) -> Result<String, EventRegistryError> {
+        storage::get_event(&env, event_id)
+            .map(|event_info| event_info.metadata_cid)
+            .ok_or(EventRegistryError::EventNotFound)
+    }
+
+    /// Update event status (only by organizer). Deactivating this way records
+    /// no reason; prefer `deactivate_event`. An event suspended by the
+    /// platform can only be reactivated by the admin, and a completed event
+    /// not at all.
     pub fn update_event_status(
         env: Env,
         event_id: String,
         is_active: bool,
     ) -> Result<(), EventRegistryError> {
-        match storage::get_event(&env, event_id.clone()) {
-            Some(mut event_info) => {
-                // Verify organizer signature
-                event_info.organizer_address.require_auth();
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
 
-                // Update status
-                event_info.is_active = is_active;
-                storage::store_event(&env, event_info.clone());
+        let reason = storage::get_deactivation_reason(&env, event_id.clone());
+        if reason == Some(DeactivationReason::Completed) {
+            return Err(EventRegistryError::EventCompleted);
+        }
+        let updated_by = if reason == Some(DeactivationReason::SuspendedByPlatform) {
+            let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+            admin.require_auth();
+            admin
+        } else {
+            // Verify organizer signature
+            event_info.organizer_address.require_auth();
+            event_info.organizer_address.clone()
+        };
+        if !is_active {
+            require_cohost_approval(&env, event_id.clone(), COHOST_CAN_CANCEL);
+        }
 
-                // Emit status update event using contract event type
-                env.events().publish(
-                    (AgoraEvent::EventStatusUpdated,),
-                    EventStatusUpdatedEvent {
-                        event_id,
-                        is_active,
-                        updated_by: event_info.organizer_address,
-                        timestamp: env.ledger().timestamp(),
-                    },
-                );
+        set_event_active(&env, &mut event_info, is_active, None);
 
-                Ok(())
+        // Emit status update event using contract event type
+        env.events().publish(
+            event_topics(
+                &env,
+                &event_id,
+                (
+                    AgoraEvent::EventStatusUpdated,
+                    event_info.organizer_address.clone(),
+                ),
+            ),
+            EventStatusUpdatedEvent {
+                event_id,
+                is_active,
+                updated_by,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Stops sales for an event and records why, so downstream logic (refund
+    /// eligibility, resale) can tell a sell-out from a cancellation.
+    /// `SuspendedByPlatform` is reserved for the admin and `Completed` for
+    /// `attest_event_completed`; every other reason requires the organizer
+    /// and co-hosts holding `COHOST_CAN_CANCEL`.
+    pub fn deactivate_event(
+        env: Env,
+        event_id: String,
+        reason: DeactivationReason,
+    ) -> Result<(), EventRegistryError> {
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        if storage::get_deactivation_reason(&env, event_id.clone())
+            == Some(DeactivationReason::Completed)
+        {
+            return Err(EventRegistryError::EventCompleted);
+        }
+        if reason == DeactivationReason::Completed {
+            return Err(EventRegistryError::Unauthorized);
+        }
+
+        let deactivated_by = if reason == DeactivationReason::SuspendedByPlatform {
+            let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+            admin.require_auth();
+            admin
+        } else {
+            // Organizers cannot overwrite a platform suspension
+            if storage::get_deactivation_reason(&env, event_id.clone())
+                == Some(DeactivationReason::SuspendedByPlatform)
+            {
+                return Err(EventRegistryError::Unauthorized);
             }
-            None => Err(EventRegistryError::EventNotFound),
+            event_info.organizer_address.require_auth();
+            require_cohost_approval(&env, event_id.clone(), COHOST_CAN_CANCEL);
+            event_info.organizer_address.clone()
+        };
+
+        set_event_active(&env, &mut event_info, false, Some(reason));
+
+        env.events().publish(
+            event_topics(
+                &env,
+                &event_id,
+                (
+                    AgoraEvent::EventDeactivated,
+                    event_info.organizer_address.clone(),
+                    reason,
+                ),
+            ),
+            EventDeactivatedEvent {
+                event_id,
+                reason,
+                deactivated_by,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Deletes an event that has made no sales, freeing its ID for reuse.
+    /// The event, its add-ons, co-hosts and tags are kept in temporary storage for
+    /// `RESTORE_WINDOW_SECS` so `restore_event` can undo the deletion; after
+    /// that they expire. A deactivation reason or pending metadata reveal is
+    /// not kept.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `Unauthorized` - If the event is suspended by the platform.
+    /// * `EventHasSales` - If tickets or add-ons were sold or are held,
+    ///   including sales recorded by the TicketPayment contract.
+    pub fn delete_event(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        require_cohost_approval(&env, event_id.clone(), COHOST_CAN_CANCEL);
+        if storage::get_deactivation_reason(&env, event_id.clone())
+            == Some(DeactivationReason::SuspendedByPlatform)
+        {
+            return Err(EventRegistryError::Unauthorized);
+        }
+
+        let addons = Self::get_event_addons(env.clone(), event_id.clone());
+        if event_info.current_supply > 0
+            || addons.iter().any(|addon| addon.current_supply > 0)
+            || held_quantity(&env, event_id.clone(), None) > 0
+            || tickets_sold(&env, &event_id) > 0
+        {
+            return Err(EventRegistryError::EventHasSales);
+        }
+
+        let restore_until = env.ledger().timestamp() + RESTORE_WINDOW_SECS;
+        let deleted = DeletedEvent {
+            event: event_info.clone(),
+            addons,
+            cohosts: storage::get_event_cohosts(&env, event_id.clone()),
+            partner_key: storage::get_partner_key(&env, event_id.clone()),
+            tags: storage::get_event_tags(&env, event_id.clone()),
+            restore_until,
+        };
+        let ttl_ledgers = (RESTORE_WINDOW_SECS / LEDGER_CLOSE_SECS + 1) as u32;
+        storage::set_deleted_event(&env, event_id.clone(), &deleted, ttl_ledgers);
+        storage::remove_event(&env, &event_info);
+
+        let mut stats = storage::get_registry_stats(&env);
+        stats.total_events = stats.total_events.saturating_sub(1);
+        if event_info.is_active {
+            stats.active_events = stats.active_events.saturating_sub(1);
+        }
+        storage::set_registry_stats(&env, &stats);
+
+        env.events().publish(
+            with_partner_key(
+                &env,
+                deleted.partner_key,
+                (AgoraEvent::EventDeleted, event_info.organizer_address),
+            ),
+            EventDeletedEvent {
+                event_id,
+                restore_until,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Restores an event removed by `delete_event` while its restore window
+    /// is open and its ID has not been registered again.
+    ///
+    /// # Errors
+    /// * `RestoreNotAvailable` - If there is nothing to restore or the window has closed.
+    /// * `EventAlreadyExists` - If the ID was reused since the deletion.
+    pub fn restore_event(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        let deleted = storage::get_deleted_event(&env, event_id.clone())
+            .filter(|deleted| deleted.restore_until >= env.ledger().timestamp())
+            .ok_or(EventRegistryError::RestoreNotAvailable)?;
+        let event_info = deleted.event;
+        event_info.organizer_address.require_auth();
+        if storage::event_exists(&env, event_id.clone()) {
+            return Err(EventRegistryError::EventAlreadyExists);
+        }
+
+        storage::store_event(&env, event_info.clone());
+        for addon in deleted.addons.iter() {
+            storage::store_event_addon(&env, event_id.clone(), &addon);
         }
+        storage::set_event_cohosts(&env, event_id.clone(), &deleted.cohosts);
+        storage::set_partner_key(&env, event_id.clone(), deleted.partner_key);
+        storage::set_event_tags(&env, event_id.clone(), &deleted.tags);
+        storage::remove_deleted_event(&env, event_id.clone());
+
+        let mut stats = storage::get_registry_stats(&env);
+        stats.total_events += 1;
+        if event_info.is_active {
+            stats.active_events += 1;
+        }
+        storage::set_registry_stats(&env, &stats);
+
+        env.events().publish(
+            event_topics(
+                &env,
+                &event_id,
+                (AgoraEvent::EventRestored, event_info.organizer_address),
+            ),
+            EventRestoredEvent {
+                event_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
     }
 
     /// Update the decentralized metadata CID for an event (only by organizer)
@@ -189,6 +644,7 @@ impl EventRegistry {
             Some(mut event_info) => {
                 // Verify organizer signature
                 event_info.organizer_address.require_auth();
+                require_cohost_approval(&env, event_id.clone(), COHOST_CAN_EDIT);
 
                 // Validate new metadata CID
                 validate_metadata_cid(&env, &new_metadata_cid)?;
@@ -199,7 +655,14 @@ impl EventRegistry {
 
                 // Emit metadata update event
                 env.events().publish(
-                    (AgoraEvent::MetadataUpdated,),
+                    event_topics(
+                        &env,
+                        &event_id,
+                        (
+                            AgoraEvent::MetadataUpdated,
+                            event_info.organizer_address.clone(),
+                        ),
+                    ),
                     MetadataUpdatedEvent {
                         event_id,
                         new_metadata_cid,
@@ -214,80 +677,529 @@ impl EventRegistry {
         }
     }
 
-    /// Stores or updates an event (legacy function for backward compatibility).
-    pub fn store_event(env: Env, event_info: EventInfo) {
-        // In a real scenario, we would check authorization here.
-        storage::store_event(&env, event_info);
-    }
-
-    /// Retrieves an event by its ID.
-    pub fn get_event(env: Env, event_id: String) -> Option<EventInfo> {
-        storage::get_event(&env, event_id)
-    }
-
-    /// Checks if an event exists.
-    pub fn event_exists(env: Env, event_id: String) -> bool {
-        storage::event_exists(&env, event_id)
-    }
+    /// Commits to hidden metadata that is revealed after `reveal_at`.
+    /// Must be done before the first ticket sells, so every buyer purchases
+    /// against the same commitment.
+    ///
+    /// # Arguments
+    /// * `event_id` - The event the hidden metadata belongs to
+    /// * `commitment` - sha256 of a 32-byte salt followed by the hidden CID
+    /// * `reveal_at` - Ledger timestamp (seconds) from which it may be revealed
+    pub fn commit_metadata_reveal(
+        env: Env,
+        event_id: String,
+        commitment: BytesN<32>,
+        reveal_at: u64,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        require_cohost_approval(&env, event_id.clone(), COHOST_CAN_EDIT);
 
-    /// Retrieves all event IDs for an organizer.
-    pub fn get_organizer_events(env: Env, organizer: Address) -> Vec<String> {
-        storage::get_organizer_events(&env, &organizer)
+        if event_info.current_supply > 0
+            || reveal_at <= env.ledger().timestamp()
+            || storage::get_metadata_reveal(&env, event_id.clone()).is_some()
+        {
+            return Err(EventRegistryError::InvalidReveal);
+        }
+        storage::set_metadata_reveal(
+            &env,
+            event_id,
+            &MetadataReveal {
+                commitment,
+                reveal_at,
+                revealed_cid: None,
+            },
+        );
+        Ok(())
     }
 
-    /// Updates the platform fee percentage. Only callable by the administrator.
-    pub fn set_platform_fee(env: Env, new_fee_percent: u32) -> Result<(), EventRegistryError> {
-        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
-        admin.require_auth();
-
-        if new_fee_percent > 10000 {
-            return Err(EventRegistryError::InvalidFeePercent);
+    /// Publishes the hidden metadata CID once its reveal time has passed.
+    /// Anyone holding the salt and CID may call it; the contract checks them
+    /// against the commitment, so the organizer cannot swap the lineup.
+    ///
+    /// # Arguments
+    /// * `event_id` - The event whose metadata is revealed
+    /// * `revealed_cid` - The hidden metadata CID
+    /// * `salt` - The salt the commitment was made with
+    pub fn reveal_metadata(
+        env: Env,
+        event_id: String,
+        revealed_cid: String,
+        salt: BytesN<32>,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        let mut reveal = storage::get_metadata_reveal(&env, event_id.clone())
+            .ok_or(EventRegistryError::RevealNotFound)?;
+        if reveal.revealed_cid.is_some() {
+            return Err(EventRegistryError::InvalidReveal);
         }
+        if env.ledger().timestamp() < reveal.reveal_at {
+            return Err(EventRegistryError::RevealNotDue);
+        }
+        validate_metadata_cid(&env, &revealed_cid)?;
 
-        storage::set_platform_fee(&env, new_fee_percent);
+        let mut preimage = Bytes::from_array(&env, &salt.to_array());
+        preimage.append(&revealed_cid.clone().into());
+        if BytesN::from(env.crypto().sha256(&preimage)) != reveal.commitment {
+            return Err(EventRegistryError::RevealMismatch);
+        }
 
-        // Emit fee update event using contract event type
+        reveal.revealed_cid = Some(revealed_cid.clone());
+        storage::set_metadata_reveal(&env, event_id.clone(), &reveal);
         env.events().publish(
-            (AgoraEvent::FeeUpdated,),
-            FeeUpdatedEvent { new_fee_percent },
+            event_topics(
+                &env,
+                &event_id,
+                (AgoraEvent::MetadataRevealed, event_info.organizer_address),
+            ),
+            MetadataRevealedEvent {
+                event_id,
+                revealed_cid,
+                timestamp: env.ledger().timestamp(),
+            },
         );
-
         Ok(())
     }
 
-    /// Returns the current platform fee percentage.
-    pub fn get_platform_fee(env: Env) -> u32 {
-        storage::get_platform_fee(&env)
+    /// Returns the metadata reveal committed for an event, if any.
+    pub fn get_metadata_reveal(env: Env, event_id: String) -> Option<MetadataReveal> {
+        storage::get_metadata_reveal(&env, event_id)
     }
 
-    /// Returns the current administrator address.
-    pub fn get_admin(env: Env) -> Result<Address, EventRegistryError> {
-        storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)
+    /// Sets the oracle allowed to call `attest_event_completed`. Only callable
+    /// by the administrator, with the current `get_admin_nonce`.
+    pub fn set_completion_attestor(
+        env: Env,
+        attestor: Address,
+        nonce: u64,
+    ) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        storage::set_completion_attestor(&env, &attestor);
+        Ok(())
     }
 
-    /// Returns the current platform wallet address.
-    pub fn get_platform_wallet(env: Env) -> Result<Address, EventRegistryError> {
-        storage::get_platform_wallet(&env).ok_or(EventRegistryError::NotInitialized)
+    /// Returns the oracle that attests event completion, if set.
+    pub fn get_completion_attestor(env: Env) -> Option<Address> {
+        storage::get_completion_attestor(&env)
     }
 
-    /// Sets the authorized TicketPayment contract address. Only callable by the administrator.
+    /// Records, on the completion attestor's word, that an event has taken
+    /// place. The event stops selling with reason `Completed` and can't be
+    /// reactivated, so organizers no longer self-report completion.
     ///
-    /// # Arguments
-    /// * `ticket_payment_address` - The address of the TicketPayment contract authorized
-    ///   to call `increment_inventory`.
+    /// # Errors
+    /// * `CompletionAttestorNotSet` - If no attestor is configured.
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventCompleted` - If completion was already attested.
+    pub fn attest_event_completed(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        let attestor = storage::get_completion_attestor(&env)
+            .ok_or(EventRegistryError::CompletionAttestorNotSet)?;
+        attestor.require_auth();
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        if storage::get_deactivation_reason(&env, event_id.clone())
+            == Some(DeactivationReason::Completed)
+        {
+            return Err(EventRegistryError::EventCompleted);
+        }
+
+        let reason = DeactivationReason::Completed;
+        set_event_active(&env, &mut event_info, false, Some(reason));
+        env.events().publish(
+            event_topics(
+                &env,
+                &event_id,
+                (
+                    AgoraEvent::EventDeactivated,
+                    event_info.organizer_address.clone(),
+                    reason,
+                ),
+            ),
+            EventDeactivatedEvent {
+                event_id,
+                reason,
+                deactivated_by: attestor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Sets the ed25519 public key whose signature `import_event` requires
+    /// over an export. Only callable by the administrator, with the current
+    /// `get_admin_nonce`.
+    pub fn set_import_signer(
+        env: Env,
+        public_key: BytesN<32>,
+        nonce: u64,
+    ) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        storage::set_import_signer(&env, &public_key);
+        Ok(())
+    }
+
+    /// Snapshots an event, its add-ons, co-hosts, partner key and tags in the
+    /// canonical `EventExport` form accepted by `import_event` on another
+    /// registry.
+    pub fn export_event(env: Env, event_id: String) -> Result<EventExport, EventRegistryError> {
+        let event =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        Ok(EventExport {
+            source_registry: env.current_contract_address(),
+            network_id: env.ledger().network_id(),
+            event,
+            addons: Self::get_event_addons(env.clone(), event_id.clone()),
+            cohosts: storage::get_event_cohosts(&env, event_id.clone()),
+            partner_key: storage::get_partner_key(&env, event_id.clone()),
+            tags: storage::get_event_tags(&env, event_id),
+            exported_at: env.ledger().timestamp(),
+        })
+    }
+
+    /// Recreates an event exported from another registry deployment. The
+    /// organizer must authorize the import, and `signature` must be the
+    /// import signer's ed25519 signature over the export's XDR, so the
+    /// snapshot cannot be altered in transit. The event goes through the
+    /// same checks as `register_event` and takes this registry's fee (or its
+    /// tenant's); each export can be imported only once.
+    ///
+    /// # Arguments
+    /// * `export` - The `EventExport` returned by the source registry
+    /// * `signature` - Import signer's signature over `export` XDR
+    ///
+    /// # Errors
+    /// * `InvalidImport` - If the export comes from this registry, was
+    ///   already imported, or carries an invalid sold supply.
+    /// * `InvalidTags` - If the exported tags are invalid here.
+    /// * Any error `register_event` returns for the event's ID, metadata CID,
+    ///   supply or tenant.
+    pub fn import_event(
+        env: Env,
+        export: EventExport,
+        signature: BytesN<64>,
+    ) -> Result<(), EventRegistryError> {
+        let signer =
+            storage::get_import_signer(&env).ok_or(EventRegistryError::ImportSignerNotSet)?;
+        let mut event = export.event.clone();
+        event.organizer_address.require_auth();
+        let message = export.clone().to_xdr(&env);
+        let export_hash: BytesN<32> = env.crypto().sha256(&message).into();
+        if export.source_registry == env.current_contract_address()
+            || storage::is_export_imported(&env, &export_hash)
+            || Supply::of_event(&event).is_err()
+            || export
+                .addons
+                .iter()
+                .any(|addon| Supply::of_addon(&addon).is_err())
+        {
+            return Err(EventRegistryError::InvalidImport);
+        }
+        event.platform_fee_percent = check_registration(
+            &env,
+            &event.event_id,
+            &event.metadata_cid,
+            event.max_supply,
+            &mut Err,
+        )?;
+        validate_tags(&export.tags)?;
+        // Traps the invocation if the signature does not match
+        env.crypto().ed25519_verify(&signer, &message, &signature);
+
+        storage::set_export_imported(&env, &export_hash);
+        storage::store_event(&env, event.clone());
+        for addon in export.addons.iter() {
+            storage::store_event_addon(&env, event.event_id.clone(), &addon);
+        }
+        storage::set_event_cohosts(&env, event.event_id.clone(), &export.cohosts);
+        storage::set_partner_key(&env, event.event_id.clone(), export.partner_key);
+        storage::set_event_tags(&env, event.event_id.clone(), &export.tags);
+
+        let mut stats = storage::get_registry_stats(&env);
+        stats.total_events += 1;
+        if event.is_active {
+            stats.active_events += 1;
+        }
+        storage::set_registry_stats(&env, &stats);
+
+        env.events().publish(
+            (AgoraEvent::EventImported, event.organizer_address),
+            EventImportedEvent {
+                event_id: event.event_id,
+                source_registry: export.source_registry,
+                source_network_id: export.network_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Stores or updates an event (legacy function for backward compatibility).
+    pub fn store_event(env: Env, event_info: EventInfo) {
+        // In a real scenario, we would check authorization here.
+        storage::store_event(&env, event_info);
+    }
+
+    /// Retrieves an event by its ID.
+    pub fn get_event(env: Env, event_id: String) -> Option<EventInfo> {
+        storage::get_event(&env, event_id)
+    }
+
+    /// Returns why an event was deactivated, if it was deactivated with a reason.
+    pub fn get_deactivation_reason(env: Env, event_id: String) -> Option<DeactivationReason> {
+        storage::get_deactivation_reason(&env, event_id)
+    }
+
+    /// Checks if an event exists.
+    pub fn event_exists(env: Env, event_id: String) -> bool {
+        storage::event_exists(&env, event_id)
+    }
+
+    /// Retrieves all event IDs for an organizer.
+    pub fn get_organizer_events(env: Env, organizer: Address) -> Vec<String> {
+        storage::get_organizer_events(&env, &organizer)
+    }
+
+    /// Returns a page of an organizer's events as full `EventInfo` records, in
+    /// registration order, so dashboards avoid one read per event. `limit` is
+    /// capped at `MAX_PAGE_SIZE`.
+    pub fn get_organizer_events_detailed(
+        env: Env,
+        organizer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EventInfo> {
+        let event_ids = storage::get_organizer_events(&env, &organizer);
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(event_ids.len());
+        let mut events = Vec::new(&env);
+        for index in offset..end {
+            if let Some(event_info) = storage::get_event(&env, event_ids.get_unchecked(index)) {
+                events.push_back(event_info);
+            }
+        }
+        events
+    }
+
+    /// Returns a page of an organizer's events, each joined with its sales
+    /// totals read live from the Ticket Payment contract, so a dashboard
+    /// needs one simulation rather than a read per event in each contract.
+    /// `limit` is capped at `MAX_PAGE_SIZE`.
+    ///
+    /// Ticket proceeds are paid to the event's payment address at purchase,
+    /// so organizers have nothing escrowed or withdrawable to report.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If no Ticket Payment contract is set.
+    pub fn get_organizer_dashboard(
+        env: Env,
+        organizer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<OrganizerDashboardEntry>, EventRegistryError> {
+        let ticket_payment_addr =
+            storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)?;
+        let payment_client = ticket_payment::Client::new(&env, &ticket_payment_addr);
+
+        let mut entries = Vec::new(&env);
+        for event in Self::get_organizer_events_detailed(env.clone(), organizer, offset, limit) {
+            let sales = payment_client.get_event_sales(&event.event_id);
+            entries.push_back(OrganizerDashboardEntry { event, sales });
+        }
+        Ok(entries)
+    }
+
+    /// Returns the running totals of registered and currently active events.
+    pub fn get_registry_stats(env: Env) -> RegistryStats {
+        storage::get_registry_stats(&env)
+    }
+
+    /// Updates the platform fee percentage. Only callable by the fee setter,
+    /// or by the administrator while no fee setter has been assigned, with
+    /// the current `get_fee_nonce`.
+    pub fn set_platform_fee(
+        env: Env,
+        new_fee_percent: u32,
+        nonce: u64,
+    ) -> Result<(), EventRegistryError> {
+        let setter = fee_controller(&env)?;
+        setter.require_auth();
+
+        if new_fee_percent > 10000 {
+            return Err(EventRegistryError::InvalidFeePercent);
+        }
+        consume_fee_nonce(&env, nonce)?;
+
+        storage::set_platform_fee(&env, new_fee_percent);
+        storage::record_fee_change(
+            &env,
+            &FeeChange {
+                fee_percent: new_fee_percent,
+                effective_from: env.ledger().timestamp(),
+                changed_by: setter,
+            },
+        );
+
+        // Emit fee update event using contract event type
+        env.events().publish(
+            (AgoraEvent::FeeUpdated,),
+            FeeUpdatedEvent { new_fee_percent },
+        );
+
+        Ok(())
+    }
+
+    /// Hands control of the platform fee to `fee_setter`, e.g. a governance
+    /// contract. The first assignment is made by the administrator; after
+    /// that only the current fee setter can pass the role on, so the admin
+    /// key no longer controls fees. Takes the current `get_fee_nonce`; each
+    /// new fee setter starts its own nonce at 0.
+    pub fn set_fee_setter(
+        env: Env,
+        fee_setter: Address,
+        nonce: u64,
+    ) -> Result<(), EventRegistryError> {
+        fee_controller(&env)?.require_auth();
+        validate_address(&env, &fee_setter)?;
+        consume_fee_nonce(&env, nonce)?;
+        storage::set_fee_setter(&env, &fee_setter);
+        storage::set_fee_setter_nonce(&env, 0);
+        Ok(())
+    }
+
+    /// Returns the nonce the next fee change, upgrade or role grant must
+    /// carry. Each such call consumes it, so a captured signed invocation
+    /// can't be replayed later.
+    pub fn get_admin_nonce(env: Env) -> u64 {
+        storage::get_admin_nonce(&env)
+    }
+
+    /// Returns the nonce the next `set_platform_fee` or `set_fee_setter` call
+    /// must carry: the fee setter's own nonce once one is assigned, so it
+    /// never shares a counter with the administrator, else `get_admin_nonce`.
+    pub fn get_fee_nonce(env: Env) -> u64 {
+        match storage::get_fee_setter(&env) {
+            Some(_) => storage::get_fee_setter_nonce(&env),
+            None => storage::get_admin_nonce(&env),
+        }
+    }
+
+    /// Returns the address controlling the platform fee, if delegated.
+    pub fn get_fee_setter(env: Env) -> Option<Address> {
+        storage::get_fee_setter(&env)
+    }
+
+    /// Returns the current platform fee percentage.
+    pub fn get_platform_fee(env: Env) -> u32 {
+        storage::get_platform_fee(&env)
+    }
+
+    /// Returns every recorded platform fee change, oldest first.
+    pub fn get_fee_history(env: Env) -> Vec<FeeChange> {
+        storage::get_fee_history(&env)
+    }
+
+    /// Returns the platform fee that applied at `timestamp`. The history
+    /// starts with the fee set at `initialize`.
+    ///
+    /// # Errors
+    /// * `FeeNotRecorded` - If `timestamp` is before the first recorded fee.
+    pub fn get_fee_at(env: Env, timestamp: u64) -> Result<u32, EventRegistryError> {
+        let mut fee = None;
+        for change in storage::get_fee_history(&env).iter() {
+            if change.effective_from > timestamp {
+                break;
+            }
+            fee = Some(change.fee_percent);
+        }
+        fee.ok_or(EventRegistryError::FeeNotRecorded)
+    }
+
+    /// Returns the current administrator address.
+    pub fn get_admin(env: Env) -> Result<Address, EventRegistryError> {
+        storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)
+    }
+
+    /// Returns the full resolved configuration in one read, so operators can
+    /// verify a deployment without querying each setting separately.
+    pub fn get_config(env: Env) -> Result<RegistryConfig, EventRegistryError> {
+        Ok(RegistryConfig {
+            admin: storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?,
+            platform_wallet: storage::get_platform_wallet(&env)
+                .ok_or(EventRegistryError::NotInitialized)?,
+            platform_fee_percent: storage::get_platform_fee(&env),
+            ticket_payment_contract: storage::get_ticket_payment_contract(&env),
+            fee_setter: storage::get_fee_setter(&env),
+            build: Self::get_build_info(env),
+        })
+    }
+
+    /// Returns the current platform wallet address.
+    pub fn get_platform_wallet(env: Env) -> Result<Address, EventRegistryError> {
+        storage::get_platform_wallet(&env).ok_or(EventRegistryError::NotInitialized)
+    }
+
+    /// Sets the authorized TicketPayment contract address. Only callable by the administrator.
+    ///
+    /// # Arguments
+    /// * `ticket_payment_address` - The address of the TicketPayment contract authorized
+    ///   to call `increment_inventory`.
+    /// * `nonce` - The current `get_admin_nonce`.
     pub fn set_ticket_payment_contract(
         env: Env,
         ticket_payment_address: Address,
+        nonce: u64,
     ) -> Result<(), EventRegistryError> {
         let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
         admin.require_auth();
 
         validate_address(&env, &ticket_payment_address)?;
+        if storage::is_caller_revoked(&env, &ticket_payment_address) {
+            return Err(EventRegistryError::UnauthorizedCaller);
+        }
+        consume_admin_nonce(&env, nonce)?;
 
         storage::set_ticket_payment_contract(&env, &ticket_payment_address);
         Ok(())
     }
 
+    /// Kill switch for a compromised payment contract: permanently stops
+    /// `caller` from invoking the inventory and add-on callbacks, even while
+    /// it is still the configured TicketPayment contract, and announces it
+    /// so wallets can warn users. A revoked address can't be set again; point
+    /// the registry at a fresh deployment instead. Only callable by the
+    /// administrator, with the current `get_admin_nonce`.
+    ///
+    /// # Errors
+    /// * `InvalidNonce` - If `nonce` is not the current admin nonce.
+    pub fn revoke_caller(env: Env, caller: Address, nonce: u64) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        storage::revoke_caller(&env, &caller);
+
+        env.events().publish(
+            (AgoraEvent::CallerRevoked, caller.clone()),
+            CallerRevokedEvent {
+                caller,
+                revoked_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns whether `caller` has been revoked with `revoke_caller`.
+    pub fn is_caller_revoked(env: Env, caller: Address) -> bool {
+        storage::is_caller_revoked(&env, &caller)
+    }
+
     /// Returns the authorized TicketPayment contract address.
     pub fn get_ticket_payment_contract(env: Env) -> Result<Address, EventRegistryError> {
         storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)
@@ -300,16 +1212,14 @@ impl EventRegistry {
     /// * `event_id` - The event whose inventory to increment.
     ///
     /// # Errors
-    /// * `UnauthorizedCaller` - If the invoker is not the registered TicketPayment contract.
+    /// * `UnauthorizedCaller` - If the invoker is not the registered TicketPayment contract, or it was revoked.
     /// * `EventNotFound` - If no event with the given ID exists.
     /// * `EventInactive` - If the event is not currently active.
     /// * `MaxSupplyExceeded` - If the event's max supply has been reached (when max_supply > 0).
-    /// * `SupplyOverflow` - If incrementing would cause an i128 overflow.
+    /// * `SupplyOverflow` - If incrementing would overflow the supply counter.
     pub fn increment_inventory(env: Env, event_id: String) -> Result<(), EventRegistryError> {
-        // Verify the caller is the authorized TicketPayment contract
-        let ticket_payment_addr =
-            storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)?;
-        ticket_payment_addr.require_auth();
+        // Verify the caller is the authorized, unrevoked TicketPayment contract
+        authorized_ticket_payment(&env)?.require_auth();
 
         // Retrieve the event
         let mut event_info =
@@ -320,23 +1230,26 @@ impl EventRegistry {
             return Err(EventRegistryError::EventInactive);
         }
 
-        // Check supply limits (max_supply of 0 means unlimited)
-        if event_info.max_supply > 0 && event_info.current_supply >= event_info.max_supply {
-            return Err(EventRegistryError::MaxSupplyExceeded);
-        }
-
-        // Safely increment the supply counter
-        event_info.current_supply = event_info
-            .current_supply
-            .checked_add(1)
-            .ok_or(EventRegistryError::SupplyOverflow)?;
+        // Check supply limits (max_supply of 0 means unlimited), leaving
+        // tickets held by buyers mid-checkout untouched
+        let mut supply = Supply::of_event(&event_info)?;
+        supply.check_available(Quantity::ONE, held_quantity(&env, event_id.clone(), None))?;
+        supply.try_sell(Quantity::ONE)?;
+        event_info.current_supply = supply.sold();
 
         // Persist updated event info using persistent storage
         storage::store_event(&env, event_info.clone());
 
         // Emit inventory incremented event
         env.events().publish(
-            (AgoraEvent::InventoryIncremented,),
+            event_topics(
+                &env,
+                &event_id,
+                (
+                    AgoraEvent::InventoryIncremented,
+                    event_info.organizer_address.clone(),
+                ),
+            ),
             InventoryIncrementedEvent {
                 event_id,
                 new_supply: event_info.current_supply,
@@ -348,11 +1261,465 @@ impl EventRegistry {
         Ok(())
     }
 
-    /// Upgrades the contract to a new WASM hash. Only callable by the administrator.
+    /// Returns `quantity` sold tickets to an event's supply after they are
+    /// burned or refunded, so they can be sold again. Works on inactive events
+    /// too, since cancelled events are refunded. This function is restricted
+    /// to calls from the authorized TicketPayment contract.
+    ///
+    /// # Errors
+    /// * `UnauthorizedCaller` - If the invoker is not the registered TicketPayment contract, or it was revoked.
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `InvalidSupply` - If `quantity` is zero or more than the tickets sold.
+    pub fn release_inventory(
+        env: Env,
+        event_id: String,
+        quantity: u32,
+    ) -> Result<(), EventRegistryError> {
+        authorized_ticket_payment(&env)?.require_auth();
+        let quantity = Quantity::new(quantity).ok_or(EventRegistryError::InvalidSupply)?;
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        let mut supply = Supply::of_event(&event_info)?;
+        supply.release(quantity)?;
+        event_info.current_supply = supply.sold();
+        storage::store_event(&env, event_info.clone());
+
+        env.events().publish(
+            event_topics(
+                &env,
+                &event_id,
+                (
+                    AgoraEvent::InventoryReleased,
+                    event_info.organizer_address.clone(),
+                ),
+            ),
+            InventoryReleasedEvent {
+                event_id,
+                quantity: quantity.get(),
+                new_supply: event_info.current_supply,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Holds `quantity` tickets for `buyer` for `ttl_secs` while they check
+    /// out, replacing any earlier hold. The hold lives in temporary storage and
+    /// simply stops counting against supply once it expires.
+    ///
+    /// # Errors
+    /// * `InvalidHold` - If the quantity is zero or the TTL is outside 1..=MAX_HOLD_SECS.
+    /// * `MaxSupplyExceeded` - If not enough unsold, unheld tickets remain.
+    pub fn hold_inventory(
+        env: Env,
+        event_id: String,
+        buyer: Address,
+        quantity: u32,
+        ttl_secs: u64,
+    ) -> Result<InventoryHold, EventRegistryError> {
+        buyer.require_auth();
+        let count = Quantity::new(quantity).ok_or(EventRegistryError::InvalidHold)?;
+        if ttl_secs == 0 || ttl_secs > MAX_HOLD_SECS {
+            return Err(EventRegistryError::InvalidHold);
+        }
+
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        if !event_info.is_active {
+            return Err(EventRegistryError::EventInactive);
+        }
+        let held_by_others = held_quantity(&env, event_id.clone(), Some(&buyer));
+        Supply::of_event(&event_info)?.check_available(count, held_by_others)?;
+
+        let hold = InventoryHold {
+            quantity: count.get(),
+            expires_at: env.ledger().timestamp() + ttl_secs,
+        };
+        let ttl_ledgers = (ttl_secs / LEDGER_CLOSE_SECS + 1) as u32;
+        storage::set_inventory_hold(&env, event_id.clone(), &buyer, &hold, ttl_ledgers);
+
+        env.events().publish(
+            event_topics(
+                &env,
+                &event_id,
+                (
+                    AgoraEvent::InventoryHeld,
+                    event_info.organizer_address.clone(),
+                ),
+            ),
+            InventoryHeldEvent {
+                event_id,
+                buyer,
+                quantity: count.get(),
+                expires_at: hold.expires_at,
+            },
+        );
+        Ok(hold)
+    }
+
+    /// Returns the buyer's hold if it is still active.
+    pub fn get_inventory_hold(env: Env, event_id: String, buyer: Address) -> Option<InventoryHold> {
+        storage::get_inventory_hold(&env, event_id, &buyer)
+            .filter(|hold| hold.expires_at > env.ledger().timestamp())
+    }
+
+    /// Converts one ticket of a buyer's active hold into sold inventory; the
+    /// TicketPayment contract calls it for each purchase by a buyer holding
+    /// tickets. The hold ends once every held ticket is bought. This function
+    /// is restricted to calls from the authorized TicketPayment contract.
+    ///
+    /// # Errors
+    /// * `HoldNotFound` - If the buyer has no active hold on the event.
+    /// * `EventInactive` - If the event is not currently active.
+    pub fn consume_inventory_hold(
+        env: Env,
+        event_id: String,
+        buyer: Address,
+    ) -> Result<(), EventRegistryError> {
+        authorized_ticket_payment(&env)?.require_auth();
+
+        let hold = Self::get_inventory_hold(env.clone(), event_id.clone(), buyer.clone())
+            .ok_or(EventRegistryError::HoldNotFound)?;
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        if !event_info.is_active {
+            return Err(EventRegistryError::EventInactive);
+        }
+
+        let mut supply = Supply::of_event(&event_info)?;
+        supply.try_sell(Quantity::ONE)?;
+        event_info.current_supply = supply.sold();
+        storage::store_event(&env, event_info.clone());
+        if hold.quantity > 1 {
+            let ttl_ledgers =
+                ((hold.expires_at - env.ledger().timestamp()) / LEDGER_CLOSE_SECS + 1) as u32;
+            let remaining = InventoryHold {
+                quantity: hold.quantity - 1,
+                expires_at: hold.expires_at,
+            };
+            storage::set_inventory_hold(&env, event_id.clone(), &buyer, &remaining, ttl_ledgers);
+        } else {
+            storage::remove_inventory_hold(&env, event_id.clone(), &buyer);
+        }
+
+        env.events().publish(
+            event_topics(
+                &env,
+                &event_id,
+                (
+                    AgoraEvent::InventoryIncremented,
+                    event_info.organizer_address.clone(),
+                ),
+            ),
+            InventoryIncrementedEvent {
+                event_id,
+                new_supply: event_info.current_supply,
+                max_supply: event_info.max_supply,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Registers a new event as a copy of an existing one (only by the source
+    /// event's organizer). Payment routing, metadata, ticket supply and add-ons
+    /// are copied; sold counts start at zero and the current platform fee applies.
+    ///
+    /// # Arguments
+    /// * `source_event_id` - The event to copy.
+    /// * `new_event_id` - Unique identifier for the new event.
+    pub fn clone_event(
+        env: Env,
+        source_event_id: String,
+        new_event_id: String,
+    ) -> Result<(), EventRegistryError> {
+        let source = storage::get_event(&env, source_event_id.clone())
+            .ok_or(EventRegistryError::EventNotFound)?;
+
+        Self::register_event(
+            env.clone(),
+            new_event_id.clone(),
+            source.organizer_address,
+            source.payment_address,
+            source.metadata_cid,
+            source.max_supply,
+        )?;
+
+        for sku in storage::get_event_addon_skus(&env, source_event_id.clone()).iter() {
+            if let Some(mut addon) = storage::get_event_addon(&env, source_event_id.clone(), sku) {
+                addon.current_supply = 0;
+                storage::store_event_addon(&env, new_event_id.clone(), &addon);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the co-hosts of an event. Requires the organizer and every
+    /// current co-host to sign, so nobody can be removed or diluted unilaterally.
+    /// Shares are taken from the organizer amount; the remainder goes to the
+    /// event's payment address.
+    ///
+    /// # Arguments
+    /// * `event_id` - The event to configure.
+    /// * `cohosts` - Co-hosts with their revenue share and `COHOST_CAN_*` permissions.
+    pub fn set_event_cohosts(
+        env: Env,
+        event_id: String,
+        cohosts: Vec<CoHost>,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        for cohost in storage::get_event_cohosts(&env, event_id.clone()).iter() {
+            cohost.address.require_auth();
+        }
+
+        let mut total_bps: u32 = 0;
+        for (i, cohost) in cohosts.iter().enumerate() {
+            validate_address(&env, &cohost.address)?;
+            let duplicate = cohosts
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.address == cohost.address);
+            if duplicate || cohost.address == event_info.organizer_address {
+                return Err(EventRegistryError::InvalidCoHosts);
+            }
+            total_bps = total_bps.saturating_add(cohost.share_bps);
+        }
+        if total_bps > 10000 {
+            return Err(EventRegistryError::InvalidCoHosts);
+        }
+
+        storage::set_event_cohosts(&env, event_id, &cohosts);
+        Ok(())
+    }
+
+    /// Returns the co-hosts of an event.
+    pub fn get_event_cohosts(env: Env, event_id: String) -> Vec<CoHost> {
+        storage::get_event_cohosts(&env, event_id)
+    }
+
+    /// Sets an opaque partner key (only by organizer) that is added as the
+    /// last topic of every contract event about this event, here and in the
+    /// payment contract, so a ticketing partner can filter the stream down
+    /// to its clients. `None` removes it.
+    pub fn set_event_partner_key(
+        env: Env,
+        event_id: String,
+        partner_key: Option<BytesN<32>>,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        storage::set_partner_key(&env, event_id, partner_key);
+        Ok(())
+    }
+
+    /// Returns the partner key of an event, if any.
+    pub fn get_event_partner_key(env: Env, event_id: String) -> Option<BytesN<32>> {
+        storage::get_partner_key(&env, event_id)
+    }
+
+    /// Replaces an event's tags (e.g. `music`, `sports`, `conference`) so
+    /// fans can browse by theme with `get_events_by_tag`. An empty list
+    /// clears them.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `InvalidTags` - If there are more than `MAX_EVENT_TAGS` tags, a tag
+    ///   repeats, or a tag is not 1 to `MAX_TAG_LEN` lowercase letters,
+    ///   digits or hyphens.
+    pub fn set_event_tags(
+        env: Env,
+        event_id: String,
+        tags: Vec<String>,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        require_cohost_approval(&env, event_id.clone(), COHOST_CAN_EDIT);
+        validate_tags(&tags)?;
+
+        storage::set_event_tags(&env, event_id, &tags);
+        Ok(())
+    }
+
+    /// Returns the tags of an event.
+    pub fn get_event_tags(env: Env, event_id: String) -> Vec<String> {
+        storage::get_event_tags(&env, event_id)
+    }
+
+    /// Returns a page of the events carrying `tag`, in the order they were
+    /// tagged. `cursor` is the index to start from; `limit` is capped at
+    /// `MAX_PAGE_SIZE`.
+    pub fn get_events_by_tag(env: Env, tag: String, cursor: u32, limit: u32) -> Vec<EventInfo> {
+        let event_ids = storage::get_tag_events(&env, tag);
+        let end = cursor
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(event_ids.len());
+        let mut events = Vec::new(&env);
+        for index in cursor..end {
+            if let Some(event_info) = storage::get_event(&env, event_ids.get_unchecked(index)) {
+                events.push_back(event_info);
+            }
+        }
+        events
+    }
+
+    /// Creates or updates a purchasable add-on for an event (only by organizer).
+    /// Updating an existing SKU keeps its sold count.
+    ///
+    /// # Arguments
+    /// * `event_id` - The event the add-on belongs to.
+    /// * `sku` - Organizer-chosen identifier, unique within the event.
+    /// * `price` - Unit price in token base units (must be positive).
+    /// * `max_supply` - Maximum units available (0 = unlimited).
+    pub fn set_event_addon(
+        env: Env,
+        event_id: String,
+        sku: String,
+        price: i128,
+        max_supply: i128,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        require_cohost_approval(&env, event_id.clone(), COHOST_CAN_EDIT);
+
+        if price <= 0 || sku.is_empty() {
+            return Err(EventRegistryError::InvalidAddon);
+        }
+
+        let current_supply = storage::get_event_addon(&env, event_id.clone(), sku.clone())
+            .map(|addon| addon.current_supply)
+            .unwrap_or(0);
+        Supply::new(max_supply, current_supply).map_err(|_| EventRegistryError::InvalidAddon)?;
+
+        storage::store_event_addon(
+            &env,
+            event_id,
+            &EventAddon {
+                sku,
+                price,
+                max_supply,
+                current_supply,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns all add-ons configured for an event.
+    pub fn get_event_addons(env: Env, event_id: String) -> Vec<EventAddon> {
+        let mut addons = Vec::new(&env);
+        for sku in storage::get_event_addon_skus(&env, event_id.clone()).iter() {
+            if let Some(addon) = storage::get_event_addon(&env, event_id.clone(), sku) {
+                addons.push_back(addon);
+            }
+        }
+        addons
+    }
+
+    /// Dry-runs `reserve_addons` without writing state and reports, per
+    /// selection, why it would fail and how many units are left.
+    pub fn validate_addon_selection(
+        env: Env,
+        event_id: String,
+        selections: Vec<AddonSelection>,
+    ) -> Vec<AddonViolation> {
+        let mut violations = Vec::new(&env);
+        for selection in selections.iter() {
+            let violation = |error: EventRegistryError, available: i128| AddonViolation {
+                sku: selection.sku.clone(),
+                code: error as u32,
+                requested: selection.quantity,
+                available,
+            };
+            let Some(addon) =
+                storage::get_event_addon(&env, event_id.clone(), selection.sku.clone())
+            else {
+                violations.push_back(violation(EventRegistryError::AddonNotFound, -1));
+                continue;
+            };
+            let available = Supply::of_addon(&addon)
+                .ok()
+                .and_then(|supply| supply.remaining())
+                .map_or(-1, |remaining| remaining as i128);
+            match Quantity::new(selection.quantity) {
+                None => {
+                    violations.push_back(violation(EventRegistryError::InvalidAddon, available))
+                }
+                Some(count) if available >= 0 && count.units() as i128 > available => {
+                    violations.push_back(violation(EventRegistryError::AddonSoldOut, available))
+                }
+                Some(_) => {}
+            }
+        }
+        violations
+    }
+
+    /// Reserves add-on units for a purchase and returns the priced line items.
+    /// This function is restricted to calls from the authorized TicketPayment contract.
+    ///
+    /// # Errors
+    /// * `EventNotFound` / `EventInactive` - If the event can't be sold.
+    /// * `AddonNotFound` - If a requested SKU doesn't exist for the event.
+    /// * `InvalidAddon` - If a requested quantity is zero or its line total overflows.
+    /// * `AddonSoldOut` - If a request exceeds the add-on's remaining supply.
+    pub fn reserve_addons(
+        env: Env,
+        event_id: String,
+        selections: Vec<AddonSelection>,
+    ) -> Result<Vec<AddonLineItem>, EventRegistryError> {
+        authorized_ticket_payment(&env)?.require_auth();
+
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        if !event_info.is_active {
+            return Err(EventRegistryError::EventInactive);
+        }
+
+        let mut line_items = Vec::new(&env);
+        for selection in selections.iter() {
+            let count =
+                Quantity::new(selection.quantity).ok_or(EventRegistryError::InvalidAddon)?;
+            let mut addon = storage::get_event_addon(&env, event_id.clone(), selection.sku.clone())
+                .ok_or(EventRegistryError::AddonNotFound)?;
+            let line_total = count
+                .price_at(addon.price)
+                .ok_or(EventRegistryError::InvalidAddon)?;
+
+            let mut supply = Supply::of_addon(&addon)?;
+            supply.try_sell(count).map_err(|error| match error {
+                EventRegistryError::MaxSupplyExceeded => EventRegistryError::AddonSoldOut,
+                other => other,
+            })?;
+            addon.current_supply = supply.sold();
+            storage::store_event_addon(&env, event_id.clone(), &addon);
+
+            line_items.push_back(AddonLineItem {
+                sku: addon.sku,
+                quantity: count.get(),
+                unit_price: addon.price,
+                line_total,
+            });
+        }
+
+        Ok(line_items)
+    }
+
+    /// Upgrades the contract to a new WASM hash. Only callable by the administrator,
+    /// with the current `get_admin_nonce`.
     /// Performs post-upgrade state verification to ensure critical storage is intact.
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), EventRegistryError> {
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        nonce: u64,
+    ) -> Result<(), EventRegistryError> {
         let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
         admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
 
         env.deployer().update_current_contract_wasm(new_wasm_hash);
 
@@ -372,6 +1739,250 @@ impl EventRegistry {
     }
 }
 
+/// Appends the event's partner key, if one is set, to `topics`.
+fn event_topics(env: &Env, event_id: &String, topics: impl IntoVal<Env, Vec<Val>>) -> Vec<Val> {
+    with_partner_key(env, storage::get_partner_key(env, event_id.clone()), topics)
+}
+
+fn with_partner_key(
+    env: &Env,
+    partner_key: Option<BytesN<32>>,
+    topics: impl IntoVal<Env, Vec<Val>>,
+) -> Vec<Val> {
+    let mut topics = topics.into_val(env);
+    if let Some(partner_key) = partner_key {
+        topics.push_back(partner_key.into_val(env));
+    }
+    topics
+}
+
+/// Rejects settlement tokens on the payment contract's denial-list. Skipped
+/// when no payment contract is set or it doesn't answer the query.
+fn ensure_tokens_not_denied(env: &Env, tokens: &Vec<Address>) -> Result<(), EventRegistryError> {
+    if tokens.is_empty() {
+        return Ok(());
+    }
+    let Some(ticket_payment_addr) = storage::get_ticket_payment_contract(env) else {
+        return Ok(());
+    };
+    let payment_client = ticket_payment::Client::new(env, &ticket_payment_addr);
+    for token in tokens.iter() {
+        if let Ok(Ok(true)) = payment_client.try_is_token_denied(&token) {
+            return Err(EventRegistryError::TokenDenied);
+        }
+    }
+    Ok(())
+}
+
+/// Tickets of an event sold through the payment contract, refunded or not.
+/// Zero when no payment contract is set, as nothing can have been sold.
+fn tickets_sold(env: &Env, event_id: &String) -> u32 {
+    storage::get_ticket_payment_contract(env).map_or(0, |ticket_payment_addr| {
+        ticket_payment::Client::new(env, &ticket_payment_addr)
+            .get_event_sales(event_id)
+            .tickets_sold
+    })
+}
+
+/// Runs the input checks of `register_event`, handing each violation to
+/// `report`, which either records it and carries on or returns it to stop.
+/// Returns the platform fee the event would be registered with.
+fn check_registration(
+    env: &Env,
+    event_id: &String,
+    metadata_cid: &String,
+    max_supply: i128,
+    report: &mut dyn FnMut(EventRegistryError) -> Result<(), EventRegistryError>,
+) -> Result<u32, EventRegistryError> {
+    if !storage::is_initialized(env) {
+        report(EventRegistryError::NotInitialized)?;
+    }
+    if let Err(e) = validate_event_id(event_id) {
+        report(e)?;
+    }
+    if is_auto_event_id(event_id) {
+        report(EventRegistryError::ReservedEventId)?;
+    }
+    if let Err(e) = validate_metadata_cid(env, metadata_cid) {
+        report(e)?;
+    }
+    if let Err(e) = Supply::new(max_supply, 0) {
+        report(e)?;
+    }
+    if storage::event_exists(env, event_id.clone()) {
+        report(EventRegistryError::EventAlreadyExists)?;
+    }
+
+    let tenant = event_tenant(env, event_id).or_else(|e| report(e).map(|_| None))?;
+    match tenant {
+        Some(tenant) => {
+            if let Err(e) = ensure_tokens_not_denied(env, &tenant.accepted_tokens) {
+                report(e)?;
+            }
+            Ok(tenant.platform_fee_percent)
+        }
+        None => Ok(storage::get_platform_fee(env)),
+    }
+}
+
+/// Validates and stores a new event on behalf of its organizer. `report`
+/// decides which `check_registration` findings are fatal.
+#[allow(deprecated)]
+fn store_new_event(
+    env: &Env,
+    event_id: String,
+    organizer_address: Address,
+    payment_address: Address,
+    metadata_cid: String,
+    max_supply: i128,
+    report: &mut dyn FnMut(EventRegistryError) -> Result<(), EventRegistryError>,
+) -> Result<(), EventRegistryError> {
+    if !storage::is_initialized(env) {
+        return Err(EventRegistryError::NotInitialized);
+    }
+    // Verify organizer signature
+    organizer_address.require_auth();
+
+    // Validate the input and get the platform fee, or the tenant's for
+    // namespaced IDs
+    let platform_fee_percent =
+        check_registration(env, &event_id, &metadata_cid, max_supply, report)?;
+
+    // Create event info with current timestamp
+    let event_info = EventInfo {
+        event_id: event_id.clone(),
+        organizer_address: organizer_address.clone(),
+        payment_address: payment_address.clone(),
+        platform_fee_percent,
+        is_active: true,
+        created_at: env.ledger().timestamp(),
+        metadata_cid,
+        max_supply,
+        current_supply: 0,
+    };
+
+    // Store the event
+    storage::store_event(env, event_info);
+
+    let mut stats = storage::get_registry_stats(env);
+    stats.total_events += 1;
+    stats.active_events += 1;
+    storage::set_registry_stats(env, &stats);
+
+    // Emit registration event using contract event type
+    env.events().publish(
+        (AgoraEvent::EventRegistered, organizer_address.clone()),
+        EventRegisteredEvent {
+            event_id: event_id.clone(),
+            organizer_address: organizer_address.clone(),
+            payment_address: payment_address.clone(),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    Ok(())
+}
+
+/// Returns the fee of the organizer's active plan, or `u32::MAX` when there
+/// is none so callers can take the lower of it and the event fee.
+fn effective_fee_percent(env: &Env, organizer: &Address) -> u32 {
+    storage::get_organizer_subscription(env, organizer)
+        .filter(|subscription| subscription.expires_at > env.ledger().timestamp())
+        .and_then(|subscription| storage::get_subscription_plan(env, subscription.plan_id))
+        .map_or(u32::MAX, |plan| plan.fee_percent)
+}
+
+/// Stores a new active state and reason, keeping the active counter in step
+/// with real transitions only.
+fn set_event_active(
+    env: &Env,
+    event_info: &mut EventInfo,
+    is_active: bool,
+    reason: Option<DeactivationReason>,
+) {
+    if event_info.is_active != is_active {
+        let mut stats = storage::get_registry_stats(env);
+        if is_active {
+            stats.active_events += 1;
+        } else {
+            stats.active_events = stats.active_events.saturating_sub(1);
+        }
+        storage::set_registry_stats(env, &stats);
+    }
+
+    event_info.is_active = is_active;
+    storage::store_event(env, event_info.clone());
+    storage::set_deactivation_reason(env, event_info.event_id.clone(), reason);
+}
+
+/// The configured TicketPayment contract, unless it has been revoked.
+fn authorized_ticket_payment(env: &Env) -> Result<Address, EventRegistryError> {
+    let ticket_payment_addr =
+        storage::get_ticket_payment_contract(env).ok_or(EventRegistryError::NotInitialized)?;
+    if storage::is_caller_revoked(env, &ticket_payment_addr) {
+        return Err(EventRegistryError::UnauthorizedCaller);
+    }
+    Ok(ticket_payment_addr)
+}
+
+/// Checks `nonce` against the expected admin nonce and advances it.
+fn consume_admin_nonce(env: &Env, nonce: u64) -> Result<(), EventRegistryError> {
+    let expected = storage::get_admin_nonce(env);
+    if nonce != expected {
+        return Err(EventRegistryError::InvalidNonce);
+    }
+    storage::set_admin_nonce(env, expected + 1);
+    Ok(())
+}
+
+/// Checks `nonce` against `get_fee_nonce` and advances whichever counter it
+/// reads.
+fn consume_fee_nonce(env: &Env, nonce: u64) -> Result<(), EventRegistryError> {
+    if storage::get_fee_setter(env).is_none() {
+        return consume_admin_nonce(env, nonce);
+    }
+    let expected = storage::get_fee_setter_nonce(env);
+    if nonce != expected {
+        return Err(EventRegistryError::InvalidNonce);
+    }
+    storage::set_fee_setter_nonce(env, expected + 1);
+    Ok(())
+}
+
+/// The fee setter if one is assigned, otherwise the administrator.
+fn fee_controller(env: &Env) -> Result<Address, EventRegistryError> {
+    match storage::get_fee_setter(env) {
+        Some(fee_setter) => Ok(fee_setter),
+        None => storage::get_admin(env).ok_or(EventRegistryError::NotInitialized),
+    }
+}
+
+/// Sums the unexpired holds on an event, optionally excluding one buyer.
+fn held_quantity(env: &Env, event_id: String, exclude: Option<&Address>) -> u64 {
+    let now = env.ledger().timestamp();
+    let mut held: u64 = 0;
+    for buyer in storage::get_event_holders(env, event_id.clone()).iter() {
+        if exclude == Some(&buyer) {
+            continue;
+        }
+        if let Some(hold) = storage::get_inventory_hold(env, event_id.clone(), &buyer) {
+            if hold.expires_at > now {
+                held += hold.quantity as u64;
+            }
+        }
+    }
+    held
+}
+
+/// Requires every co-host holding `permission` to have signed the invocation.
+fn require_cohost_approval(env: &Env, event_id: String, permission: u32) {
+    for cohost in storage::get_event_cohosts(env, event_id).iter() {
+        if cohost.permissions & permission != 0 {
+            cohost.address.require_auth();
+        }
+    }
+}
+
 fn validate_address(env: &Env, address: &Address) -> Result<(), EventRegistryError> {
     if address == &env.current_contract_address() {
         return Err(EventRegistryError::InvalidAddress);
@@ -380,7 +1991,7 @@ fn validate_address(env: &Env, address: &Address) -> Result<(), EventRegistryErr
 }
 
 fn validate_metadata_cid(env: &Env, cid: &String) -> Result<(), EventRegistryError> {
-    if cid.len() < 46 {
+    if cid.len() < 46 || cid.len() > MAX_CID_LEN {
         return Err(EventRegistryError::InvalidMetadataCid);
     }
 
@@ -393,8 +2004,173 @@ fn validate_metadata_cid(env: &Env, cid: &String) -> Result<(), EventRegistryErr
         return Err(EventRegistryError::InvalidMetadataCid);
     }
 
+    // The rest must be in the lowercase base32 alphabet (a-z, 2-7)
+    if !bytes
+        .iter()
+        .all(|c| c.is_ascii_lowercase() || (b'2'..=b'7').contains(&c))
+    {
+        return Err(EventRegistryError::InvalidMetadataCid);
+    }
+
+    Ok(())
+}
+
+/// Builds the `counter`-th auto-generated event ID of an organizer:
+/// `e`, the first 4 bytes of sha256(organizer) in hex, `-`, then `counter`.
+fn auto_event_id(env: &Env, organizer: &Address, counter: u64) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let digest = env
+        .crypto()
+        .sha256(&organizer.clone().to_xdr(env))
+        .to_array();
+
+    // 1 + 8 hex digits + '-' + up to 20 decimal digits of a u64
+    let mut buf = [0u8; 30];
+    buf[0] = b'e';
+    for (i, byte) in digest[..4].iter().enumerate() {
+        buf[1 + 2 * i] = HEX[(byte >> 4) as usize];
+        buf[2 + 2 * i] = HEX[(byte & 0x0f) as usize];
+    }
+    buf[9] = b'-';
+
+    let mut digits = [0u8; 20];
+    let mut len = 0;
+    let mut rest = counter;
+    loop {
+        digits[len] = b'0' + (rest % 10) as u8;
+        len += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    for i in 0..len {
+        buf[10 + i] = digits[len - 1 - i];
+    }
+    String::from_bytes(env, &buf[..10 + len])
+}
+
+/// Whether `event_id` has the `e<8 hex>-<n>` form `auto_event_id` builds.
+fn is_auto_event_id(event_id: &String) -> bool {
+    let len = event_id.len() as usize;
+    if !(11..=30).contains(&len) {
+        return false;
+    }
+    let mut buf = [0u8; 30];
+    let buf = &mut buf[..len];
+    event_id.copy_into_slice(buf);
+    buf[0] == b'e'
+        && buf[1..9]
+            .iter()
+            .all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(c))
+        && buf[9] == b'-'
+        && buf[10..].iter().all(u8::is_ascii_digit)
+}
+
+/// Event IDs are 1..=MAX_EVENT_ID_LEN characters of ASCII letters, digits,
+/// '-' and '_', so they stay cheap to store and safe to use as index keys.
+/// A single ':' may separate a tenant prefix from the rest of the ID.
+fn validate_event_id(event_id: &String) -> Result<(), EventRegistryError> {
+    let len = event_id.len();
+    if len == 0 || len > MAX_EVENT_ID_LEN {
+        return Err(EventRegistryError::InvalidEventId);
+    }
+
+    let mut buf = [0u8; MAX_EVENT_ID_LEN as usize];
+    let buf = &mut buf[..len as usize];
+    event_id.copy_into_slice(buf);
+    if !buf
+        .iter()
+        .all(|c| c.is_ascii_alphanumeric() || *c == b'-' || *c == b'_' || *c == b':')
+    {
+        return Err(EventRegistryError::InvalidEventId);
+    }
+    match buf.iter().position(|c| *c == b':') {
+        Some(pos) if pos == 0 || pos == buf.len() - 1 || buf[pos + 1..].contains(&b':') => {
+            Err(EventRegistryError::InvalidEventId)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Tenant IDs are 1..=MAX_TENANT_ID_LEN lowercase ASCII letters and digits.
+fn validate_tenant_id(tenant_id: &String) -> Result<(), EventRegistryError> {
+    let len = tenant_id.len();
+    if len == 0 || len > MAX_TENANT_ID_LEN {
+        return Err(EventRegistryError::InvalidTenant);
+    }
+
+    let mut buf = [0u8; MAX_TENANT_ID_LEN as usize];
+    let buf = &mut buf[..len as usize];
+    tenant_id.copy_into_slice(buf);
+    if !buf
+        .iter()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+        return Err(EventRegistryError::InvalidTenant);
+    }
+    Ok(())
+}
+
+/// Checks an event's tag list: at most `MAX_EVENT_TAGS` distinct tags, each
+/// 1 to `MAX_TAG_LEN` lowercase letters, digits or hyphens.
+fn validate_tags(tags: &Vec<String>) -> Result<(), EventRegistryError> {
+    if tags.len() > MAX_EVENT_TAGS {
+        return Err(EventRegistryError::InvalidTags);
+    }
+
+    for (i, tag) in tags.iter().enumerate() {
+        let len = tag.len();
+        if len == 0 || len > MAX_TAG_LEN || tags.first_index_of(&tag) != Some(i as u32) {
+            return Err(EventRegistryError::InvalidTags);
+        }
+
+        let mut buf = [0u8; MAX_TAG_LEN as usize];
+        let buf = &mut buf[..len as usize];
+        tag.copy_into_slice(buf);
+        if !buf
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-')
+        {
+            return Err(EventRegistryError::InvalidTags);
+        }
+    }
     Ok(())
 }
 
+/// Resolves the tenant a namespaced event ID belongs to; plain IDs have none.
+///
+/// # Errors
+/// * `TenantNotFound` - If the ID has a prefix that names no tenant.
+fn event_tenant(env: &Env, event_id: &String) -> Result<Option<Tenant>, EventRegistryError> {
+    let len = event_id.len();
+    if len == 0 || len > MAX_EVENT_ID_LEN {
+        return Ok(None);
+    }
+
+    let mut buf = [0u8; MAX_EVENT_ID_LEN as usize];
+    let buf = &mut buf[..len as usize];
+    event_id.copy_into_slice(buf);
+    let Some(pos) = buf.iter().position(|c| *c == b':') else {
+        return Ok(None);
+    };
+    let tenant_id = String::from_bytes(env, &buf[..pos]);
+    storage::get_tenant(env, tenant_id)
+        .map(Some)
+        .ok_or(EventRegistryError::TenantNotFound)
+}
+
 #[cfg(test)]
 mod test;
+
+#[cfg(test)]
+mod cost_test;
+
+#[cfg(test)]
+mod spec_test;
+
+#[cfg(test)]
+mod supply_test;
+
+#[cfg(test)]
+mod quantity_test;
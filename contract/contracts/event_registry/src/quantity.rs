@@ -0,0 +1,37 @@
+//! Ticket and add-on counts.
+//!
+//! Counts cross the contract interface as plain `u32` (hold sizes, add-on
+//! selections), next to prices and supplies held as `i128`. Inside the
+//! contract they are wrapped in [`Quantity`] as soon as they are read, so a
+//! count can't be passed where a token amount is expected, and turning a
+//! count into money always goes through [`Quantity::price_at`].
+
+/// A non-zero number of tickets or add-on units.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Quantity(u32);
+
+impl Quantity {
+    pub const ONE: Quantity = Quantity(1);
+
+    /// Wraps a requested count, or returns `None` for zero so each caller can
+    /// report its own error.
+    pub fn new(count: u32) -> Option<Self> {
+        (count > 0).then_some(Self(count))
+    }
+
+    /// The count as passed across the contract interface.
+    pub fn get(self) -> u32 {
+        self.0
+    }
+
+    /// The count in the unit [`Supply`](crate::supply::Supply) tracks.
+    pub fn units(self) -> u64 {
+        self.0 as u64
+    }
+
+    /// Total price of this many units at `unit_price` token base units, or
+    /// `None` on overflow.
+    pub fn price_at(self, unit_price: i128) -> Option<i128> {
+        unit_price.checked_mul(self.0 as i128)
+    }
+}
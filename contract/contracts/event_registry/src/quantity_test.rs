@@ -0,0 +1,16 @@
+use crate::quantity::Quantity;
+
+#[test]
+fn test_quantity_rejects_zero() {
+    assert_eq!(Quantity::new(0), None);
+    assert_eq!(Quantity::new(3).map(Quantity::get), Some(3));
+    assert_eq!(Quantity::ONE.units(), 1);
+}
+
+#[test]
+fn test_quantity_prices_units() {
+    let quantity = Quantity::new(4).unwrap();
+    assert_eq!(quantity.price_at(250), Some(1000));
+    assert_eq!(quantity.price_at(0), Some(0));
+    assert_eq!(quantity.price_at(i128::MAX), None);
+}
@@ -0,0 +1,153 @@
+//! Checks the exported contract spec that TypeScript and Rust bindings are
+//! generated from: every public entrypoint must be in it, with its docs.
+
+extern crate std;
+
+use crate::types::EventInfo;
+use crate::EventRegistry;
+use soroban_sdk::xdr::{Limits, ReadXdr, ScSpecEntry, ScSpecFunctionV0, ScSpecTypeDef};
+use std::{string::ToString, vec::Vec};
+
+fn decode(spec_xdr: &[u8]) -> ScSpecEntry {
+    ScSpecEntry::from_xdr(spec_xdr, Limits::none()).unwrap()
+}
+
+macro_rules! decode_all {
+    ($($spec:expr),* $(,)?) => {
+        [$(decode(&$spec)),*]
+    };
+}
+
+fn exported_functions() -> Vec<ScSpecFunctionV0> {
+    decode_all![
+        EventRegistry::spec_xdr_initialize(),
+        EventRegistry::spec_xdr_register_event(),
+        EventRegistry::spec_xdr_register_event_auto_id(),
+        EventRegistry::spec_xdr_validate_registration(),
+        EventRegistry::spec_xdr_get_build_info(),
+        EventRegistry::spec_xdr_get_event_payment_info(),
+        EventRegistry::spec_xdr_register_tenant(),
+        EventRegistry::spec_xdr_update_tenant(),
+        EventRegistry::spec_xdr_get_tenant(),
+        EventRegistry::spec_xdr_set_subscription_plan(),
+        EventRegistry::spec_xdr_get_subscription_plan(),
+        EventRegistry::spec_xdr_subscribe(),
+        EventRegistry::spec_xdr_get_organizer_subscription(),
+        EventRegistry::spec_xdr_get_event_metadata(),
+        EventRegistry::spec_xdr_update_event_status(),
+        EventRegistry::spec_xdr_deactivate_event(),
+        EventRegistry::spec_xdr_delete_event(),
+        EventRegistry::spec_xdr_restore_event(),
+        EventRegistry::spec_xdr_update_metadata(),
+        EventRegistry::spec_xdr_commit_metadata_reveal(),
+        EventRegistry::spec_xdr_reveal_metadata(),
+        EventRegistry::spec_xdr_get_metadata_reveal(),
+        EventRegistry::spec_xdr_set_completion_attestor(),
+        EventRegistry::spec_xdr_get_completion_attestor(),
+        EventRegistry::spec_xdr_attest_event_completed(),
+        EventRegistry::spec_xdr_set_import_signer(),
+        EventRegistry::spec_xdr_export_event(),
+        EventRegistry::spec_xdr_import_event(),
+        EventRegistry::spec_xdr_store_event(),
+        EventRegistry::spec_xdr_get_event(),
+        EventRegistry::spec_xdr_get_deactivation_reason(),
+        EventRegistry::spec_xdr_event_exists(),
+        EventRegistry::spec_xdr_get_organizer_events(),
+        EventRegistry::spec_xdr_get_organizer_events_detailed(),
+        EventRegistry::spec_xdr_get_organizer_dashboard(),
+        EventRegistry::spec_xdr_get_registry_stats(),
+        EventRegistry::spec_xdr_set_platform_fee(),
+        EventRegistry::spec_xdr_set_fee_setter(),
+        EventRegistry::spec_xdr_get_fee_setter(),
+        EventRegistry::spec_xdr_get_admin_nonce(),
+        EventRegistry::spec_xdr_get_fee_nonce(),
+        EventRegistry::spec_xdr_get_platform_fee(),
+        EventRegistry::spec_xdr_get_fee_history(),
+        EventRegistry::spec_xdr_get_fee_at(),
+        EventRegistry::spec_xdr_get_admin(),
+        EventRegistry::spec_xdr_get_config(),
+        EventRegistry::spec_xdr_get_platform_wallet(),
+        EventRegistry::spec_xdr_set_ticket_payment_contract(),
+        EventRegistry::spec_xdr_get_ticket_payment_contract(),
+        EventRegistry::spec_xdr_revoke_caller(),
+        EventRegistry::spec_xdr_is_caller_revoked(),
+        EventRegistry::spec_xdr_increment_inventory(),
+        EventRegistry::spec_xdr_release_inventory(),
+        EventRegistry::spec_xdr_hold_inventory(),
+        EventRegistry::spec_xdr_get_inventory_hold(),
+        EventRegistry::spec_xdr_consume_inventory_hold(),
+        EventRegistry::spec_xdr_clone_event(),
+        EventRegistry::spec_xdr_set_event_cohosts(),
+        EventRegistry::spec_xdr_get_event_cohosts(),
+        EventRegistry::spec_xdr_set_event_partner_key(),
+        EventRegistry::spec_xdr_get_event_partner_key(),
+        EventRegistry::spec_xdr_set_event_tags(),
+        EventRegistry::spec_xdr_get_event_tags(),
+        EventRegistry::spec_xdr_get_events_by_tag(),
+        EventRegistry::spec_xdr_set_event_addon(),
+        EventRegistry::spec_xdr_get_event_addons(),
+        EventRegistry::spec_xdr_validate_addon_selection(),
+        EventRegistry::spec_xdr_reserve_addons(),
+        EventRegistry::spec_xdr_upgrade(),
+    ]
+    .into_iter()
+    .map(|entry| match entry {
+        ScSpecEntry::FunctionV0(function) => function,
+        other => panic!("unexpected spec entry {other:?}"),
+    })
+    .collect()
+}
+
+/// Public entrypoint names declared in the `#[contractimpl]` block.
+fn source_functions() -> Vec<&'static str> {
+    let source = include_str!("lib.rs");
+    let start = source.find("#[contractimpl]").unwrap();
+    let end = start + source[start..].find("\n}\n").unwrap();
+    source[start..end]
+        .lines()
+        .filter_map(|line| line.strip_prefix("    pub fn "))
+        .map(|rest| rest.split(['(', '<']).next().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_spec_exports_every_public_function() {
+    let mut exported: Vec<_> = exported_functions()
+        .iter()
+        .map(|function| function.name.to_string())
+        .collect();
+    let mut declared: Vec<_> = source_functions()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    exported.sort();
+    declared.sort();
+    assert_eq!(exported, declared);
+}
+
+#[test]
+fn test_spec_documents_every_function() {
+    for function in exported_functions() {
+        assert!(
+            !function.doc.is_empty(),
+            "{} has no doc comment in the contract spec",
+            *function.name
+        );
+    }
+}
+
+#[test]
+fn test_spec_documents_event_info_amounts_and_timestamps() {
+    let ScSpecEntry::UdtStructV0(spec) = decode(&EventInfo::spec_xdr()) else {
+        panic!("EventInfo is not exported as a struct");
+    };
+    for field in spec.fields.iter() {
+        if matches!(field.type_, ScSpecTypeDef::I128 | ScSpecTypeDef::U64) {
+            assert!(
+                !field.doc.is_empty(),
+                "EventInfo.{} has no doc comment in the contract spec",
+                field.name
+            );
+        }
+    }
+}
@@ -1,5 +1,8 @@
-use crate::types::{DataKey, EventInfo};
-use soroban_sdk::{Address, Env, String, Vec};
+use crate::types::{
+    CoHost, DataKey, DeactivationReason, DeletedEvent, EventAddon, EventInfo, FeeChange,
+    InventoryHold, MetadataReveal, OrganizerSubscription, RegistryStats, SubscriptionPlan, Tenant,
+};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 /// Sets the administrator address of the contract.
 pub fn set_admin(env: &Env, admin: &Address) {
@@ -118,3 +121,437 @@ pub fn get_ticket_payment_contract(env: &Env) -> Option<Address> {
         .persistent()
         .get(&DataKey::TicketPaymentContract)
 }
+
+/// Permanently bars an address from the registry's callbacks.
+pub fn revoke_caller(env: &Env, caller: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RevokedCaller(caller.clone()), &true);
+}
+
+/// Returns whether an address has been barred from the registry's callbacks.
+pub fn is_caller_revoked(env: &Env, caller: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::RevokedCaller(caller.clone()))
+}
+
+/// Retrieves the nonce the next nonce-protected admin call must carry.
+pub fn get_admin_nonce(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AdminNonce)
+        .unwrap_or(0)
+}
+
+/// Stores the nonce the next nonce-protected admin call must carry.
+pub fn set_admin_nonce(env: &Env, nonce: u64) {
+    env.storage().persistent().set(&DataKey::AdminNonce, &nonce);
+}
+
+/// Retrieves the registry-wide event counters.
+pub fn get_registry_stats(env: &Env) -> RegistryStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RegistryStats)
+        .unwrap_or_default()
+}
+
+/// Updates the registry-wide event counters.
+pub fn set_registry_stats(env: &Env, stats: &RegistryStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RegistryStats, stats);
+}
+
+/// Stores an event add-on, registering its SKU on first write.
+pub fn store_event_addon(env: &Env, event_id: String, addon: &EventAddon) {
+    let key = DataKey::EventAddon(event_id.clone(), addon.sku.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut skus = get_event_addon_skus(env, event_id.clone());
+        skus.push_back(addon.sku.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::EventAddons(event_id), &skus);
+    }
+    env.storage().persistent().set(&key, addon);
+}
+
+/// Retrieves an event add-on by SKU.
+pub fn get_event_addon(env: &Env, event_id: String, sku: String) -> Option<EventAddon> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventAddon(event_id, sku))
+}
+
+/// Retrieves all add-on SKUs registered for an event.
+pub fn get_event_addon_skus(env: &Env, event_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventAddons(event_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Replaces the co-hosts of an event.
+pub fn set_event_cohosts(env: &Env, event_id: String, cohosts: &Vec<CoHost>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EventCoHosts(event_id), cohosts);
+}
+
+/// Retrieves the co-hosts of an event.
+pub fn get_event_cohosts(env: &Env, event_id: String) -> Vec<CoHost> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventCoHosts(event_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores a buyer's inventory hold in temporary storage, keeping it (and the
+/// event's holder list) alive for at least `ttl_ledgers`. Buyers whose holds
+/// have lapsed are dropped from the holder list, so it only grows with live
+/// holds.
+pub fn set_inventory_hold(
+    env: &Env,
+    event_id: String,
+    buyer: &Address,
+    hold: &InventoryHold,
+    ttl_ledgers: u32,
+) {
+    let key = DataKey::InventoryHold(event_id.clone(), buyer.clone());
+    env.storage().temporary().set(&key, hold);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, ttl_ledgers, ttl_ledgers);
+
+    let now = env.ledger().timestamp();
+    let holders_key = DataKey::EventHolders(event_id.clone());
+    let mut holders = Vec::new(env);
+    for holder in get_event_holders(env, event_id.clone()).iter() {
+        let live = get_inventory_hold(env, event_id.clone(), &holder)
+            .is_some_and(|hold| hold.expires_at > now);
+        if live && holder != *buyer {
+            holders.push_back(holder);
+        }
+    }
+    holders.push_back(buyer.clone());
+    env.storage().temporary().set(&holders_key, &holders);
+    env.storage()
+        .temporary()
+        .extend_ttl(&holders_key, ttl_ledgers, ttl_ledgers);
+}
+
+/// Retrieves a buyer's inventory hold, whether or not it has expired.
+pub fn get_inventory_hold(env: &Env, event_id: String, buyer: &Address) -> Option<InventoryHold> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::InventoryHold(event_id, buyer.clone()))
+}
+
+/// Removes a buyer's inventory hold and drops them from the holder list.
+pub fn remove_inventory_hold(env: &Env, event_id: String, buyer: &Address) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::InventoryHold(event_id.clone(), buyer.clone()));
+
+    let mut holders = get_event_holders(env, event_id.clone());
+    if let Some(index) = holders.first_index_of(buyer) {
+        holders.remove(index);
+        env.storage()
+            .temporary()
+            .set(&DataKey::EventHolders(event_id), &holders);
+    }
+}
+
+/// Retrieves the buyers that may currently hold inventory for an event.
+pub fn get_event_holders(env: &Env, event_id: String) -> Vec<Address> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::EventHolders(event_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Appends a platform fee change to the fee history.
+pub fn record_fee_change(env: &Env, change: &FeeChange) {
+    let mut history = get_fee_history(env);
+    history.push_back(change.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeHistory, &history);
+}
+
+/// Retrieves all recorded platform fee changes, oldest first.
+pub fn get_fee_history(env: &Env) -> Vec<FeeChange> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeHistory)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Records why an event was deactivated, or clears it with `None`.
+pub fn set_deactivation_reason(env: &Env, event_id: String, reason: Option<DeactivationReason>) {
+    let key = DataKey::DeactivationReason(event_id);
+    match reason {
+        Some(reason) => env.storage().persistent().set(&key, &reason),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves why an event was deactivated, if a reason was given.
+pub fn get_deactivation_reason(env: &Env, event_id: String) -> Option<DeactivationReason> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DeactivationReason(event_id))
+}
+
+/// Creates or replaces a subscription plan.
+pub fn set_subscription_plan(env: &Env, plan: &SubscriptionPlan) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SubscriptionPlan(plan.plan_id), plan);
+}
+
+/// Retrieves a subscription plan by ID.
+pub fn get_subscription_plan(env: &Env, plan_id: u32) -> Option<SubscriptionPlan> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SubscriptionPlan(plan_id))
+}
+
+/// Stores an organizer's current subscription.
+pub fn set_organizer_subscription(
+    env: &Env,
+    organizer: &Address,
+    subscription: &OrganizerSubscription,
+) {
+    env.storage().persistent().set(
+        &DataKey::OrganizerSubscription(organizer.clone()),
+        subscription,
+    );
+}
+
+/// Retrieves an organizer's subscription, whether or not it has expired.
+pub fn get_organizer_subscription(env: &Env, organizer: &Address) -> Option<OrganizerSubscription> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrganizerSubscription(organizer.clone()))
+}
+
+/// Creates or replaces a tenant.
+pub fn set_tenant(env: &Env, tenant: &Tenant) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Tenant(tenant.tenant_id.clone()), tenant);
+}
+
+/// Retrieves a tenant by ID.
+pub fn get_tenant(env: &Env, tenant_id: String) -> Option<Tenant> {
+    env.storage().persistent().get(&DataKey::Tenant(tenant_id))
+}
+
+/// Sets the key that signs event exports accepted for import.
+pub fn set_import_signer(env: &Env, public_key: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ImportSigner, public_key);
+}
+
+/// Marks an export, by the sha256 of its XDR, as imported.
+pub fn set_export_imported(env: &Env, export_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ImportedExport(export_hash.clone()), &true);
+}
+
+/// Returns whether an export, by the sha256 of its XDR, was already imported.
+pub fn is_export_imported(env: &Env, export_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ImportedExport(export_hash.clone()))
+}
+
+/// Retrieves the key that signs event exports accepted for import.
+pub fn get_import_signer(env: &Env) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&DataKey::ImportSigner)
+}
+
+/// Sets the oracle that attests event completion.
+pub fn set_completion_attestor(env: &Env, attestor: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CompletionAttestor, attestor);
+}
+
+/// Retrieves the oracle that attests event completion.
+pub fn get_completion_attestor(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::CompletionAttestor)
+}
+
+/// Sets the address that controls the platform fee.
+pub fn set_fee_setter(env: &Env, fee_setter: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeSetter, fee_setter);
+}
+
+/// Retrieves the address that controls the platform fee, if delegated.
+pub fn get_fee_setter(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::FeeSetter)
+}
+
+/// Retrieves the nonce the fee setter's next call must carry.
+pub fn get_fee_setter_nonce(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeSetterNonce)
+        .unwrap_or(0)
+}
+
+/// Stores the nonce the fee setter's next call must carry.
+pub fn set_fee_setter_nonce(env: &Env, nonce: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeSetterNonce, &nonce);
+}
+
+/// Stores the metadata reveal of an event.
+pub fn set_metadata_reveal(env: &Env, event_id: String, reveal: &MetadataReveal) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MetadataReveal(event_id), reveal);
+}
+
+/// Retrieves the metadata reveal of an event.
+pub fn get_metadata_reveal(env: &Env, event_id: String) -> Option<MetadataReveal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MetadataReveal(event_id))
+}
+
+/// Sets or (with `None`) clears the partner key of an event.
+pub fn set_partner_key(env: &Env, event_id: String, partner_key: Option<BytesN<32>>) {
+    let key = DataKey::PartnerKey(event_id);
+    match partner_key {
+        Some(partner_key) => env.storage().persistent().set(&key, &partner_key),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves the partner key of an event.
+pub fn get_partner_key(env: &Env, event_id: String) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartnerKey(event_id))
+}
+
+/// Replaces an event's tags, moving the event between the tag indexes.
+pub fn set_event_tags(env: &Env, event_id: String, tags: &Vec<String>) {
+    let storage = env.storage().persistent();
+    let old_tags = get_event_tags(env, event_id.clone());
+    for tag in old_tags.iter() {
+        if tags.contains(&tag) {
+            continue;
+        }
+        let mut tag_events = get_tag_events(env, tag.clone());
+        if let Some(index) = tag_events.first_index_of(&event_id) {
+            tag_events.remove(index);
+        }
+        if tag_events.is_empty() {
+            storage.remove(&DataKey::TagEvents(tag));
+        } else {
+            storage.set(&DataKey::TagEvents(tag), &tag_events);
+        }
+    }
+    for tag in tags.iter() {
+        if old_tags.contains(&tag) {
+            continue;
+        }
+        let mut tag_events = get_tag_events(env, tag.clone());
+        tag_events.push_back(event_id.clone());
+        storage.set(&DataKey::TagEvents(tag), &tag_events);
+    }
+
+    if tags.is_empty() {
+        storage.remove(&DataKey::EventTags(event_id));
+    } else {
+        storage.set(&DataKey::EventTags(event_id), tags);
+    }
+}
+
+/// Retrieves the tags of an event.
+pub fn get_event_tags(env: &Env, event_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventTags(event_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Retrieves the IDs of the events carrying a tag, in the order they were tagged.
+pub fn get_tag_events(env: &Env, tag: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TagEvents(tag))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Removes an event and everything stored under its ID, including its entry
+/// in the organizer's event list and the tag indexes.
+pub fn remove_event(env: &Env, event_info: &EventInfo) {
+    let event_id = event_info.event_id.clone();
+    let storage = env.storage().persistent();
+    for sku in get_event_addon_skus(env, event_id.clone()).iter() {
+        storage.remove(&DataKey::EventAddon(event_id.clone(), sku));
+    }
+    storage.remove(&DataKey::EventAddons(event_id.clone()));
+    storage.remove(&DataKey::EventCoHosts(event_id.clone()));
+    storage.remove(&DataKey::DeactivationReason(event_id.clone()));
+    storage.remove(&DataKey::MetadataReveal(event_id.clone()));
+    storage.remove(&DataKey::PartnerKey(event_id.clone()));
+    set_event_tags(env, event_id.clone(), &Vec::new(env));
+    storage.remove(&DataKey::Event(event_id.clone()));
+
+    let organizer = event_info.organizer_address.clone();
+    let mut organizer_events = get_organizer_events(env, &organizer);
+    if let Some(index) = organizer_events.first_index_of(&event_id) {
+        organizer_events.remove(index);
+        storage.set(&DataKey::OrganizerEvents(organizer), &organizer_events);
+    }
+}
+
+/// Stores a deleted event in temporary storage for `ttl_ledgers`.
+pub fn set_deleted_event(env: &Env, event_id: String, deleted: &DeletedEvent, ttl_ledgers: u32) {
+    let key = DataKey::DeletedEvent(event_id);
+    env.storage().temporary().set(&key, deleted);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, ttl_ledgers, ttl_ledgers);
+}
+
+/// Retrieves a deleted event, whether or not its restore window has closed.
+pub fn get_deleted_event(env: &Env, event_id: String) -> Option<DeletedEvent> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::DeletedEvent(event_id))
+}
+
+/// Drops a deleted event once it has been restored.
+pub fn remove_deleted_event(env: &Env, event_id: String) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::DeletedEvent(event_id));
+}
+
+/// Retrieves the number of the last event ID generated for an organizer.
+pub fn get_organizer_id_counter(env: &Env, organizer: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrganizerIdCounter(organizer.clone()))
+        .unwrap_or(0)
+}
+
+/// Updates the number of the last event ID generated for an organizer.
+pub fn set_organizer_id_counter(env: &Env, organizer: &Address, counter: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrganizerIdCounter(organizer.clone()), &counter);
+}
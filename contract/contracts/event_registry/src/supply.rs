@@ -0,0 +1,102 @@
+//! Sold-versus-capacity counter shared by events and add-ons.
+//!
+//! Stored records keep `max_supply` / `current_supply` as `i128` so the
+//! contract interface doesn't change, but every mutation goes through
+//! [`Supply`], which only holds counts that are non-negative and, for capped
+//! supplies, never above the cap.
+
+use crate::error::EventRegistryError;
+use crate::quantity::Quantity;
+use crate::types::{EventAddon, EventInfo};
+
+/// Units sold against an optional cap (`max` of 0 means unlimited).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Supply {
+    max: u64,
+    sold: u64,
+}
+
+impl Supply {
+    /// Validates a stored `(max_supply, current_supply)` pair.
+    ///
+    /// # Errors
+    /// * `InvalidSupply` - If either count is negative or `sold` exceeds a non-zero `max`.
+    pub fn new(max: i128, sold: i128) -> Result<Self, EventRegistryError> {
+        let max = u64::try_from(max).map_err(|_| EventRegistryError::InvalidSupply)?;
+        let sold = u64::try_from(sold).map_err(|_| EventRegistryError::InvalidSupply)?;
+        if max > 0 && sold > max {
+            return Err(EventRegistryError::InvalidSupply);
+        }
+        Ok(Self { max, sold })
+    }
+
+    pub fn of_event(event_info: &EventInfo) -> Result<Self, EventRegistryError> {
+        Self::new(event_info.max_supply, event_info.current_supply)
+    }
+
+    pub fn of_addon(addon: &EventAddon) -> Result<Self, EventRegistryError> {
+        Self::new(addon.max_supply, addon.current_supply)
+    }
+
+    pub fn max(&self) -> i128 {
+        self.max as i128
+    }
+
+    pub fn sold(&self) -> i128 {
+        self.sold as i128
+    }
+
+    /// Units left to sell, or `None` when the supply is unlimited.
+    pub fn remaining(&self) -> Option<u64> {
+        (self.max > 0).then(|| self.max - self.sold)
+    }
+
+    /// Checks that `quantity` more units fit alongside `held` units reserved elsewhere.
+    ///
+    /// # Errors
+    /// * `MaxSupplyExceeded` - If the cap would be passed.
+    /// * `SupplyOverflow` - If the counts don't fit in a `u64`.
+    pub fn check_available(&self, quantity: Quantity, held: u64) -> Result<(), EventRegistryError> {
+        let wanted = self
+            .sold
+            .checked_add(held)
+            .and_then(|total| total.checked_add(quantity.units()))
+            .ok_or(EventRegistryError::SupplyOverflow)?;
+        if self.max > 0 && wanted > self.max {
+            return Err(EventRegistryError::MaxSupplyExceeded);
+        }
+        Ok(())
+    }
+
+    /// Records `quantity` units as sold.
+    ///
+    /// # Errors
+    /// * `MaxSupplyExceeded` - If the cap would be passed.
+    /// * `SupplyOverflow` - If the sold count doesn't fit in a `u64`.
+    pub fn try_sell(&mut self, quantity: Quantity) -> Result<(), EventRegistryError> {
+        self.check_available(quantity, 0)?;
+        self.sold += quantity.units();
+        Ok(())
+    }
+
+    /// Returns `quantity` sold units to the pool.
+    ///
+    /// # Errors
+    /// * `InvalidSupply` - If fewer than `quantity` units have been sold.
+    pub fn release(&mut self, quantity: Quantity) -> Result<(), EventRegistryError> {
+        self.sold = self
+            .sold
+            .checked_sub(quantity.units())
+            .ok_or(EventRegistryError::InvalidSupply)?;
+        Ok(())
+    }
+
+    /// Changes the cap, keeping what has already been sold.
+    ///
+    /// # Errors
+    /// * `InvalidSupply` - If `max` is negative or below the sold count.
+    pub fn set_max(&mut self, max: i128) -> Result<(), EventRegistryError> {
+        *self = Self::new(max, self.sold as i128)?;
+        Ok(())
+    }
+}
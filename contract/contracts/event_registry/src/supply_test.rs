@@ -0,0 +1,80 @@
+use crate::error::EventRegistryError;
+use crate::quantity::Quantity;
+use crate::supply::Supply;
+
+fn qty(count: u32) -> Quantity {
+    Quantity::new(count).unwrap()
+}
+
+#[test]
+fn test_supply_rejects_invalid_counts() {
+    assert_eq!(Supply::new(-1, 0), Err(EventRegistryError::InvalidSupply));
+    assert_eq!(Supply::new(10, -1), Err(EventRegistryError::InvalidSupply));
+    assert_eq!(Supply::new(10, 11), Err(EventRegistryError::InvalidSupply));
+    assert_eq!(
+        Supply::new(i128::MAX, 0),
+        Err(EventRegistryError::InvalidSupply)
+    );
+    assert!(Supply::new(10, 10).is_ok());
+    // A zero cap is unlimited, so any sold count is valid
+    assert!(Supply::new(0, 1_000_000).is_ok());
+}
+
+#[test]
+fn test_supply_sells_up_to_cap() {
+    let mut supply = Supply::new(3, 0).unwrap();
+    assert_eq!(supply.remaining(), Some(3));
+    supply.try_sell(qty(2)).unwrap();
+    assert_eq!(
+        supply.try_sell(qty(2)),
+        Err(EventRegistryError::MaxSupplyExceeded)
+    );
+    // A failed sale leaves the count untouched
+    assert_eq!(supply.sold(), 2);
+    supply.try_sell(qty(1)).unwrap();
+    assert_eq!(supply.remaining(), Some(0));
+}
+
+#[test]
+fn test_supply_counts_held_units() {
+    let supply = Supply::new(5, 2).unwrap();
+    assert!(supply.check_available(qty(1), 2).is_ok());
+    assert_eq!(
+        supply.check_available(qty(2), 2),
+        Err(EventRegistryError::MaxSupplyExceeded)
+    );
+    assert_eq!(
+        supply.check_available(qty(1), u64::MAX),
+        Err(EventRegistryError::SupplyOverflow)
+    );
+}
+
+#[test]
+fn test_unlimited_supply_stops_at_overflow() {
+    let mut supply = Supply::new(0, u64::MAX as i128 - 1).unwrap();
+    assert_eq!(supply.remaining(), None);
+    supply.try_sell(qty(1)).unwrap();
+    assert_eq!(
+        supply.try_sell(qty(1)),
+        Err(EventRegistryError::SupplyOverflow)
+    );
+}
+
+#[test]
+fn test_supply_release_and_set_max() {
+    let mut supply = Supply::new(10, 4).unwrap();
+    supply.release(qty(3)).unwrap();
+    assert_eq!(supply.sold(), 1);
+    assert_eq!(
+        supply.release(qty(2)),
+        Err(EventRegistryError::InvalidSupply)
+    );
+    assert_eq!(supply.sold(), 1);
+
+    assert_eq!(supply.set_max(0), Ok(()));
+    assert_eq!(supply.remaining(), None);
+    supply.try_sell(qty(4)).unwrap();
+    assert_eq!(supply.set_max(5), Ok(()));
+    assert_eq!(supply.set_max(4), Err(EventRegistryError::InvalidSupply));
+    assert_eq!(supply.max(), 5);
+}
@@ -1,7 +1,30 @@
 use super::*;
 use crate::error::EventRegistryError;
-use crate::types::EventInfo;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use crate::types::{
+    AddonSelection, AddonViolation, CoHost, DeactivationReason, EventInfo, SubscriptionPlan,
+    Tenant, COHOST_CAN_CANCEL, COHOST_CAN_EDIT,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, String,
+};
+
+// Mock Ticket Payment contract with no recorded sales and no denied tokens
+#[soroban_sdk::contract]
+pub struct MockTicketPayment;
+
+#[soroban_sdk::contractimpl]
+impl MockTicketPayment {
+    pub fn get_event_sales(_env: Env, _event_id: String) -> crate::types::EventSales {
+        crate::types::EventSales::default()
+    }
+
+    pub fn is_token_denied(_env: Env, _token: Address) -> bool {
+        false
+    }
+}
 
 #[test]
 fn test_initialize() {
@@ -67,7 +90,7 @@ fn test_set_platform_fee() {
     let platform_wallet = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_platform_fee(&10);
+    client.set_platform_fee(&10, &0);
 
     assert_eq!(client.get_platform_fee(), 10);
 }
@@ -83,10 +106,36 @@ fn test_set_platform_fee_invalid() {
     let platform_wallet = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    let result = client.try_set_platform_fee(&10001);
+    let result = client.try_set_platform_fee(&10001, &0);
     assert_eq!(result, Err(Ok(EventRegistryError::InvalidFeePercent)));
 }
 
+#[test]
+fn test_admin_nonce_rejects_replayed_calls() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+    assert_eq!(client.get_admin_nonce(), 0);
+
+    client.set_platform_fee(&300, &0);
+    assert_eq!(client.get_admin_nonce(), 1);
+    // Replaying the same signed call, or skipping ahead, is refused
+    assert_eq!(
+        client.try_set_platform_fee(&300, &0),
+        Err(Ok(EventRegistryError::InvalidNonce))
+    );
+    assert_eq!(
+        client.try_set_fee_setter(&Address::generate(&env), &2),
+        Err(Ok(EventRegistryError::InvalidNonce))
+    );
+    assert_eq!(client.get_admin_nonce(), 1);
+
+    client.set_ticket_payment_contract(&Address::generate(&env), &1);
+    assert_eq!(client.get_admin_nonce(), 2);
+}
+
 #[test]
 #[should_panic] // Authentication failure
 fn test_set_platform_fee_unauthorized() {
@@ -98,7 +147,7 @@ fn test_set_platform_fee_unauthorized() {
     let platform_wallet = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_platform_fee(&10);
+    client.set_platform_fee(&10, &0);
 }
 
 #[test]
@@ -308,6 +357,11 @@ fn test_get_event_payment_info() {
     let info = client.get_event_payment_info(&event_id);
     assert_eq!(info.payment_address, payment_addr);
     assert_eq!(info.platform_fee_percent, 750);
+    assert_eq!(info.metadata_cid, metadata_cid);
+
+    // Live metadata stays readable after the event is deactivated
+    client.update_event_status(&event_id, &false);
+    assert_eq!(client.get_event_metadata(&event_id), metadata_cid);
 }
 
 #[test]
@@ -483,11 +537,42 @@ fn test_set_ticket_payment_contract() {
     let ticket_payment = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_ticket_payment_contract(&ticket_payment);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
 
     assert_eq!(client.get_ticket_payment_contract(), ticket_payment);
 }
 
+#[test]
+fn test_revoked_ticket_payment_contract_loses_callbacks() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    let compromised = client.get_ticket_payment_contract();
+    client.increment_inventory(&event_id);
+
+    client.revoke_caller(&compromised, &1);
+    assert!(client.is_caller_revoked(&compromised));
+    assert_eq!(
+        client.try_increment_inventory(&event_id),
+        Err(Ok(EventRegistryError::UnauthorizedCaller))
+    );
+    let selections = soroban_sdk::vec![&env];
+    assert_eq!(
+        client.try_reserve_addons(&event_id, &selections),
+        Err(Ok(EventRegistryError::UnauthorizedCaller))
+    );
+    assert_eq!(
+        client.try_set_ticket_payment_contract(&compromised, &2),
+        Err(Ok(EventRegistryError::UnauthorizedCaller))
+    );
+
+    // A fresh deployment takes over the callbacks
+    let replacement = Address::generate(&env);
+    client.set_ticket_payment_contract(&replacement, &2);
+    client.increment_inventory(&event_id);
+    assert_eq!(client.get_event(&event_id).unwrap().current_supply, 2);
+}
+
 #[test]
 fn test_increment_inventory_success() {
     let env = Env::default();
@@ -503,7 +588,7 @@ fn test_increment_inventory_success() {
     let ticket_payment = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_ticket_payment_contract(&ticket_payment);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
 
     let event_id = String::from_str(&env, "supply_event");
     let metadata_cid = String::from_str(
@@ -541,7 +626,7 @@ fn test_increment_inventory_max_supply_exceeded() {
     let ticket_payment = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_ticket_payment_contract(&ticket_payment);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
 
     let event_id = String::from_str(&env, "limited_event");
     let metadata_cid = String::from_str(
@@ -579,7 +664,7 @@ fn test_increment_inventory_unlimited_supply() {
     let ticket_payment = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_ticket_payment_contract(&ticket_payment);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
 
     let event_id = String::from_str(&env, "unlimited_event");
     let metadata_cid = String::from_str(
@@ -612,7 +697,7 @@ fn test_increment_inventory_event_not_found() {
     let ticket_payment = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_ticket_payment_contract(&ticket_payment);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
 
     let fake_event_id = String::from_str(&env, "nonexistent");
     let result = client.try_increment_inventory(&fake_event_id);
@@ -634,7 +719,7 @@ fn test_increment_inventory_inactive_event() {
     let ticket_payment = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_ticket_payment_contract(&ticket_payment);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
 
     let event_id = String::from_str(&env, "inactive_event");
     let metadata_cid = String::from_str(
@@ -666,7 +751,7 @@ fn test_increment_inventory_persists_across_reads() {
     let ticket_payment = Address::generate(&env);
 
     client.initialize(&admin, &platform_wallet, &500);
-    client.set_ticket_payment_contract(&ticket_payment);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
 
     let event_id = String::from_str(&env, "persist_event");
     let metadata_cid = String::from_str(
@@ -687,3 +772,1204 @@ fn test_increment_inventory_persists_across_reads() {
     assert_eq!(event_info_2.current_supply, 5);
     assert_eq!(event_info_1.max_supply, 50);
 }
+
+#[test]
+fn test_registry_stats_track_registrations_and_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let payment_addr = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let event_1 = String::from_str(&env, "stats_event_1");
+    let event_2 = String::from_str(&env, "stats_event_2");
+    client.register_event(&event_1, &organizer, &payment_addr, &metadata_cid, &100);
+    client.register_event(&event_2, &organizer, &payment_addr, &metadata_cid, &100);
+
+    let stats = client.get_registry_stats();
+    assert_eq!(stats.total_events, 2);
+    assert_eq!(stats.active_events, 2);
+
+    // Repeated deactivation only counts once
+    client.update_event_status(&event_1, &false);
+    client.update_event_status(&event_1, &false);
+    let stats = client.get_registry_stats();
+    assert_eq!(stats.total_events, 2);
+    assert_eq!(stats.active_events, 1);
+
+    client.update_event_status(&event_1, &true);
+    assert_eq!(client.get_registry_stats().active_events, 2);
+}
+
+// ==================== Add-on Tests ====================
+
+fn setup_addon_event(env: &Env) -> (EventRegistryClient<'static>, String) {
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let organizer = Address::generate(env);
+    let payment_addr = Address::generate(env);
+    let platform_wallet = Address::generate(env);
+    let ticket_payment = env.register(MockTicketPayment, ());
+
+    client.initialize(&admin, &platform_wallet, &500);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
+
+    let event_id = String::from_str(env, "addon_event");
+    let metadata_cid = String::from_str(
+        env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    client.register_event(&event_id, &organizer, &payment_addr, &metadata_cid, &100);
+
+    (client, event_id)
+}
+
+#[test]
+fn test_set_and_reserve_addons() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+
+    let parking = String::from_str(&env, "parking");
+    let shirt = String::from_str(&env, "tshirt");
+    client.set_event_addon(&event_id, &parking, &200, &10);
+    client.set_event_addon(&event_id, &shirt, &350, &0);
+
+    let addons = client.get_event_addons(&event_id);
+    assert_eq!(addons.len(), 2);
+    assert_eq!(addons.get(0).unwrap().sku, parking);
+
+    let selections = soroban_sdk::vec![
+        &env,
+        AddonSelection {
+            sku: parking.clone(),
+            quantity: 2,
+        },
+        AddonSelection {
+            sku: shirt.clone(),
+            quantity: 1,
+        },
+    ];
+    let line_items = client.reserve_addons(&event_id, &selections);
+    assert_eq!(line_items.len(), 2);
+    assert_eq!(line_items.get(0).unwrap().unit_price, 200);
+    assert_eq!(line_items.get(0).unwrap().quantity, 2);
+    assert_eq!(line_items.get(0).unwrap().line_total, 400);
+    assert_eq!(line_items.get(1).unwrap().unit_price, 350);
+
+    // Re-pricing keeps the sold count
+    client.set_event_addon(&event_id, &parking, &250, &10);
+    let addons = client.get_event_addons(&event_id);
+    assert_eq!(addons.get(0).unwrap().price, 250);
+    assert_eq!(addons.get(0).unwrap().current_supply, 2);
+}
+
+#[test]
+fn test_reserve_addons_sold_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+
+    let parking = String::from_str(&env, "parking");
+    client.set_event_addon(&event_id, &parking, &200, &3);
+
+    let selections = soroban_sdk::vec![
+        &env,
+        AddonSelection {
+            sku: parking.clone(),
+            quantity: 2,
+        },
+    ];
+    client.reserve_addons(&event_id, &selections);
+
+    let result = client.try_reserve_addons(&event_id, &selections);
+    assert_eq!(result, Err(Ok(EventRegistryError::AddonSoldOut)));
+
+    // Failed reservation leaves supply untouched
+    assert_eq!(
+        client
+            .get_event_addons(&event_id)
+            .get(0)
+            .unwrap()
+            .current_supply,
+        2
+    );
+}
+
+#[test]
+fn test_addon_validation_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+
+    let parking = String::from_str(&env, "parking");
+    let result = client.try_set_event_addon(&event_id, &parking, &0, &10);
+    assert_eq!(result, Err(Ok(EventRegistryError::InvalidAddon)));
+
+    let unknown = soroban_sdk::vec![
+        &env,
+        AddonSelection {
+            sku: String::from_str(&env, "unknown"),
+            quantity: 1,
+        },
+    ];
+    let result = client.try_reserve_addons(&event_id, &unknown);
+    assert_eq!(result, Err(Ok(EventRegistryError::AddonNotFound)));
+
+    client.set_event_addon(&event_id, &parking, &200, &10);
+    let zero_qty = soroban_sdk::vec![
+        &env,
+        AddonSelection {
+            sku: parking,
+            quantity: 0,
+        },
+    ];
+    let result = client.try_reserve_addons(&event_id, &zero_qty);
+    assert_eq!(result, Err(Ok(EventRegistryError::InvalidAddon)));
+}
+
+#[test]
+fn test_clone_event_copies_configuration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+
+    let parking = String::from_str(&env, "parking");
+    client.set_event_addon(&event_id, &parking, &200, &10);
+    client.set_ticket_payment_contract(&Address::generate(&env), &1);
+    client.reserve_addons(
+        &event_id,
+        &soroban_sdk::vec![
+            &env,
+            AddonSelection {
+                sku: parking.clone(),
+                quantity: 4,
+            }
+        ],
+    );
+
+    let clone_id = String::from_str(&env, "addon_event_march");
+    client.clone_event(&event_id, &clone_id);
+
+    let source = client.get_event(&event_id).unwrap();
+    let cloned = client.get_event(&clone_id).unwrap();
+    assert_eq!(cloned.organizer_address, source.organizer_address);
+    assert_eq!(cloned.payment_address, source.payment_address);
+    assert_eq!(cloned.metadata_cid, source.metadata_cid);
+    assert_eq!(cloned.max_supply, source.max_supply);
+    assert_eq!(cloned.current_supply, 0);
+    assert!(cloned.is_active);
+
+    let addons = client.get_event_addons(&clone_id);
+    assert_eq!(addons.len(), 1);
+    let addon = addons.get(0).unwrap();
+    assert_eq!(addon.price, 200);
+    assert_eq!(addon.max_supply, 10);
+    assert_eq!(addon.current_supply, 0);
+    assert_eq!(client.get_registry_stats().total_events, 2);
+
+    assert_eq!(
+        client.try_clone_event(&event_id, &clone_id),
+        Err(Ok(EventRegistryError::EventAlreadyExists))
+    );
+    assert_eq!(
+        client.try_clone_event(&String::from_str(&env, "missing"), &clone_id),
+        Err(Ok(EventRegistryError::EventNotFound))
+    );
+}
+
+#[test]
+fn test_validate_registration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let event_id = String::from_str(&env, "event_001");
+    let bad_cid = String::from_str(&env, "Qm123");
+    let violations = client.validate_registration(&event_id, &bad_cid, &0);
+    assert_eq!(
+        violations,
+        soroban_sdk::vec![
+            &env,
+            EventRegistryError::NotInitialized as u32,
+            EventRegistryError::InvalidMetadataCid as u32,
+        ]
+    );
+
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    assert!(client
+        .validate_registration(&event_id, &metadata_cid, &0)
+        .is_empty());
+
+    let organizer = Address::generate(&env);
+    client.register_event(&event_id, &organizer, &organizer, &metadata_cid, &0);
+    assert_eq!(
+        client.validate_registration(&event_id, &metadata_cid, &0),
+        soroban_sdk::vec![&env, EventRegistryError::EventAlreadyExists as u32]
+    );
+
+    // Supply and tenant checks match register_event's
+    assert_eq!(
+        client.validate_registration(&String::from_str(&env, "acme:gala"), &metadata_cid, &-1),
+        soroban_sdk::vec![
+            &env,
+            EventRegistryError::InvalidSupply as u32,
+            EventRegistryError::TenantNotFound as u32,
+        ]
+    );
+}
+
+#[test]
+fn test_validate_addon_selection_reports_availability() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+
+    let parking = String::from_str(&env, "parking");
+    let shirt = String::from_str(&env, "tshirt");
+    client.set_event_addon(&event_id, &parking, &200, &3);
+    client.set_event_addon(&event_id, &shirt, &350, &0);
+
+    let selections = soroban_sdk::vec![
+        &env,
+        AddonSelection {
+            sku: parking.clone(),
+            quantity: 5,
+        },
+        AddonSelection {
+            sku: shirt,
+            quantity: 50,
+        },
+        AddonSelection {
+            sku: String::from_str(&env, "meal"),
+            quantity: 1,
+        },
+    ];
+    let violations = client.validate_addon_selection(&event_id, &selections);
+    assert_eq!(
+        violations,
+        soroban_sdk::vec![
+            &env,
+            AddonViolation {
+                sku: parking,
+                code: EventRegistryError::AddonSoldOut as u32,
+                requested: 5,
+                available: 3,
+            },
+            AddonViolation {
+                sku: String::from_str(&env, "meal"),
+                code: EventRegistryError::AddonNotFound as u32,
+                requested: 1,
+                available: -1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_event_cohosts_share_control() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+
+    let venue = Address::generate(&env);
+    let promoter = Address::generate(&env);
+    let cohosts = soroban_sdk::vec![
+        &env,
+        CoHost {
+            address: venue.clone(),
+            share_bps: 3000,
+            permissions: COHOST_CAN_CANCEL | COHOST_CAN_EDIT,
+        },
+        CoHost {
+            address: promoter.clone(),
+            share_bps: 1000,
+            permissions: 0,
+        },
+    ];
+    client.set_event_cohosts(&event_id, &cohosts);
+    assert_eq!(client.get_event_cohosts(&event_id), cohosts);
+    assert_eq!(client.get_event_payment_info(&event_id).cohosts, cohosts);
+
+    // Cancelling needs the venue's signature, not the promoter's
+    client.update_event_status(&event_id, &false);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(signer, _)| *signer == venue));
+    assert!(!auths.iter().any(|(signer, _)| *signer == promoter));
+
+    let too_much = soroban_sdk::vec![
+        &env,
+        CoHost {
+            address: venue,
+            share_bps: 10001,
+            permissions: 0,
+        },
+    ];
+    assert_eq!(
+        client.try_set_event_cohosts(&event_id, &too_much),
+        Err(Ok(EventRegistryError::InvalidCoHosts))
+    );
+}
+
+#[test]
+fn test_event_partner_key_survives_delete_and_restore() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    let partner_key = BytesN::from_array(&env, &[7; 32]);
+
+    let organizer = client.get_event(&event_id).unwrap().organizer_address;
+    client.set_event_partner_key(&event_id, &Some(partner_key.clone()));
+    assert_eq!(env.auths()[0].0, organizer);
+    assert_eq!(
+        client.get_event_partner_key(&event_id),
+        Some(partner_key.clone())
+    );
+    assert_eq!(
+        client.get_event_payment_info(&event_id).partner_key,
+        Some(partner_key.clone())
+    );
+
+    client.delete_event(&event_id);
+    assert_eq!(client.get_event_partner_key(&event_id), None);
+    client.restore_event(&event_id);
+    assert_eq!(client.get_event_partner_key(&event_id), Some(partner_key));
+
+    client.set_event_partner_key(&event_id, &None);
+    assert_eq!(client.get_event_payment_info(&event_id).partner_key, None);
+    assert_eq!(
+        client.try_set_event_partner_key(&String::from_str(&env, "missing"), &None),
+        Err(Ok(EventRegistryError::EventNotFound))
+    );
+}
+
+#[test]
+fn test_event_tags_maintain_tag_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    let music = String::from_str(&env, "music");
+    let festival = String::from_str(&env, "open-air");
+
+    let organizer = client.get_event(&event_id).unwrap().organizer_address;
+    client.set_event_tags(
+        &event_id,
+        &soroban_sdk::vec![&env, music.clone(), festival.clone()],
+    );
+    assert_eq!(env.auths()[0].0, organizer);
+    let tagged = client.get_events_by_tag(&music, &0, &10);
+    assert_eq!(tagged.len(), 1);
+    assert_eq!(tagged.get(0).unwrap().event_id, event_id);
+    assert_eq!(client.get_events_by_tag(&music, &1, &10).len(), 0);
+
+    // Retagging moves the event out of dropped tags' indexes
+    client.set_event_tags(&event_id, &soroban_sdk::vec![&env, festival.clone()]);
+    assert_eq!(client.get_events_by_tag(&music, &0, &10).len(), 0);
+    assert_eq!(client.get_events_by_tag(&festival, &0, &10).len(), 1);
+
+    client.delete_event(&event_id);
+    assert_eq!(client.get_events_by_tag(&festival, &0, &10).len(), 0);
+    client.restore_event(&event_id);
+    assert_eq!(
+        client.get_event_tags(&event_id),
+        soroban_sdk::vec![&env, festival.clone()]
+    );
+    assert_eq!(client.get_events_by_tag(&festival, &0, &10).len(), 1);
+
+    for bad in [
+        soroban_sdk::vec![&env, music.clone(), music.clone()],
+        soroban_sdk::vec![&env, String::from_str(&env, "Music")],
+        soroban_sdk::vec![&env, String::from_str(&env, "")],
+        soroban_sdk::vec![&env, String::from_str(&env, "a-very-long-tag-name")],
+        soroban_sdk::vec![
+            &env,
+            String::from_str(&env, "a"),
+            String::from_str(&env, "b"),
+            String::from_str(&env, "c"),
+            String::from_str(&env, "d"),
+            String::from_str(&env, "e"),
+            String::from_str(&env, "f"),
+        ],
+    ] {
+        assert_eq!(
+            client.try_set_event_tags(&event_id, &bad),
+            Err(Ok(EventRegistryError::InvalidTags))
+        );
+    }
+}
+
+#[test]
+fn test_inventory_holds_prevent_overselling() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_addon_event(&env);
+
+    let event_id = String::from_str(&env, "small_event");
+    let organizer = Address::generate(&env);
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    client.register_event(&event_id, &organizer, &organizer, &metadata_cid, &3);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let hold = client.hold_inventory(&event_id, &alice, &2, &300);
+    assert_eq!(hold.expires_at, 300);
+    assert_eq!(client.get_inventory_hold(&event_id, &alice), Some(hold));
+
+    // Only one unheld ticket remains
+    assert_eq!(
+        client.try_hold_inventory(&event_id, &bob, &2, &300),
+        Err(Ok(EventRegistryError::MaxSupplyExceeded))
+    );
+    client.increment_inventory(&event_id);
+    assert_eq!(
+        client.try_increment_inventory(&event_id),
+        Err(Ok(EventRegistryError::MaxSupplyExceeded))
+    );
+
+    // Each purchase takes one ticket off the hold until it is used up
+    client.consume_inventory_hold(&event_id, &alice);
+    assert_eq!(client.get_event(&event_id).unwrap().current_supply, 2);
+    assert_eq!(
+        client
+            .get_inventory_hold(&event_id, &alice)
+            .unwrap()
+            .quantity,
+        1
+    );
+    client.consume_inventory_hold(&event_id, &alice);
+    assert_eq!(client.get_event(&event_id).unwrap().current_supply, 3);
+    assert_eq!(client.get_inventory_hold(&event_id, &alice), None);
+    assert_eq!(
+        client.try_consume_inventory_hold(&event_id, &alice),
+        Err(Ok(EventRegistryError::HoldNotFound))
+    );
+
+    // Expired holds stop counting against supply
+    let other_id = String::from_str(&env, "other_event");
+    client.register_event(&other_id, &organizer, &organizer, &metadata_cid, &2);
+    client.hold_inventory(&other_id, &alice, &2, &60);
+    env.ledger().with_mut(|li| li.timestamp = 61);
+    assert_eq!(client.get_inventory_hold(&other_id, &alice), None);
+    assert_eq!(
+        client.try_consume_inventory_hold(&other_id, &alice),
+        Err(Ok(EventRegistryError::HoldNotFound))
+    );
+    client.hold_inventory(&other_id, &bob, &2, &60);
+    // Alice's lapsed hold was pruned from the holder list
+    let holders = env.as_contract(&client.address, || {
+        storage::get_event_holders(&env, other_id.clone())
+    });
+    assert_eq!(holders, soroban_sdk::vec![&env, bob.clone()]);
+
+    assert_eq!(
+        client.try_hold_inventory(&other_id, &bob, &1, &(MAX_HOLD_SECS + 1)),
+        Err(Ok(EventRegistryError::InvalidHold))
+    );
+}
+
+#[test]
+fn test_fee_history_and_fee_at() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.initialize(&admin, &Address::generate(&env), &500);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.set_platform_fee(&300, &0);
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    client.set_platform_fee(&800, &1);
+
+    let history = client.get_fee_history();
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(1).unwrap().fee_percent, 300);
+    assert_eq!(history.get(1).unwrap().effective_from, 200);
+    assert_eq!(history.get(1).unwrap().changed_by, admin);
+
+    assert_eq!(
+        client.try_get_fee_at(&99),
+        Err(Ok(EventRegistryError::FeeNotRecorded))
+    );
+    assert_eq!(client.get_fee_at(&100), 500);
+    assert_eq!(client.get_fee_at(&150), 500);
+    assert_eq!(client.get_fee_at(&200), 300);
+    assert_eq!(client.get_fee_at(&299), 300);
+    assert_eq!(client.get_fee_at(&1000), 800);
+}
+
+#[test]
+fn test_deactivate_event_with_reason() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+
+    client.deactivate_event(&event_id, &DeactivationReason::Postponed);
+    let event_info = client.get_event(&event_id).unwrap();
+    assert!(!event_info.is_active);
+    assert_eq!(
+        client.get_deactivation_reason(&event_id),
+        Some(DeactivationReason::Postponed)
+    );
+    assert_eq!(client.get_registry_stats().active_events, 0);
+    assert_eq!(
+        client.try_get_event_payment_info(&event_id),
+        Err(Ok(EventRegistryError::EventInactive))
+    );
+
+    client.update_event_status(&event_id, &true);
+    let event_info = client.get_event(&event_id).unwrap();
+    assert!(event_info.is_active);
+    assert_eq!(client.get_deactivation_reason(&event_id), None);
+    assert_eq!(client.get_registry_stats().active_events, 1);
+}
+
+#[test]
+fn test_platform_suspension_needs_admin_to_lift() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    let admin = client.get_admin();
+
+    client.deactivate_event(&event_id, &DeactivationReason::SuspendedByPlatform);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(
+        client.try_deactivate_event(&event_id, &DeactivationReason::SoldOut),
+        Err(Ok(EventRegistryError::Unauthorized))
+    );
+
+    client.update_event_status(&event_id, &true);
+    assert_eq!(env.auths()[0].0, admin);
+    assert!(client.get_event(&event_id).unwrap().is_active);
+}
+
+#[test]
+fn test_completion_attestation_is_final() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    assert_eq!(
+        client.try_attest_event_completed(&event_id),
+        Err(Ok(EventRegistryError::CompletionAttestorNotSet))
+    );
+
+    let oracle = Address::generate(&env);
+    client.set_completion_attestor(&oracle, &1);
+    assert_eq!(client.get_completion_attestor(), Some(oracle.clone()));
+    // Organizers can't claim completion themselves
+    assert_eq!(
+        client.try_deactivate_event(&event_id, &DeactivationReason::Completed),
+        Err(Ok(EventRegistryError::Unauthorized))
+    );
+
+    client.attest_event_completed(&event_id);
+    assert_eq!(env.auths()[0].0, oracle);
+    assert!(!client.get_event(&event_id).unwrap().is_active);
+    assert_eq!(
+        client.get_deactivation_reason(&event_id),
+        Some(DeactivationReason::Completed)
+    );
+    assert_eq!(client.get_registry_stats().active_events, 0);
+
+    assert_eq!(
+        client.try_attest_event_completed(&event_id),
+        Err(Ok(EventRegistryError::EventCompleted))
+    );
+    assert_eq!(
+        client.try_update_event_status(&event_id, &true),
+        Err(Ok(EventRegistryError::EventCompleted))
+    );
+    assert_eq!(
+        client.try_deactivate_event(&event_id, &DeactivationReason::SuspendedByPlatform),
+        Err(Ok(EventRegistryError::EventCompleted))
+    );
+}
+
+#[test]
+fn test_build_info() {
+    let env = Env::default();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let info = client.get_build_info();
+    assert_eq!(
+        info.version,
+        String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    );
+    assert!(!info.git_commit.is_empty());
+}
+
+#[test]
+fn test_get_organizer_events_detailed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+
+    let organizer = Address::generate(&env);
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    for event_id in ["e1", "e2", "e3"] {
+        client.register_event(
+            &String::from_str(&env, event_id),
+            &organizer,
+            &organizer,
+            &metadata_cid,
+            &10,
+        );
+    }
+
+    let page = client.get_organizer_events_detailed(&organizer, &1, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().event_id, String::from_str(&env, "e2"));
+    assert_eq!(page.get(1).unwrap().max_supply, 10);
+
+    assert_eq!(
+        client
+            .get_organizer_events_detailed(&organizer, &0, &1)
+            .len(),
+        1
+    );
+    assert!(client
+        .get_organizer_events_detailed(&organizer, &5, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_register_event_rejects_malformed_strings() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+
+    let organizer = Address::generate(&env);
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    for bad_id in [
+        "",
+        "event 1",
+        "event/1",
+        "e1234567890123456789012345678901234567890123456789012345678901234",
+    ] {
+        let res = client.try_register_event(
+            &String::from_str(&env, bad_id),
+            &organizer,
+            &organizer,
+            &metadata_cid,
+            &0,
+        );
+        assert_eq!(res, Err(Ok(EventRegistryError::InvalidEventId)));
+    }
+
+    // Uppercase and '0'/'1'/'8'/'9' are outside the base32 alphabet
+    for bad_cid in [
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdI",
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz01",
+    ] {
+        let res = client.try_register_event(
+            &String::from_str(&env, "event_001"),
+            &organizer,
+            &organizer,
+            &String::from_str(&env, bad_cid),
+            &0,
+        );
+        assert_eq!(res, Err(Ok(EventRegistryError::InvalidMetadataCid)));
+    }
+
+    assert_eq!(
+        client.validate_registration(&String::from_str(&env, "bad id"), &metadata_cid, &0),
+        soroban_sdk::vec![&env, EventRegistryError::InvalidEventId as u32]
+    );
+    client.register_event(
+        &String::from_str(&env, "event-001_a"),
+        &organizer,
+        &organizer,
+        &metadata_cid,
+        &0,
+    );
+}
+
+#[test]
+fn test_subscription_plan_lowers_fee_until_expiry() {
+    use soroban_sdk::testutils::Ledger;
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    let organizer = client.get_event(&event_id).unwrap().organizer_address;
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &token_id).mint(&organizer, &1000);
+
+    let plan = SubscriptionPlan {
+        plan_id: 1,
+        fee_percent: 200,
+        price: 300,
+        token: token_id.clone(),
+        period_secs: 1000,
+    };
+    assert_eq!(
+        client.try_set_subscription_plan(
+            &SubscriptionPlan {
+                price: 0,
+                ..plan.clone()
+            },
+            &1
+        ),
+        Err(Ok(EventRegistryError::InvalidPlan))
+    );
+    client.set_subscription_plan(&plan, &1);
+    assert_eq!(
+        client.try_subscribe(&organizer, &2),
+        Err(Ok(EventRegistryError::PlanNotFound))
+    );
+
+    assert_eq!(
+        client
+            .get_event_payment_info(&event_id)
+            .platform_fee_percent,
+        500
+    );
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.subscribe(&organizer, &1);
+    assert_eq!(
+        client
+            .get_event_payment_info(&event_id)
+            .platform_fee_percent,
+        200
+    );
+
+    // Renewing before expiry extends from the current expiry
+    let renewed = client.subscribe(&organizer, &1);
+    assert_eq!(renewed.expires_at, 2100);
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token_id).balance(&organizer),
+        400
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 2100);
+    assert_eq!(
+        client
+            .get_event_payment_info(&event_id)
+            .platform_fee_percent,
+        500
+    );
+}
+
+#[test]
+fn test_tenant_namespaced_events_use_tenant_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_addon_event(&env);
+    let organizer = Address::generate(&env);
+    let tenant_admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+
+    let tenant = Tenant {
+        tenant_id: String::from_str(&env, "acme"),
+        admin: tenant_admin,
+        platform_fee_percent: 300,
+        accepted_tokens: soroban_sdk::vec![&env, token.clone()],
+    };
+    assert_eq!(
+        client.try_register_tenant(
+            &Tenant {
+                tenant_id: String::from_str(&env, "Acme"),
+                ..tenant.clone()
+            },
+            &1
+        ),
+        Err(Ok(EventRegistryError::InvalidTenant))
+    );
+    client.register_tenant(&tenant, &1);
+    assert_eq!(
+        client.try_register_tenant(&tenant, &2),
+        Err(Ok(EventRegistryError::InvalidTenant))
+    );
+
+    // Unknown prefixes and malformed separators are rejected
+    let res = client.try_register_event(
+        &String::from_str(&env, "other:gala"),
+        &organizer,
+        &organizer,
+        &metadata_cid,
+        &0,
+    );
+    assert_eq!(res, Err(Ok(EventRegistryError::TenantNotFound)));
+    let res = client.try_register_event(
+        &String::from_str(&env, "acme:gala:x"),
+        &organizer,
+        &organizer,
+        &metadata_cid,
+        &0,
+    );
+    assert_eq!(res, Err(Ok(EventRegistryError::InvalidEventId)));
+
+    let event_id = String::from_str(&env, "acme:gala");
+    client.register_event(&event_id, &organizer, &organizer, &metadata_cid, &0);
+    let info = client.get_event_payment_info(&event_id);
+    assert_eq!(info.platform_fee_percent, 300);
+    assert_eq!(info.accepted_tokens, soroban_sdk::vec![&env, token]);
+
+    // Token changes apply at once; fee changes only to later events
+    client.update_tenant(&tenant.tenant_id, &100, &soroban_sdk::vec![&env]);
+    let info = client.get_event_payment_info(&event_id);
+    assert_eq!(info.platform_fee_percent, 300);
+    assert!(info.accepted_tokens.is_empty());
+    let later = String::from_str(&env, "acme:expo");
+    client.register_event(&later, &organizer, &organizer, &metadata_cid, &0);
+    assert_eq!(
+        client.get_event_payment_info(&later).platform_fee_percent,
+        100
+    );
+}
+
+#[test]
+fn test_get_config_resolves_every_setting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    assert_eq!(
+        client.try_get_config(),
+        Err(Ok(EventRegistryError::NotInitialized))
+    );
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &600);
+    let config = client.get_config();
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.platform_wallet, platform_wallet);
+    assert_eq!(config.platform_fee_percent, 600);
+    assert_eq!(config.ticket_payment_contract, None);
+
+    let ticket_payment = Address::generate(&env);
+    client.set_ticket_payment_contract(&ticket_payment, &0);
+    assert_eq!(
+        client.get_config().ticket_payment_contract,
+        Some(ticket_payment)
+    );
+}
+
+#[test]
+fn test_metadata_reveal_checks_commitment_and_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+    client.set_ticket_payment_contract(&Address::generate(&env), &0);
+    let event_id = String::from_str(&env, "secret_lineup");
+    let public_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let hidden_cid = String::from_str(
+        &env,
+        "bafybeibwzifw52ttrkqlikfzext5akxu7lz4xiwjgwzmqcpdzmp3n5vnbe",
+    );
+    client.register_event(&event_id, &organizer, &organizer, &public_cid, &0);
+
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let mut preimage = Bytes::from_array(&env, &salt.to_array());
+    preimage.append(&hidden_cid.clone().into());
+    let commitment: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(
+        client.try_commit_metadata_reveal(&event_id, &commitment, &1_000),
+        Err(Ok(EventRegistryError::InvalidReveal))
+    );
+    client.commit_metadata_reveal(&event_id, &commitment, &5_000);
+    assert_eq!(
+        client.try_reveal_metadata(&event_id, &hidden_cid, &salt),
+        Err(Ok(EventRegistryError::RevealNotDue))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    assert_eq!(
+        client.try_reveal_metadata(&event_id, &public_cid, &salt),
+        Err(Ok(EventRegistryError::RevealMismatch))
+    );
+    client.reveal_metadata(&event_id, &hidden_cid, &salt);
+    assert_eq!(
+        client.get_metadata_reveal(&event_id).unwrap().revealed_cid,
+        Some(hidden_cid.clone())
+    );
+    assert_eq!(
+        client.try_reveal_metadata(&event_id, &hidden_cid, &salt),
+        Err(Ok(EventRegistryError::InvalidReveal))
+    );
+
+    // Commitments are refused once tickets have been sold
+    let sold = String::from_str(&env, "already_selling");
+    client.register_event(&sold, &organizer, &organizer, &public_cid, &0);
+    client.increment_inventory(&sold);
+    assert_eq!(
+        client.try_commit_metadata_reveal(&sold, &commitment, &9_000),
+        Err(Ok(EventRegistryError::InvalidReveal))
+    );
+}
+
+#[test]
+fn test_export_and_import_event_between_registries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, event_id) = setup_addon_event(&env);
+    source.set_event_addon(&event_id, &String::from_str(&env, "parking"), &200, &10);
+    let tag = String::from_str(&env, "music");
+    let partner_key = BytesN::from_array(&env, &[9; 32]);
+    source.set_event_tags(&event_id, &soroban_sdk::vec![&env, tag.clone()]);
+    source.set_event_partner_key(&event_id, &Some(partner_key.clone()));
+    source.increment_inventory(&event_id);
+    let export = source.export_event(&event_id);
+    assert_eq!(export.source_registry, source.address);
+    assert_eq!(export.event.current_supply, 1);
+
+    let target = EventRegistryClient::new(&env, &env.register(EventRegistry, ()));
+    target.initialize(&Address::generate(&env), &Address::generate(&env), &300);
+    let signing_key = SigningKey::from_bytes(&[3; 32]);
+    let sign = |export: &EventExport| {
+        let message = export.clone().to_xdr(&env);
+        let mut buf = [0u8; 4096];
+        let len = message.len() as usize;
+        message.copy_into_slice(&mut buf[..len]);
+        BytesN::from_array(&env, &signing_key.sign(&buf[..len]).to_bytes())
+    };
+    let signature = sign(&export);
+    assert_eq!(
+        target.try_import_event(&export, &signature),
+        Err(Ok(EventRegistryError::ImportSignerNotSet))
+    );
+    target.set_import_signer(
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+        &0,
+    );
+
+    let mut tampered = export.clone();
+    tampered.event.max_supply = 1_000_000;
+    assert!(target.try_import_event(&tampered, &signature).is_err());
+
+    // Signed exports still go through the registration checks
+    let mut foreign = export.clone();
+    foreign.event.event_id = String::from_str(&env, "acme:gala");
+    assert_eq!(
+        target.try_import_event(&foreign, &sign(&foreign)),
+        Err(Ok(EventRegistryError::TenantNotFound))
+    );
+
+    // The event takes the target's fee and keeps its tags and partner key
+    target.import_event(&export, &signature);
+    let imported = target.get_event(&event_id).unwrap();
+    assert_eq!(imported.platform_fee_percent, 300);
+    assert_eq!(imported.current_supply, 1);
+    assert_eq!(target.get_event_addons(&event_id), export.addons);
+    assert_eq!(target.get_event_partner_key(&event_id), Some(partner_key));
+    assert_eq!(
+        target.get_events_by_tag(&tag, &0, &10),
+        soroban_sdk::vec![&env, imported]
+    );
+    assert_eq!(target.get_registry_stats().total_events, 1);
+    assert_eq!(
+        target.try_import_event(&export, &signature),
+        Err(Ok(EventRegistryError::InvalidImport))
+    );
+}
+
+#[test]
+fn test_delete_and_restore_event_before_sales() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    client.set_event_addon(&event_id, &String::from_str(&env, "parking"), &200, &10);
+    let event = client.get_event(&event_id).unwrap();
+    let addons = client.get_event_addons(&event_id);
+
+    client.delete_event(&event_id);
+    assert_eq!(client.get_event(&event_id), None);
+    assert!(client.get_event_addons(&event_id).is_empty());
+    assert!(client
+        .get_organizer_events(&event.organizer_address)
+        .is_empty());
+    assert_eq!(client.get_registry_stats().total_events, 0);
+
+    client.restore_event(&event_id);
+    assert_eq!(client.get_event(&event_id), Some(event.clone()));
+    assert_eq!(client.get_event_addons(&event_id), addons);
+    assert_eq!(client.get_registry_stats().active_events, 1);
+    assert_eq!(
+        client.try_restore_event(&event_id),
+        Err(Ok(EventRegistryError::RestoreNotAvailable))
+    );
+
+    client.increment_inventory(&event_id);
+    assert_eq!(
+        client.try_delete_event(&event_id),
+        Err(Ok(EventRegistryError::EventHasSales))
+    );
+}
+
+#[test]
+fn test_deleted_event_id_is_reusable_after_restore_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    let event = client.get_event(&event_id).unwrap();
+
+    client.delete_event(&event_id);
+    env.ledger()
+        .with_mut(|li| li.timestamp += crate::RESTORE_WINDOW_SECS + 1);
+    assert_eq!(
+        client.try_restore_event(&event_id),
+        Err(Ok(EventRegistryError::RestoreNotAvailable))
+    );
+
+    let new_organizer = Address::generate(&env);
+    client.register_event(
+        &event_id,
+        &new_organizer,
+        &event.payment_address,
+        &event.metadata_cid,
+        &50,
+    );
+    assert_eq!(
+        client.get_event(&event_id).unwrap().organizer_address,
+        new_organizer
+    );
+}
+
+#[test]
+fn test_register_event_auto_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, event_id) = setup_addon_event(&env);
+    let event = client.get_event(&event_id).unwrap();
+    let organizer = event.organizer_address;
+
+    let first = client.register_event_auto_id(
+        &organizer,
+        &event.payment_address,
+        &event.metadata_cid,
+        &100,
+    );
+    let second = client.register_event_auto_id(
+        &organizer,
+        &event.payment_address,
+        &event.metadata_cid,
+        &100,
+    );
+    assert_ne!(first, second);
+    assert_eq!(first.len(), 11);
+    assert!(client
+        .validate_registration(&first, &event.metadata_cid, &0)
+        .contains(EventRegistryError::EventAlreadyExists as u32));
+    assert_eq!(
+        client.get_event(&second).unwrap().organizer_address,
+        organizer
+    );
+
+    // Nobody can claim the organizer's next auto ID ahead of them
+    let mut buf = [0u8; 11];
+    first.copy_into_slice(&mut buf);
+    buf[10] = b'3';
+    let next = String::from_bytes(&env, &buf);
+    assert_eq!(
+        client.try_register_event(
+            &next,
+            &Address::generate(&env),
+            &event.payment_address,
+            &event.metadata_cid,
+            &0,
+        ),
+        Err(Ok(EventRegistryError::ReservedEventId))
+    );
+    assert_eq!(
+        client.validate_registration(&next, &event.metadata_cid, &0),
+        soroban_sdk::vec![&env, EventRegistryError::ReservedEventId as u32]
+    );
+
+    // Another organizer gets a different prefix
+    let other = client.register_event_auto_id(
+        &Address::generate(&env),
+        &event.payment_address,
+        &event.metadata_cid,
+        &0,
+    );
+    assert_ne!(other, first);
+    assert_eq!(client.get_organizer_events(&organizer).len(), 3);
+}
+
+#[test]
+fn test_fee_setter_takes_fee_control_from_admin() {
+    let env = Env::default();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let governance = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin, &Address::generate(&env), &500);
+    client.set_fee_setter(&governance, &0);
+    assert_eq!(client.get_fee_setter(), Some(governance.clone()));
+    assert_eq!(client.get_config().fee_setter, Some(governance.clone()));
+
+    // The fee setter counts its own nonce from 0, apart from the admin's
+    assert_eq!(client.get_fee_nonce(), 0);
+    client.set_platform_fee(&300, &0);
+    assert_eq!(client.get_fee_nonce(), 1);
+    assert_eq!(client.get_admin_nonce(), 1);
+    let history = client.get_fee_history();
+    assert_eq!(history.last().unwrap().changed_by, governance);
+
+    // The admin's signature alone no longer moves the fee
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "set_platform_fee",
+            args: (100u32, 1u64).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_set_platform_fee(&100, &1).is_err());
+    assert_eq!(client.get_platform_fee(), 300);
+}
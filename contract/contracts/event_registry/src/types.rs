@@ -1,4 +1,25 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+
+/// Lifetime sales totals of an event (mirrors the Ticket Payment type).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventSales {
+    /// Purchases made, including ones later refunded or burned
+    pub tickets_sold: u32,
+    /// Tickets neither refunded nor burned
+    pub active_tickets: u32,
+    pub gross: i128,
+    pub fees: i128,
+    pub refunded: i128,
+}
+
+/// An organizer's event together with its sales, as shown on their dashboard.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerDashboardEntry {
+    pub event: EventInfo,
+    pub sales: EventSales,
+}
 
 /// Represents information about an event in the registry.
 #[contracttype]
@@ -10,11 +31,11 @@ pub struct EventInfo {
     pub organizer_address: Address,
     /// The address where payments for this event should be routed
     pub payment_address: Address,
-    /// The percentage fee taken by the platform (e.g., 5 for 5%)
+    /// Platform fee in basis points (500 = 5%, 10000 = 100%)
     pub platform_fee_percent: u32,
     /// Whether the event is currently active and accepting payments
     pub is_active: bool,
-    /// Timestamp when the event was created
+    /// Ledger timestamp (seconds) when the event was created
     pub created_at: u64,
     /// IPFS Content Identifier storing rich metadata details
     pub metadata_cid: String,
@@ -24,14 +45,177 @@ pub struct EventInfo {
     pub current_supply: i128,
 }
 
+/// Why an event stopped accepting payments.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeactivationReason {
+    SoldOut,
+    Postponed,
+    CancelledByOrganizer,
+    SuspendedByPlatform,
+    /// The completion attestor confirmed the event took place; final
+    Completed,
+}
+
 /// Payment information for an event
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PaymentInfo {
     /// The address where payments for this event should be routed
     pub payment_address: Address,
-    /// The percentage fee taken by the platform
+    /// Platform fee in basis points (500 = 5%, 10000 = 100%)
     pub platform_fee_percent: u32,
+    /// Current metadata CID, snapshotted into tickets at purchase
+    pub metadata_cid: String,
+    /// Co-hosts whose revenue share is split off the organizer amount
+    pub cohosts: Vec<CoHost>,
+    /// Tokens the event's tenant accepts; empty means any whitelisted token
+    pub accepted_tokens: Vec<Address>,
+    /// Ledger timestamp (seconds) when the event was registered
+    pub created_at: u64,
+    /// Opaque key a ticketing partner filters the event stream on
+    pub partner_key: Option<BytesN<32>>,
+    /// Whether a tenant or subscription plan set `platform_fee_percent`,
+    /// which then takes precedence over a platform-wide fee strategy
+    pub custom_fee: bool,
+}
+
+/// Co-host permission bit: must co-sign deactivating the event.
+pub const COHOST_CAN_CANCEL: u32 = 1;
+/// Co-host permission bit: must co-sign metadata and add-on edits.
+pub const COHOST_CAN_EDIT: u32 = 2;
+
+/// An additional host of an event sharing its revenue and control.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoHost {
+    /// The co-host's wallet address
+    pub address: Address,
+    /// Share of the organizer amount in basis points (10000 = 100%)
+    pub share_bps: u32,
+    /// Bitmask of `COHOST_CAN_*` actions requiring this co-host's approval
+    pub permissions: u32,
+}
+
+/// A non-ticket add-on (parking pass, merch, meal voucher) sold with an event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventAddon {
+    /// Organizer-chosen SKU, unique within the event
+    pub sku: String,
+    /// Unit price in token base units
+    pub price: i128,
+    /// Maximum number of units available (0 = unlimited)
+    pub max_supply: i128,
+    /// Number of units sold so far
+    pub current_supply: i128,
+}
+
+/// An add-on requested as part of a purchase.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddonSelection {
+    /// SKU of the add-on
+    pub sku: String,
+    /// Number of units requested
+    pub quantity: u32,
+}
+
+/// A reserved add-on line item, priced at reservation time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddonLineItem {
+    /// SKU of the add-on
+    pub sku: String,
+    /// Number of units reserved
+    pub quantity: u32,
+    /// Unit price charged
+    pub unit_price: i128,
+    /// Price of the whole line, `quantity` units at `unit_price`
+    pub line_total: i128,
+}
+
+/// An add-on selection that could not be reserved, reported by a dry run.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddonViolation {
+    /// SKU of the offending selection
+    pub sku: String,
+    /// `EventRegistryError` code the reservation would fail with
+    pub code: u32,
+    /// Units requested
+    pub requested: u32,
+    /// Units still available (-1 if unlimited or unknown)
+    pub available: i128,
+}
+
+/// Tickets reserved for a buyer during checkout (temporary storage).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InventoryHold {
+    /// Number of tickets held
+    pub quantity: u32,
+    /// Timestamp after which the hold no longer counts against supply
+    pub expires_at: u64,
+}
+
+/// A platform fee change, kept so past fees can be looked up.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeChange {
+    /// Platform fee in basis points from `effective_from` on
+    pub fee_percent: u32,
+    /// Ledger timestamp at which the fee took effect
+    pub effective_from: u64,
+    /// Admin that set the fee
+    pub changed_by: Address,
+}
+
+/// Canonical snapshot of an event for moving it between registry deployments
+/// (e.g. a testnet rehearsal to mainnet, or a v1 registry to v2).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventExport {
+    /// Registry the event was exported from
+    pub source_registry: Address,
+    /// Network the source registry runs on
+    pub network_id: BytesN<32>,
+    /// The event, including its sold supply
+    pub event: EventInfo,
+    /// The event's add-ons and their sold quantities
+    pub addons: Vec<EventAddon>,
+    /// The event's co-hosts
+    pub cohosts: Vec<CoHost>,
+    /// The event's partner key, if any
+    pub partner_key: Option<BytesN<32>>,
+    /// The event's tags
+    pub tags: Vec<String>,
+    /// Ledger timestamp (seconds) of the export
+    pub exported_at: u64,
+}
+
+/// An event deleted before any sales, kept (in temporary storage) until its
+/// restore window closes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeletedEvent {
+    pub event: EventInfo,
+    pub addons: Vec<EventAddon>,
+    pub cohosts: Vec<CoHost>,
+    pub partner_key: Option<BytesN<32>>,
+    pub tags: Vec<String>,
+    /// Ledger timestamp (seconds) after which the event can no longer be restored
+    pub restore_until: u64,
+}
+
+/// Running event counters maintained by the registry.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegistryStats {
+    /// Total number of registered events, less any deleted ones
+    pub total_events: u64,
+    /// Number of events currently accepting payments
+    pub active_events: u64,
 }
 
 /// Storage keys for the Event Registry contract.
@@ -51,4 +235,136 @@ pub enum DataKey {
     OrganizerEvents(Address),
     /// The authorized TicketPayment contract address for inventory updates
     TicketPaymentContract,
+    /// Running registry-wide event counters
+    RegistryStats,
+    /// Mapping of (event_id, sku) to EventAddon (Persistent)
+    EventAddon(String, String),
+    /// Mapping of event_id to the list of its add-on SKUs (Persistent)
+    EventAddons(String),
+    /// Mapping of event_id to its co-hosts (Persistent)
+    EventCoHosts(String),
+    /// Chronological list of platform fee changes
+    FeeHistory,
+    /// Mapping of event_id to why it was deactivated (Persistent)
+    DeactivationReason(String),
+    /// Mapping of (event_id, buyer) to an InventoryHold (Temporary)
+    InventoryHold(String, Address),
+    /// Mapping of event_id to buyers that may hold inventory (Temporary)
+    EventHolders(String),
+    /// Mapping of plan_id to SubscriptionPlan (Persistent)
+    SubscriptionPlan(u32),
+    /// Mapping of organizer_address to OrganizerSubscription (Persistent)
+    OrganizerSubscription(Address),
+    /// Mapping of tenant_id to Tenant (Persistent)
+    Tenant(String),
+    /// Mapping of event_id to its MetadataReveal (Persistent)
+    MetadataReveal(String),
+    /// ed25519 public key that signs event exports accepted by `import_event`
+    ImportSigner,
+    /// Mapping of event_id to a DeletedEvent awaiting restore (Temporary)
+    DeletedEvent(String),
+    /// Mapping of organizer_address to the last auto-generated event ID number
+    OrganizerIdCounter(Address),
+    /// Address that controls the platform fee in place of the admin
+    FeeSetter,
+    /// Mapping of event_id to the partner key added to its event topics (Persistent)
+    PartnerKey(String),
+    /// Oracle address allowed to attest that events have taken place
+    CompletionAttestor,
+    /// Contract addresses barred from the registry's callbacks (Persistent)
+    RevokedCaller(Address),
+    /// Nonce the next fee change, upgrade or role grant must carry
+    AdminNonce,
+    /// Nonce the fee setter's next fee change or hand-over must carry (Persistent)
+    FeeSetterNonce,
+    /// Mapping of event_id to its tags (Persistent)
+    EventTags(String),
+    /// Mapping of a tag to the IDs of the events carrying it (Persistent)
+    TagEvents(String),
+    /// sha256 of an `EventExport` that has already been imported (Persistent)
+    ImportedExport(BytesN<32>),
+}
+
+/// Hidden metadata (e.g. a surprise lineup) committed to before sales and
+/// revealed once `reveal_at` has passed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataReveal {
+    /// sha256 of the 32-byte salt followed by the hidden metadata CID
+    pub commitment: BytesN<32>,
+    /// Ledger timestamp (seconds) from which the CID may be revealed
+    pub reveal_at: u64,
+    /// The revealed CID, once published
+    pub revealed_cid: Option<String>,
+}
+
+/// A white-label deployment sharing this registry. Events whose ID starts
+/// with `<tenant_id>:` belong to the tenant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tenant {
+    /// Namespace prefix of the tenant's event IDs
+    pub tenant_id: String,
+    /// Address allowed to change the tenant's configuration
+    pub admin: Address,
+    /// Platform fee in basis points for events registered under the tenant
+    pub platform_fee_percent: u32,
+    /// Tokens accepted for the tenant's events; empty means any whitelisted token
+    pub accepted_tokens: Vec<Address>,
+}
+
+/// A subscription organizers can pre-pay for a lower platform fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionPlan {
+    /// Identifier of the plan
+    pub plan_id: u32,
+    /// Platform fee in basis points while the plan is active
+    pub fee_percent: u32,
+    /// Price of one period
+    pub price: i128,
+    /// Token the price is paid in
+    pub token: Address,
+    /// Length of one period in seconds
+    pub period_secs: u64,
+}
+
+/// An organizer's current plan and when it lapses.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerSubscription {
+    /// Plan the organizer paid for
+    pub plan_id: u32,
+    /// Ledger timestamp at which the plan stops applying
+    pub expires_at: u64,
+}
+
+/// Resolved configuration of the registry, for verifying a deployment in one read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryConfig {
+    /// The administrator address
+    pub admin: Address,
+    /// The platform wallet address
+    pub platform_wallet: Address,
+    /// Platform fee in basis points for newly registered events
+    pub platform_fee_percent: u32,
+    /// The linked TicketPayment contract, if registered yet
+    pub ticket_payment_contract: Option<Address>,
+    /// Address controlling the platform fee, if not the admin
+    pub fee_setter: Option<Address>,
+    /// Build provenance of this WASM
+    pub build: BuildInfo,
+}
+
+/// Build provenance of the deployed WASM, for matching an instance to source.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildInfo {
+    /// Crate semver
+    pub version: String,
+    /// `AGORA_GIT_COMMIT` at build time, or "unknown"
+    pub git_commit: String,
+    /// "release" or "debug"
+    pub profile: String,
 }
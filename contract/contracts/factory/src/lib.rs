@@ -0,0 +1,128 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, BytesN, Env};
+
+// Event Registry interface
+pub mod event_registry {
+    use soroban_sdk::{contractclient, Address, Env};
+
+    #[contractclient(name = "Client")]
+    pub trait EventRegistryInterface {
+        fn initialize(
+            env: Env,
+            admin: Address,
+            platform_wallet: Address,
+            platform_fee_percent: u32,
+        );
+        fn set_ticket_payment_contract(env: Env, ticket_payment_address: Address, nonce: u64);
+    }
+}
+
+// Ticket Payment interface
+pub mod ticket_payment {
+    use soroban_sdk::{contractclient, Address, Env};
+
+    #[contractclient(name = "Client")]
+    pub trait TicketPaymentInterface {
+        fn initialize(
+            env: Env,
+            admin: Address,
+            usdc_token: Address,
+            platform_wallet: Address,
+            event_registry: Address,
+        );
+    }
+}
+
+/// Addresses of a registry and payment contract deployed as a linked set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkedDeployment {
+    /// The Event Registry instance
+    pub event_registry: Address,
+    /// The TicketPayment instance wired to `event_registry`
+    pub ticket_payment: Address,
+}
+
+#[contract]
+pub struct AgoraFactory;
+
+#[contractimpl]
+impl AgoraFactory {
+    /// Returns the addresses `deploy_linked` will use for `salt`, so
+    /// front-ends and scripts can be configured before deploying.
+    pub fn get_linked_addresses(env: Env, salt: BytesN<32>) -> LinkedDeployment {
+        LinkedDeployment {
+            event_registry: env
+                .deployer()
+                .with_current_contract(component_salt(&env, &salt, b"registry"))
+                .deployed_address(),
+            ticket_payment: env
+                .deployer()
+                .with_current_contract(component_salt(&env, &salt, b"payment"))
+                .deployed_address(),
+        }
+    }
+
+    /// Deploys an Event Registry and a TicketPayment contract, initializes
+    /// both and points each at the other in one transaction, so a
+    /// deployment can never be left half-wired. Requires `admin`'s signature,
+    /// which is also needed by the registry to register the payment contract.
+    ///
+    /// # Arguments
+    /// * `admin` - Administrator of both contracts.
+    /// * `registry_wasm_hash` - Uploaded Event Registry WASM.
+    /// * `payment_wasm_hash` - Uploaded TicketPayment WASM.
+    /// * `salt` - Deployment salt; see `get_linked_addresses`.
+    /// * `usdc_token` - Token whitelisted by default for payments.
+    /// * `platform_wallet` - Wallet receiving platform fees.
+    /// * `platform_fee_percent` - Initial platform fee in basis points.
+    #[allow(clippy::too_many_arguments)]
+    pub fn deploy_linked(
+        env: Env,
+        admin: Address,
+        registry_wasm_hash: BytesN<32>,
+        payment_wasm_hash: BytesN<32>,
+        salt: BytesN<32>,
+        usdc_token: Address,
+        platform_wallet: Address,
+        platform_fee_percent: u32,
+    ) -> LinkedDeployment {
+        admin.require_auth();
+
+        let event_registry = env
+            .deployer()
+            .with_current_contract(component_salt(&env, &salt, b"registry"))
+            .deploy_v2(registry_wasm_hash, ());
+        let ticket_payment = env
+            .deployer()
+            .with_current_contract(component_salt(&env, &salt, b"payment"))
+            .deploy_v2(payment_wasm_hash, ());
+
+        let registry_client = event_registry::Client::new(&env, &event_registry);
+        registry_client.initialize(&admin, &platform_wallet, &platform_fee_percent);
+        ticket_payment::Client::new(&env, &ticket_payment).initialize(
+            &admin,
+            &usdc_token,
+            &platform_wallet,
+            &event_registry,
+        );
+        // First nonce-protected call on the fresh registry
+        registry_client.set_ticket_payment_contract(&ticket_payment, &0);
+
+        LinkedDeployment {
+            event_registry,
+            ticket_payment,
+        }
+    }
+}
+
+/// Derives a per-contract salt so one caller-supplied salt covers the set.
+fn component_salt(env: &Env, salt: &BytesN<32>, component: &[u8]) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &salt.to_array());
+    preimage.extend_from_slice(component);
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,22 @@
+use super::*;
+use soroban_sdk::{BytesN, Env};
+
+#[test]
+fn test_linked_addresses_are_deterministic_and_distinct() {
+    let env = Env::default();
+    let contract_id = env.register(AgoraFactory, ());
+    let client = AgoraFactoryClient::new(&env, &contract_id);
+
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let linked = client.get_linked_addresses(&salt);
+    assert_eq!(client.get_linked_addresses(&salt), linked);
+    assert_ne!(linked.event_registry, linked.ticket_payment);
+
+    let other = client.get_linked_addresses(&BytesN::from_array(&env, &[8; 32]));
+    assert_ne!(other.event_registry, linked.event_registry);
+    assert_ne!(other.ticket_payment, linked.ticket_payment);
+
+    // A different factory instance derives different addresses
+    let other_factory = AgoraFactoryClient::new(&env, &env.register(AgoraFactory, ()));
+    assert_ne!(other_factory.get_linked_addresses(&salt), linked);
+}
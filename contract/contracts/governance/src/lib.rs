@@ -0,0 +1,239 @@
+#![no_std]
+
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Vec};
+
+// Event Registry interface
+pub mod event_registry {
+    use soroban_sdk::{contractclient, Env};
+
+    #[contractclient(name = "Client")]
+    pub trait EventRegistryInterface {
+        fn set_platform_fee(env: Env, new_fee_percent: u32, nonce: u64);
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GovernanceError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    InvalidCouncil = 3,
+    NotCouncilMember = 4,
+    InvalidFeePercent = 5,
+    ProposalNotFound = 6,
+    AlreadyApproved = 7,
+    ProposalExpired = 8,
+    ThresholdNotMet = 9,
+    AlreadyExecuted = 10,
+}
+
+/// Council that governs platform parameters, and the registry it controls.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    /// Addresses allowed to propose and approve changes
+    pub council: Vec<Address>,
+    /// Approvals needed before a proposal can be executed
+    pub threshold: u32,
+    /// How long a proposal stays open, in seconds
+    pub voting_period_secs: u64,
+    /// Event Registry whose fee setter is this contract
+    pub event_registry: Address,
+}
+
+/// A proposed platform fee change and the council members backing it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeProposal {
+    pub proposal_id: u32,
+    pub proposer: Address,
+    /// New platform fee in basis points (500 = 5%)
+    pub new_fee_percent: u32,
+    /// Council members who approved, the proposer included
+    pub approvals: Vec<Address>,
+    /// Ledger timestamp (seconds) after which the proposal can't be approved or executed
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Config,           // GovernanceConfig
+    ProposalCount,    // Number of proposals created
+    FeeProposal(u32), // proposal_id -> FeeProposal
+    FeeNonce,         // Next nonce passed to the registry's fee setter calls
+}
+
+#[contract]
+pub struct AgoraGovernance;
+
+#[contractimpl]
+impl AgoraGovernance {
+    /// Sets up the council. Point the registry's fee setter at this contract
+    /// (`set_fee_setter`) afterwards to hand it control of the platform fee.
+    ///
+    /// # Arguments
+    /// * `council` - Distinct addresses allowed to propose and approve.
+    /// * `threshold` - Approvals required to execute, 1..=council size.
+    /// * `voting_period_secs` - How long each proposal stays open.
+    /// * `event_registry` - Event Registry to govern.
+    pub fn initialize(
+        env: Env,
+        council: Vec<Address>,
+        threshold: u32,
+        voting_period_secs: u64,
+        event_registry: Address,
+    ) -> Result<(), GovernanceError> {
+        if env.storage().persistent().has(&DataKey::Config) {
+            return Err(GovernanceError::AlreadyInitialized);
+        }
+        for (i, member) in council.iter().enumerate() {
+            if council.first_index_of(&member) != Some(i as u32) {
+                return Err(GovernanceError::InvalidCouncil);
+            }
+        }
+        if threshold == 0 || threshold > council.len() || voting_period_secs == 0 {
+            return Err(GovernanceError::InvalidCouncil);
+        }
+
+        env.storage().persistent().set(
+            &DataKey::Config,
+            &GovernanceConfig {
+                council,
+                threshold,
+                voting_period_secs,
+                event_registry,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the council, threshold and governed registry.
+    pub fn get_config(env: Env) -> Result<GovernanceConfig, GovernanceError> {
+        get_config(&env)
+    }
+
+    /// Opens a proposal to change the platform fee, counting the proposer's
+    /// approval. Returns the new proposal's ID.
+    pub fn propose_fee_change(
+        env: Env,
+        proposer: Address,
+        new_fee_percent: u32,
+    ) -> Result<u32, GovernanceError> {
+        proposer.require_auth();
+        let config = get_config(&env)?;
+        if !config.council.contains(&proposer) {
+            return Err(GovernanceError::NotCouncilMember);
+        }
+        if new_fee_percent > 10000 {
+            return Err(GovernanceError::InvalidFeePercent);
+        }
+
+        let proposal_id: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProposalCount)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalCount, &proposal_id);
+
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(proposer.clone());
+        set_proposal(
+            &env,
+            &FeeProposal {
+                proposal_id,
+                proposer,
+                new_fee_percent,
+                approvals,
+                expires_at: env.ledger().timestamp() + config.voting_period_secs,
+                executed: false,
+            },
+        );
+        Ok(proposal_id)
+    }
+
+    /// Records a council member's approval of an open proposal.
+    pub fn approve(env: Env, voter: Address, proposal_id: u32) -> Result<(), GovernanceError> {
+        voter.require_auth();
+        let config = get_config(&env)?;
+        if !config.council.contains(&voter) {
+            return Err(GovernanceError::NotCouncilMember);
+        }
+        let mut proposal = get_open_proposal(&env, proposal_id)?;
+        if proposal.approvals.contains(&voter) {
+            return Err(GovernanceError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(voter);
+        set_proposal(&env, &proposal);
+        Ok(())
+    }
+
+    /// Applies an open proposal that has reached the approval threshold by
+    /// setting the fee on the registry, with this contract's own fee setter
+    /// nonce. Anyone may call it.
+    pub fn execute(env: Env, proposal_id: u32) -> Result<(), GovernanceError> {
+        let config = get_config(&env)?;
+        let mut proposal = get_open_proposal(&env, proposal_id)?;
+        if proposal.approvals.len() < config.threshold {
+            return Err(GovernanceError::ThresholdNotMet);
+        }
+
+        proposal.executed = true;
+        set_proposal(&env, &proposal);
+        let nonce: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FeeNonce)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::FeeNonce, &(nonce + 1));
+        event_registry::Client::new(&env, &config.event_registry)
+            .set_platform_fee(&proposal.new_fee_percent, &nonce);
+        Ok(())
+    }
+
+    /// Returns a proposal by ID.
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Option<FeeProposal> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeProposal(proposal_id))
+    }
+}
+
+fn get_config(env: &Env) -> Result<GovernanceConfig, GovernanceError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Config)
+        .ok_or(GovernanceError::NotInitialized)
+}
+
+fn set_proposal(env: &Env, proposal: &FeeProposal) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeProposal(proposal.proposal_id), proposal);
+}
+
+/// Loads a proposal that can still be approved or executed.
+fn get_open_proposal(env: &Env, proposal_id: u32) -> Result<FeeProposal, GovernanceError> {
+    let proposal: FeeProposal = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FeeProposal(proposal_id))
+        .ok_or(GovernanceError::ProposalNotFound)?;
+    if proposal.executed {
+        return Err(GovernanceError::AlreadyExecuted);
+    }
+    if env.ledger().timestamp() > proposal.expires_at {
+        return Err(GovernanceError::ProposalExpired);
+    }
+    Ok(proposal)
+}
+
+#[cfg(test)]
+mod test;
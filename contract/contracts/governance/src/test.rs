@@ -0,0 +1,158 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, Symbol,
+};
+
+// Registry stand-in that, like the real one, only accepts fee changes
+// authorized by its fee setter, carrying the setter's own nonce
+#[contract]
+pub struct MockEventRegistry;
+
+#[contractimpl]
+impl MockEventRegistry {
+    pub fn get_fee_nonce(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "nonce"))
+            .unwrap_or(0)
+    }
+
+    pub fn set_fee_setter(env: Env, fee_setter: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "setter"), &fee_setter);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "nonce"), &0u64);
+    }
+
+    pub fn set_platform_fee(env: Env, new_fee_percent: u32, nonce: u64) {
+        let fee_setter: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "setter"))
+            .unwrap();
+        fee_setter.require_auth();
+        assert_eq!(nonce, Self::get_fee_nonce(env.clone()));
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "nonce"), &(nonce + 1));
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "fee"), &new_fee_percent);
+    }
+
+    pub fn get_platform_fee(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "fee"))
+            .unwrap_or(500)
+    }
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    AgoraGovernanceClient<'static>,
+    MockEventRegistryClient<'static>,
+    Vec<Address>,
+) {
+    let registry = MockEventRegistryClient::new(env, &env.register(MockEventRegistry, ()));
+    let client = AgoraGovernanceClient::new(env, &env.register(AgoraGovernance, ()));
+    let council = vec![
+        env,
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    client.initialize(&council, &2, &86_400, &registry.address);
+    registry.set_fee_setter(&client.address);
+    (client, registry, council)
+}
+
+#[test]
+fn test_initialize_rejects_bad_council() {
+    let env = Env::default();
+    let client = AgoraGovernanceClient::new(&env, &env.register(AgoraGovernance, ()));
+    let member = Address::generate(&env);
+    let registry = Address::generate(&env);
+
+    let duplicated = vec![&env, member.clone(), member.clone()];
+    assert_eq!(
+        client.try_initialize(&duplicated, &1, &86_400, &registry),
+        Err(Ok(GovernanceError::InvalidCouncil))
+    );
+    let single = vec![&env, member];
+    assert_eq!(
+        client.try_initialize(&single, &2, &86_400, &registry),
+        Err(Ok(GovernanceError::InvalidCouncil))
+    );
+
+    client.initialize(&single, &1, &86_400, &registry);
+    assert_eq!(
+        client.try_initialize(&single, &1, &86_400, &registry),
+        Err(Ok(GovernanceError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_fee_change_executes_once_threshold_is_met() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, registry, council) = setup(&env);
+
+    let proposal_id = client.propose_fee_change(&council.get(0).unwrap(), &250);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(GovernanceError::ThresholdNotMet))
+    );
+    assert_eq!(
+        client.try_approve(&council.get(0).unwrap(), &proposal_id),
+        Err(Ok(GovernanceError::AlreadyApproved))
+    );
+    assert_eq!(
+        client.try_approve(&Address::generate(&env), &proposal_id),
+        Err(Ok(GovernanceError::NotCouncilMember))
+    );
+
+    client.approve(&council.get(1).unwrap(), &proposal_id);
+    client.execute(&proposal_id);
+    assert_eq!(registry.get_platform_fee(), 250);
+    assert!(client.get_proposal(&proposal_id).unwrap().executed);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(GovernanceError::AlreadyExecuted))
+    );
+
+    // Each execution carries the next of governance's own nonces
+    let proposal_id = client.propose_fee_change(&council.get(1).unwrap(), &300);
+    client.approve(&council.get(2).unwrap(), &proposal_id);
+    client.execute(&proposal_id);
+    assert_eq!(registry.get_platform_fee(), 300);
+    assert_eq!(registry.get_fee_nonce(), 2);
+}
+
+#[test]
+fn test_expired_proposal_cannot_be_approved_or_executed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, registry, council) = setup(&env);
+
+    assert_eq!(
+        client.try_propose_fee_change(&council.get(0).unwrap(), &10_001),
+        Err(Ok(GovernanceError::InvalidFeePercent))
+    );
+    let proposal_id = client.propose_fee_change(&council.get(0).unwrap(), &900);
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+
+    assert_eq!(
+        client.try_approve(&council.get(1).unwrap(), &proposal_id),
+        Err(Ok(GovernanceError::ProposalExpired))
+    );
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(GovernanceError::ProposalExpired))
+    );
+    assert_eq!(registry.get_platform_fee(), 500);
+}
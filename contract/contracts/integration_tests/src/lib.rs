@@ -0,0 +1,10 @@
+//! End-to-end scenarios that deploy the real Event Registry and Ticket
+//! Payment contracts side by side. The scenarios live in `tests/`; this crate
+//! has no code of its own.
+//!
+//! Each contract's unit tests call the other through a mock, so they can't
+//! notice when the two drift apart: a renamed field in `PaymentInfo`, a
+//! reordered error code, a changed `reserve_addons` signature. Here every
+//! cross-contract call goes through the host with the real types on both
+//! sides.
+#![no_std]
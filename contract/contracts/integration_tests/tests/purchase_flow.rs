@@ -0,0 +1,512 @@
+use event_registry::error::EventRegistryError;
+use event_registry::types::{
+    AddonSelection as RegistryAddonSelection, CoHost, SubscriptionPlan, Tenant,
+};
+use event_registry::{EventRegistry, EventRegistryClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+use ticket_payment::contract::{TicketPaymentContract, TicketPaymentContractClient};
+use ticket_payment::error::TicketPaymentError;
+use ticket_payment::types::{
+    AddonSelection, FeeStrategy, PaymentStatus, PurchaseExtra, PurchaseOptions, RefundReason,
+};
+
+const METADATA_CID: &str = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+struct Platform<'a> {
+    env: Env,
+    registry: EventRegistryClient<'a>,
+    payment: TicketPaymentContractClient<'a>,
+    usdc: token::Client<'a>,
+    usdc_admin: token::StellarAssetClient<'a>,
+    platform_wallet: Address,
+}
+
+impl Platform<'_> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let platform_wallet = Address::generate(&env);
+        let usdc_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let registry = EventRegistryClient::new(&env, &env.register(EventRegistry, ()));
+        let payment =
+            TicketPaymentContractClient::new(&env, &env.register(TicketPaymentContract, ()));
+
+        registry.initialize(&admin, &platform_wallet, &500);
+        payment.initialize(&admin, &usdc_id, &platform_wallet, &registry.address);
+        registry.set_ticket_payment_contract(&payment.address, &0);
+
+        Platform {
+            usdc: token::Client::new(&env, &usdc_id),
+            usdc_admin: token::StellarAssetClient::new(&env, &usdc_id),
+            env,
+            registry,
+            payment,
+            platform_wallet,
+        }
+    }
+
+    fn str(&self, value: &str) -> String {
+        String::from_str(&self.env, value)
+    }
+
+    fn funded_buyer(&self, amount: i128) -> Address {
+        let buyer = Address::generate(&self.env);
+        self.usdc_admin.mint(&buyer, &amount);
+        buyer
+    }
+
+    fn try_buy(
+        &self,
+        payment_id: &str,
+        event_id: &str,
+        buyer: &Address,
+        addons: &[(&str, u32)],
+    ) -> Result<(), TicketPaymentError> {
+        let mut selections = vec![&self.env];
+        for (sku, quantity) in addons {
+            selections.push_back(AddonSelection {
+                sku: self.str(sku),
+                quantity: *quantity,
+            });
+        }
+        match self.payment.try_process_payment_with_options(
+            &self.str(payment_id),
+            &self.str(event_id),
+            &self.str("general"),
+            buyer,
+            &self.usdc.address,
+            &1000,
+            &PurchaseOptions {
+                expires_at: u64::MAX,
+                extras: vec![&self.env, PurchaseExtra::Addons(selections)],
+            },
+        ) {
+            Ok(Ok(_)) => Ok(()),
+            Err(Ok(error)) => Err(error),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_purchase_sell_out_refund_and_settle() {
+    let platform = Platform::new();
+    let env = &platform.env;
+    let organizer = Address::generate(env);
+    let payout = Address::generate(env);
+    let cohost = Address::generate(env);
+    let event_id = platform.str("launch_party");
+
+    platform.registry.register_event(
+        &event_id,
+        &organizer,
+        &payout,
+        &platform.str(METADATA_CID),
+        &100,
+    );
+    platform.registry.set_event_cohosts(
+        &event_id,
+        &vec![
+            env,
+            CoHost {
+                address: cohost.clone(),
+                share_bps: 2000,
+                permissions: 0,
+            },
+        ],
+    );
+    platform
+        .registry
+        .set_event_addon(&event_id, &platform.str("parking"), &50, &1);
+
+    // The registry prices and reserves the add-on; the buyer pays ticket and
+    // add-on together, split between the platform, co-host and organizer
+    let buyer = platform.funded_buyer(5000);
+    platform
+        .try_buy("p1", "launch_party", &buyer, &[("parking", 1)])
+        .unwrap();
+    let paid = 1050;
+    assert_eq!(platform.usdc.balance(&buyer), 5000 - paid);
+    let fee = platform.usdc.balance(&platform.platform_wallet);
+    assert!(fee > 0);
+    assert!(platform.usdc.balance(&cohost) > 0);
+    assert_eq!(
+        fee + platform.usdc.balance(&cohost)
+            + platform.usdc.balance(&payout)
+            + platform.usdc.balance(&platform.payment.address),
+        paid
+    );
+    let addons = platform.registry.get_event_addons(&event_id);
+    assert_eq!(addons.get(0).unwrap().current_supply, 1);
+
+    // Registry error codes surface as the payment contract's own errors
+    let second_buyer = platform.funded_buyer(5000);
+    assert_eq!(
+        platform.try_buy("p2", "launch_party", &second_buyer, &[("parking", 1)]),
+        Err(TicketPaymentError::AddonSoldOut)
+    );
+    assert_eq!(
+        platform.try_buy("p2", "launch_party", &second_buyer, &[("valet", 1)]),
+        Err(TicketPaymentError::AddonNotFound)
+    );
+    assert_eq!(
+        platform.try_buy("p2", "no_such_event", &second_buyer, &[]),
+        Err(TicketPaymentError::EventNotFound)
+    );
+    // The dry run agrees with the failed purchase
+    let violations = platform.registry.validate_addon_selection(
+        &event_id,
+        &vec![
+            env,
+            RegistryAddonSelection {
+                sku: platform.str("parking"),
+                quantity: 1,
+            },
+        ],
+    );
+    assert_eq!(
+        violations.get(0).unwrap().code,
+        EventRegistryError::AddonSoldOut as u32
+    );
+
+    // Refunds are paid by the organizer, platform fee included
+    platform.usdc_admin.mint(&payout, &paid);
+    platform.payment.refund_payments(
+        &event_id,
+        &vec![env, platform.str("p1")],
+        &RefundReason::Other,
+    );
+    assert_eq!(platform.usdc.balance(&buyer), 5000);
+    assert_eq!(
+        platform
+            .payment
+            .get_payment_status(&platform.str("p1"))
+            .unwrap()
+            .status,
+        PaymentStatus::Refunded
+    );
+
+    // The organizer's dashboard joins registry events with payment totals
+    let dashboard = platform
+        .registry
+        .get_organizer_dashboard(&organizer, &0, &10);
+    assert_eq!(dashboard.len(), 1);
+    let entry = dashboard.get(0).unwrap();
+    assert_eq!(entry.event.event_id, event_id);
+    assert_eq!(entry.sales.tickets_sold, 1);
+    assert_eq!(entry.sales.active_tickets, 0);
+    assert_eq!(entry.sales.gross, paid);
+    assert_eq!(entry.sales.fees, fee);
+    assert_eq!(entry.sales.refunded, paid);
+
+    // Platform stats combine both contracts' counters
+    let stats = platform.payment.get_platform_stats();
+    assert_eq!(stats.events_registered, 1);
+    assert_eq!(stats.active_events, 1);
+    assert_eq!(stats.total_payments, 1);
+    assert_eq!(stats.total_refunded, paid);
+}
+
+#[test]
+fn test_deactivated_event_stops_sales() {
+    let platform = Platform::new();
+    let env = &platform.env;
+    let organizer = Address::generate(env);
+    let event_id = platform.str("closing_night");
+    platform.registry.register_event(
+        &event_id,
+        &organizer,
+        &Address::generate(env),
+        &platform.str(METADATA_CID),
+        &0,
+    );
+
+    let buyer = platform.funded_buyer(5000);
+    platform
+        .try_buy("p1", "closing_night", &buyer, &[])
+        .unwrap();
+
+    platform.registry.update_event_status(&event_id, &false);
+    assert_eq!(
+        platform.try_buy("p2", "closing_night", &buyer, &[]),
+        Err(TicketPaymentError::EventInactive)
+    );
+    assert_eq!(platform.payment.get_platform_stats().active_events, 0);
+}
+
+#[test]
+fn test_event_with_sales_cannot_be_deleted() {
+    let platform = Platform::new();
+    let env = &platform.env;
+    let organizer = Address::generate(env);
+    let event_id = platform.str("soft_launch");
+    platform.registry.register_event(
+        &event_id,
+        &organizer,
+        &Address::generate(env),
+        &platform.str(METADATA_CID),
+        &0,
+    );
+
+    let buyer = platform.funded_buyer(5000);
+    platform.try_buy("p1", "soft_launch", &buyer, &[]).unwrap();
+    assert_eq!(
+        platform.registry.try_delete_event(&event_id),
+        Err(Ok(EventRegistryError::EventHasSales))
+    );
+}
+
+#[test]
+fn test_purchase_consumes_checkout_hold() {
+    let platform = Platform::new();
+    let env = &platform.env;
+    let organizer = Address::generate(env);
+    let event_id = platform.str("matinee");
+    platform.registry.register_event(
+        &event_id,
+        &organizer,
+        &Address::generate(env),
+        &platform.str(METADATA_CID),
+        &2,
+    );
+
+    // The buyer holds both tickets, so nobody else can hold one
+    let buyer = platform.funded_buyer(5000);
+    platform
+        .registry
+        .hold_inventory(&event_id, &buyer, &2, &300);
+    assert_eq!(
+        platform
+            .registry
+            .try_hold_inventory(&event_id, &Address::generate(env), &1, &300),
+        Err(Ok(EventRegistryError::MaxSupplyExceeded))
+    );
+
+    // Each purchase turns one held ticket into a sale
+    platform.try_buy("p1", "matinee", &buyer, &[]).unwrap();
+    assert_eq!(
+        platform
+            .registry
+            .get_inventory_hold(&event_id, &buyer)
+            .unwrap()
+            .quantity,
+        1
+    );
+    platform.try_buy("p2", "matinee", &buyer, &[]).unwrap();
+    assert_eq!(
+        platform.registry.get_inventory_hold(&event_id, &buyer),
+        None
+    );
+    assert_eq!(
+        platform
+            .registry
+            .get_event(&event_id)
+            .unwrap()
+            .current_supply,
+        2
+    );
+}
+
+#[test]
+fn test_unheld_buyer_hits_sold_out_after_holds_fill_capacity() {
+    let platform = Platform::new();
+    let env = &platform.env;
+    let organizer = Address::generate(env);
+    let event_id = platform.str("small_room");
+    platform.registry.register_event(
+        &event_id,
+        &organizer,
+        &Address::generate(env),
+        &platform.str(METADATA_CID),
+        &3,
+    );
+
+    let alice = platform.funded_buyer(5000);
+    let bob = platform.funded_buyer(5000);
+    platform
+        .registry
+        .hold_inventory(&event_id, &alice, &2, &300);
+    platform.registry.hold_inventory(&event_id, &bob, &1, &300);
+
+    // Every ticket is held, so a buyer without a hold can't check out
+    let carol = platform.funded_buyer(5000);
+    assert_eq!(
+        platform.try_buy("p1", "small_room", &carol, &[]),
+        Err(TicketPaymentError::TicketsSoldOut)
+    );
+
+    // Holders still get their tickets, and the event sells out at capacity
+    platform.try_buy("p2", "small_room", &alice, &[]).unwrap();
+    platform.try_buy("p3", "small_room", &alice, &[]).unwrap();
+    platform.try_buy("p4", "small_room", &bob, &[]).unwrap();
+    assert_eq!(
+        platform.try_buy("p5", "small_room", &alice, &[]),
+        Err(TicketPaymentError::TicketsSoldOut)
+    );
+    assert_eq!(
+        platform
+            .registry
+            .get_event(&event_id)
+            .unwrap()
+            .current_supply,
+        3
+    );
+}
+
+#[test]
+fn test_burned_and_refunded_tickets_return_to_supply() {
+    let platform = Platform::new();
+    let env = &platform.env;
+    let payout = Address::generate(env);
+    let event_id = platform.str("one_seat");
+    platform.registry.register_event(
+        &event_id,
+        &Address::generate(env),
+        &payout,
+        &platform.str(METADATA_CID),
+        &1,
+    );
+
+    let alice = platform.funded_buyer(5000);
+    let bob = platform.funded_buyer(5000);
+    platform.try_buy("p1", "one_seat", &alice, &[]).unwrap();
+    assert_eq!(
+        platform.try_buy("p2", "one_seat", &bob, &[]),
+        Err(TicketPaymentError::TicketsSoldOut)
+    );
+
+    // Burning the ticket frees its seat for the next buyer
+    platform.payment.burn_ticket(&platform.str("p1"));
+    assert_eq!(
+        platform
+            .registry
+            .get_event(&event_id)
+            .unwrap()
+            .current_supply,
+        0
+    );
+    platform.try_buy("p2", "one_seat", &bob, &[]).unwrap();
+
+    // So does refunding it
+    platform.usdc_admin.mint(&payout, &1000);
+    platform.payment.refund_payments(
+        &event_id,
+        &vec![env, platform.str("p2")],
+        &RefundReason::Other,
+    );
+    platform.try_buy("p3", "one_seat", &alice, &[]).unwrap();
+    assert_eq!(
+        platform.try_buy("p4", "one_seat", &bob, &[]),
+        Err(TicketPaymentError::TicketsSoldOut)
+    );
+}
+
+#[test]
+fn test_denied_token_is_refused_as_tenant_currency() {
+    let platform = Platform::new();
+    let env = &platform.env;
+    let rebasing = Address::generate(env);
+    let tenant = Tenant {
+        tenant_id: platform.str("acme"),
+        admin: Address::generate(env),
+        platform_fee_percent: 300,
+        accepted_tokens: vec![env, platform.usdc.address.clone()],
+    };
+    platform.registry.register_tenant(&tenant, &1);
+
+    platform.payment.deny_token(&rebasing, &0);
+    assert_eq!(
+        platform.registry.try_update_tenant(
+            &tenant.tenant_id,
+            &300,
+            &vec![env, platform.usdc.address.clone(), rebasing]
+        ),
+        Err(Ok(EventRegistryError::TokenDenied))
+    );
+
+    // Tenant events can't be registered while a settlement token is denied
+    platform.payment.deny_token(&platform.usdc.address, &1);
+    let organizer = Address::generate(env);
+    assert_eq!(
+        platform.registry.try_register_event(
+            &platform.str("acme:gala"),
+            &organizer,
+            &organizer,
+            &platform.str(METADATA_CID),
+            &0,
+        ),
+        Err(Ok(EventRegistryError::TokenDenied))
+    );
+    assert_eq!(
+        platform.registry.validate_registration(
+            &platform.str("acme:gala"),
+            &platform.str(METADATA_CID),
+            &0
+        ),
+        vec![env, EventRegistryError::TokenDenied as u32]
+    );
+}
+
+#[test]
+fn test_tenant_and_plan_fees_take_precedence_over_platform_fee_strategy() {
+    let platform = Platform::new();
+    let env = &platform.env;
+    let tenant = Tenant {
+        tenant_id: platform.str("acme"),
+        admin: Address::generate(env),
+        platform_fee_percent: 300,
+        accepted_tokens: vec![env, platform.usdc.address.clone()],
+    };
+    platform.registry.register_tenant(&tenant, &1);
+    platform.registry.set_subscription_plan(
+        &SubscriptionPlan {
+            plan_id: 1,
+            fee_percent: 200,
+            price: 100,
+            token: platform.usdc.address.clone(),
+            period_secs: 86_400,
+        },
+        &2,
+    );
+    platform
+        .payment
+        .set_fee_strategy(&Some(FeeStrategy::FlatBps(800)), &0);
+
+    let organizer = Address::generate(env);
+    let member = Address::generate(env);
+    platform.usdc_admin.mint(&member, &100);
+    platform.registry.subscribe(&member, &1);
+    for (event_id, owner) in [
+        ("open_air", &organizer),
+        ("acme:gala", &organizer),
+        ("members_night", &member),
+    ] {
+        platform.registry.register_event(
+            &platform.str(event_id),
+            owner,
+            owner,
+            &platform.str(METADATA_CID),
+            &0,
+        );
+    }
+
+    // The platform default only replaces the registry fee of plain events;
+    // the tenant's 3% and the plan's 2% still apply to theirs
+    let buyer = platform.funded_buyer(3000);
+    for (payment_id, event_id, fee) in [
+        ("p1", "open_air", 80),
+        ("p2", "acme:gala", 30),
+        ("p3", "members_night", 20),
+    ] {
+        platform.try_buy(payment_id, event_id, &buyer, &[]).unwrap();
+        let payment = platform
+            .payment
+            .get_payment_status(&platform.str(payment_id))
+            .unwrap();
+        assert_eq!(payment.platform_fee, fee);
+    }
+}
@@ -0,0 +1,258 @@
+//! Adversarial token behaviour. Each mock below implements just the token
+//! functions the payment contract calls (`transfer`, `transfer_from`,
+//! `balance`) and misbehaves in one specific way.
+
+use super::contract::TicketPaymentContractClient;
+use super::test::setup_test;
+use crate::error::TicketPaymentError;
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, Address, Env, IntoVal, String, Symbol,
+};
+
+fn balance_key(env: &Env, id: &Address) -> (Symbol, Address) {
+    (Symbol::new(env, "balance"), id.clone())
+}
+
+fn read_balance(env: &Env, id: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&balance_key(env, id))
+        .unwrap_or(0)
+}
+
+fn move_balance(env: &Env, from: &Address, to: &Address, debit: i128, credit: i128) {
+    let from_balance = read_balance(env, from);
+    if from_balance < debit {
+        panic!("insufficient balance");
+    }
+    env.storage()
+        .instance()
+        .set(&balance_key(env, from), &(from_balance - debit));
+    let to_balance = read_balance(env, to);
+    env.storage()
+        .instance()
+        .set(&balance_key(env, to), &(to_balance + credit));
+}
+
+// Always reverts on transfer
+#[contract]
+pub struct RevertingToken;
+
+#[contractimpl]
+impl RevertingToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = read_balance(&env, &to);
+        env.storage()
+            .instance()
+            .set(&balance_key(&env, &to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        read_balance(&env, &id)
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {
+        panic!("transfer disabled");
+    }
+}
+
+// Delivers 1% less than the amount sent
+#[contract]
+pub struct FeeOnTransferToken;
+
+#[contractimpl]
+impl FeeOnTransferToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = read_balance(&env, &to);
+        env.storage()
+            .instance()
+            .set(&balance_key(&env, &to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        read_balance(&env, &id)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        move_balance(&env, &from, &to, amount, amount - amount / 100);
+    }
+}
+
+// Reports a huge balance for everyone but holds nothing
+#[contract]
+pub struct LyingBalanceToken;
+
+#[contractimpl]
+impl LyingBalanceToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        i128::MAX
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        move_balance(&env, &from, &to, amount, amount);
+    }
+}
+
+// Calls back into the payment contract from inside `transfer`
+#[contract]
+pub struct ReentrantToken;
+
+#[contractimpl]
+impl ReentrantToken {
+    pub fn set_target(env: Env, target: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "target"), &target);
+    }
+
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(env: Env, from: Address, _to: Address, amount: i128) {
+        let target: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "target"))
+            .unwrap();
+        let _: String = env.invoke_contract(
+            &target,
+            &Symbol::new(&env, "process_payment"),
+            soroban_sdk::vec![
+                &env,
+                String::from_str(&env, "reentered").into_val(&env),
+                String::from_str(&env, "event_1").into_val(&env),
+                String::from_str(&env, "tier_1").into_val(&env),
+                from.into_val(&env),
+                env.current_contract_address().into_val(&env),
+                amount.into_val(&env),
+            ],
+        );
+    }
+}
+
+fn pay(
+    env: &Env,
+    client: &TicketPaymentContractClient,
+    token: &Address,
+    buyer: &Address,
+    payment_id: &str,
+    amount: i128,
+) -> Result<(), ()> {
+    match client.try_process_payment(
+        &String::from_str(env, payment_id),
+        &String::from_str(env, "event_1"),
+        &String::from_str(env, "tier_1"),
+        buyer,
+        token,
+        &amount,
+        &u64::MAX,
+    ) {
+        Ok(Ok(_)) => Ok(()),
+        _ => Err(()),
+    }
+}
+
+#[test]
+fn test_reverting_token_leaves_no_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _, _, _) = setup_test(&env);
+
+    let token_id = env.register(RevertingToken, ());
+    client.add_token(&token_id);
+    let buyer = Address::generate(&env);
+    RevertingTokenClient::new(&env, &token_id).mint(&buyer, &5000);
+
+    assert!(pay(&env, &client, &token_id, &buyer, "p1", 1000).is_err());
+    assert!(client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .is_none());
+    assert_eq!(client.get_buyer_summary(&buyer).purchase_count, 0);
+    assert_eq!(client.get_platform_stats().total_payments, 0);
+}
+
+#[test]
+fn test_fee_on_transfer_token_shows_as_reserve_shortfall() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _, _, _) = setup_test(&env);
+
+    let token_id = env.register(FeeOnTransferToken, ());
+    client.add_token(&token_id);
+    let buyer = Address::generate(&env);
+    FeeOnTransferTokenClient::new(&env, &token_id).mint(&buyer, &50_000);
+
+    let agency = Address::generate(&env);
+    client.set_event_partner(
+        &String::from_str(&env, "event_1"),
+        &Some(crate::types::PartnerConfig {
+            partner: agency.clone(),
+            share_bps: 10000,
+        }),
+        &0,
+    );
+    pay(&env, &client, &token_id, &buyer, "p1", 10_000).unwrap();
+
+    // The 500 fee owed to the partner arrived 1% short; the deficit is
+    // visible in the reserve view rather than silently absorbed
+    let reserve = client.get_reserve_accounting(&token_id);
+    assert_eq!(reserve.partner_fees_owed, 500);
+    assert_eq!(reserve.balance, 495);
+    assert_eq!(reserve.surplus, -5);
+
+    // The partner can never withdraw more than the contract actually holds
+    assert!(client
+        .try_withdraw_partner_fees(&agency, &token_id)
+        .is_err());
+}
+
+#[test]
+fn test_lying_balance_token_cannot_fake_a_purchase() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _, _, _) = setup_test(&env);
+
+    let token_id = env.register(LyingBalanceToken, ());
+    client.add_token(&token_id);
+    let buyer = Address::generate(&env);
+
+    // The dry run trusts the reported balance...
+    let violations = client.validate_purchase(
+        &String::from_str(&env, "event_1"),
+        &buyer,
+        &token_id,
+        &1000i128,
+    );
+    assert!(!violations.contains(TicketPaymentError::InsufficientBalance as u32));
+
+    // ...but the purchase itself depends on the transfer succeeding
+    assert!(pay(&env, &client, &token_id, &buyer, "p1", 1000).is_err());
+    assert!(client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .is_none());
+}
+
+#[test]
+fn test_reentrant_token_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _, _, _) = setup_test(&env);
+
+    let token_id = env.register(ReentrantToken, ());
+    ReentrantTokenClient::new(&env, &token_id).set_target(&client.address);
+    client.add_token(&token_id);
+    let buyer = Address::generate(&env);
+
+    // Soroban forbids re-entering a contract already on the call stack
+    assert!(pay(&env, &client, &token_id, &buyer, "p1", 1000).is_err());
+    assert!(client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .is_none());
+    assert!(client
+        .get_payment_status(&String::from_str(&env, "reentered"))
+        .is_none());
+    assert_eq!(client.get_platform_stats().total_payments, 0);
+}
@@ -1,36 +1,154 @@
 use crate::storage::{
-    add_token_to_whitelist, get_admin, get_event_registry, get_payment, get_platform_wallet,
-    is_initialized, is_token_whitelisted, remove_token_from_whitelist, set_admin,
-    set_event_registry, set_initialized, set_platform_wallet, set_usdc_token, store_payment,
-    update_payment_status,
+    add_token_to_whitelist, get_admin, get_admin_nonce, get_attestor_key, get_buyer_payments,
+    get_buyer_summary, get_daily_stats, get_dust_balance, get_dust_tokens, get_event_fee_strategy,
+    get_event_max_ticket_price, get_event_partner, get_event_payments_by_day, get_event_registry,
+    get_event_sales, get_fee_strategy, get_guardian, get_hooks, get_max_ticket_price,
+    get_partner_balance, get_partner_fees_owed, get_payment, get_payment_freeze,
+    get_payment_rebate, get_payment_totals, get_pending_migration, get_platform_wallet,
+    get_promo_campaign, get_purchase_limits, get_questionnaire, get_questionnaire_answers,
+    get_quote_key, get_rebate_balance, get_rebate_program, get_rebates_owed,
+    get_refund_reason_stats, get_refund_redirect, get_rejection_stats, get_rounding_beneficiary,
+    get_schema_version, get_spend_cap, get_standing_approval, get_ticket_claim, get_usdc_token,
+    get_withholding, has_promo_code, increment_refund_reason_count, is_custodian, is_initialized,
+    is_paused, is_quote_used, is_restricted_event, is_token_denied, is_token_whitelisted,
+    mark_preflight_counted, mark_quote_used, move_buyer_payment, record_rejections,
+    remove_standing_approval, remove_ticket_claim, remove_token_from_whitelist, save_payment,
+    set_admin, set_admin_nonce, set_attestor_key, set_buyer_summary, set_custodian,
+    set_daily_stats, set_dust_balance, set_event_fee_strategy, set_event_max_ticket_price,
+    set_event_partner, set_event_registry, set_event_sales, set_fee_strategy, set_guardian,
+    set_hooks, set_initialized, set_max_ticket_price, set_partner_balance, set_paused,
+    set_payment_freeze, set_payment_rebate, set_payment_totals, set_pending_migration,
+    set_platform_wallet, set_promo_campaign, set_promo_code, set_purchase_limits,
+    set_questionnaire, set_questionnaire_answers, set_quote_key, set_rebate_balance,
+    set_rebate_program, set_refund_redirect, set_restricted_event, set_rounding_beneficiary,
+    set_schema_version, set_spend_cap, set_standing_approval, set_ticket_claim, set_token_denied,
+    set_usdc_token, set_withholding, store_payment, take_promo_code, update_payment_status,
+    SECONDS_PER_DAY,
+};
+use crate::types::{
+    AddonLineItem, AddonSelection, AttestationPayload, BuildInfo, BuyerSummary, ClaimPayload,
+    ContractConfig, DailyStats, EventSales, FeeStrategy, HookKind, HookPayload, InvariantViolation,
+    PartnerConfig, Payment, PaymentFreeze, PaymentPage, PaymentReceipt, PaymentStatus,
+    PendingAction, PlatformStats, PriceQuote, PromoCampaign, PurchaseAttestation, PurchaseExtra,
+    PurchaseLimits, PurchaseOptions, QuestionnaireAnswers, QuotePayload, RebateProgram,
+    RefundReason, RefundReasonStats, RefundRedirect, ReserveAccounting, RoundingBeneficiary,
+    SpendCap, StandingApproval, TicketClaim, TicketMetadata, ViolationDetail, WalletTicket,
+    WithholdingConfig,
 };
-use crate::types::{Payment, PaymentStatus};
 use crate::{
     error::TicketPaymentError,
     events::{
-        AgoraEvent, ContractUpgraded, InitializationEvent, PaymentProcessedEvent,
-        PaymentStatusChangedEvent,
+        AgoraEvent, ContractUpgraded, FeeRebateAccruedEvent, FeeRebateClaimedEvent,
+        InitializationEvent, PartnerFeesWithdrawnEvent, PauseChangedEvent, PaymentFrozenEvent,
+        PaymentProcessedEvent, PaymentRedactedEvent, PaymentRefundedEvent, PaymentSettledEvent,
+        PaymentStatusChangedEvent, PaymentUnfrozenEvent, RefundRedirectedEvent, SettlementLeg,
+        SettlementLegKind, TaxWithheldEvent, TicketBurnedEvent, TicketClaimedEvent,
     },
 };
-use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, token, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal,
+    Map, String, Val, Vec,
+};
 
 // Event Registry interface
 pub mod event_registry {
-    use soroban_sdk::{contractclient, Address, Env, String};
+    use crate::types::{AddonLineItem, AddonSelection};
+    use soroban_sdk::{contractclient, Address, BytesN, Env, String, Vec};
 
     #[soroban_sdk::contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub struct PaymentInfo {
         pub payment_address: Address,
         pub platform_fee_percent: u32,
+        pub metadata_cid: String,
+        pub cohosts: Vec<CoHost>,
+        pub accepted_tokens: Vec<Address>,
+        pub created_at: u64,
+        pub partner_key: Option<BytesN<32>>,
+        pub custom_fee: bool,
+    }
+
+    #[soroban_sdk::contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct CoHost {
+        pub address: Address,
+        pub share_bps: u32,
+        pub permissions: u32,
+    }
+
+    #[soroban_sdk::contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct RegistryStats {
+        pub total_events: u64,
+        pub active_events: u64,
+    }
+
+    #[soroban_sdk::contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct InventoryHold {
+        pub quantity: u32,
+        pub expires_at: u64,
     }
 
     #[contractclient(name = "Client")]
     pub trait EventRegistryInterface {
         fn get_event_payment_info(env: Env, event_id: String) -> PaymentInfo;
+        fn get_event_metadata(env: Env, event_id: String) -> String;
+        fn get_registry_stats(env: Env) -> RegistryStats;
+        fn get_event_partner_key(env: Env, event_id: String) -> Option<BytesN<32>>;
+        fn reserve_addons(
+            env: Env,
+            event_id: String,
+            selections: Vec<AddonSelection>,
+        ) -> Vec<AddonLineItem>;
+        fn get_inventory_hold(env: Env, event_id: String, buyer: Address) -> Option<InventoryHold>;
+        fn consume_inventory_hold(env: Env, event_id: String, buyer: Address);
+        fn increment_inventory(env: Env, event_id: String);
+        fn release_inventory(env: Env, event_id: String, quantity: u32);
+    }
+}
+
+/// Maximum number of payments returned by a single paginated query.
+pub const MAX_PAGE_SIZE: u32 = 100;
+/// Maximum number of calendar days a time-range query may span.
+pub const MAX_RANGE_DAYS: u64 = 31;
+/// Maximum number of payments refunded in a single call.
+pub const MAX_REFUND_BATCH: u32 = 50;
+/// Maximum number of promo codes created in a single call.
+pub const MAX_PROMO_BATCH: u32 = 200;
+/// Maximum length of a questionnaire or answers CID.
+pub const MAX_CID_LEN: u32 = 128;
+/// Maximum length of a payment, event or tier ID.
+pub const MAX_ID_LEN: u32 = 64;
+/// Maximum number of hook contracts notified after each purchase or refund.
+pub const MAX_HOOKS: u32 = 5;
+/// Maximum number of bands in a tiered fee strategy.
+pub const MAX_FEE_TIERS: u32 = 10;
+/// Delay before a refund redirect takes effect, giving the buyer time to
+/// notice and cancel one they were tricked into signing.
+pub const REFUND_REDIRECT_COOLDOWN: u64 = 86_400;
+/// Furthest ahead a price quote may expire, in seconds.
+pub const MAX_QUOTE_SECS: u64 = 3_600;
+/// How long a guardian's freeze lasts unless the admin escalates it.
+pub const GUARDIAN_FREEZE_SECS: u64 = 259_200;
+/// Approximate ledger close time, used to size temporary storage TTLs.
+const LEDGER_CLOSE_SECS: u64 = 5;
+
+// Interface implemented by approved hook contracts
+pub mod hook {
+    use crate::types::HookPayload;
+    use soroban_sdk::{contractclient, Env};
+
+    #[contractclient(name = "Client")]
+    pub trait TicketHook {
+        fn on_ticket_event(env: Env, payload: HookPayload);
     }
 }
 
+// Keep `binver` in step with the crate version reported by `get_build_info`
+contractmeta!(key = "binver", val = "0.0.0");
+contractmeta!(key = "source_repo", val = "github:utilityjnr/agora");
+
 #[contract]
 pub struct TicketPaymentContract;
 
@@ -75,9 +193,16 @@ impl TicketPaymentContract {
         Ok(())
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+    /// Replaces the contract WASM with `new_wasm_hash`. Only callable by the
+    /// administrator, with the current `get_admin_nonce`.
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
         let admin = get_admin(&env).expect("Admin not set");
         admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
 
         let old_wasm_hash = match env.current_contract_address().executable() {
             Some(soroban_sdk::Executable::Wasm(hash)) => hash,
@@ -94,25 +219,167 @@ impl TicketPaymentContract {
                 new_wasm_hash,
             },
         );
+        Ok(())
+    }
+
+    /// Upgrades the contract and blocks purchases until `on_upgrade` has run
+    /// `migration_id` in the new WASM. The new code only takes effect once
+    /// this invocation returns, so the migration is a separate call; the
+    /// block keeps the half-migrated window from taking sales.
+    pub fn upgrade_with_migration(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        migration_id: u32,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        if migration_id <= get_schema_version(&env) {
+            panic!("Migration already applied");
+        }
+        set_pending_migration(&env, Some(migration_id));
+        Self::upgrade(env, new_wasm_hash, nonce)
+    }
+
+    /// Returns the nonce the next upgrade or role grant must carry. Each such
+    /// call consumes it, so a captured signed invocation can't be replayed.
+    pub fn get_admin_nonce(env: Env) -> u64 {
+        get_admin_nonce(&env)
+    }
+
+    /// Runs the migration an upgrade is waiting on and re-enables purchases.
+    /// Only callable by the administrator.
+    pub fn on_upgrade(env: Env, migration_id: u32) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        if get_pending_migration(&env) != Some(migration_id) {
+            return Err(TicketPaymentError::InvalidMigration);
+        }
+
+        run_migration(migration_id)?;
+        set_schema_version(&env, migration_id);
+        set_pending_migration(&env, None);
+        Ok(())
+    }
+
+    /// Ops worklist of everything waiting on the administrator: a pending
+    /// post-upgrade migration and rounding dust awaiting a sweep, per token.
+    pub fn get_pending_actions(env: Env) -> Vec<PendingAction> {
+        let mut actions = Vec::new(&env);
+        if let Some(migration_id) = get_pending_migration(&env) {
+            actions.push_back(PendingAction::Migration(migration_id));
+        }
+        for token_address in get_dust_tokens(&env).iter() {
+            let amount = get_dust_balance(&env, &token_address);
+            actions.push_back(PendingAction::DustSweep(token_address, amount));
+        }
+        actions
+    }
+
+    /// Returns the last migration applied and the one pending, if any.
+    pub fn get_migration_state(env: Env) -> (u32, Option<u32>) {
+        (get_schema_version(&env), get_pending_migration(&env))
     }
 
-    pub fn add_token(env: Env, token: Address) {
+    /// Whitelists a token for payments. Only callable by the administrator.
+    /// Denied tokens can't be whitelisted until they are taken off the
+    /// denial-list.
+    pub fn add_token(env: Env, token: Address) -> Result<(), TicketPaymentError> {
         let admin = get_admin(&env).expect("Admin not set");
         admin.require_auth();
+        if is_token_denied(&env, &token) {
+            return Err(TicketPaymentError::TokenDenied);
+        }
         add_token_to_whitelist(&env, &token);
+        Ok(())
     }
 
+    /// Removes a token from the whitelist. Only callable by the administrator.
     pub fn remove_token(env: Env, token: Address) {
         let admin = get_admin(&env).expect("Admin not set");
         admin.require_auth();
         remove_token_from_whitelist(&env, &token);
     }
 
+    /// Returns whether `token` is whitelisted for payments.
     pub fn is_token_allowed(env: Env, token: Address) -> bool {
         is_token_whitelisted(&env, &token)
     }
 
-    /// Processes a payment for an event ticket.
+    /// Puts a known malicious or rebasing token on the denial-list, also
+    /// removing it from the whitelist. The registry then refuses it as a
+    /// tenant's settlement currency and purchases in it are rejected. Only
+    /// callable by the administrator, with the current `get_admin_nonce`.
+    pub fn deny_token(env: Env, token: Address, nonce: u64) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        remove_token_from_whitelist(&env, &token);
+        set_token_denied(&env, &token, true);
+        Ok(())
+    }
+
+    /// Takes a token off the denial-list. It must be whitelisted again
+    /// before it can be used. Only callable by the administrator.
+    pub fn remove_denied_token(env: Env, token: Address) {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        set_token_denied(&env, &token, false);
+    }
+
+    /// Returns whether `token` is on the denial-list.
+    pub fn is_token_denied(env: Env, token: Address) -> bool {
+        is_token_denied(&env, &token)
+    }
+
+    /// Returns the version, git commit and build profile this WASM was built
+    /// from. CI sets `AGORA_GIT_COMMIT`; local builds report "unknown".
+    pub fn get_build_info(env: Env) -> BuildInfo {
+        BuildInfo {
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            git_commit: String::from_str(
+                &env,
+                option_env!("AGORA_GIT_COMMIT").unwrap_or("unknown"),
+            ),
+            profile: String::from_str(
+                &env,
+                if cfg!(debug_assertions) {
+                    "debug"
+                } else {
+                    "release"
+                },
+            ),
+        }
+    }
+
+    /// Returns the full resolved configuration in one read, so operators can
+    /// verify a deployment without querying each setting separately.
+    pub fn get_config(env: Env) -> Result<ContractConfig, TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        Ok(ContractConfig {
+            admin,
+            usdc_token: get_usdc_token(&env),
+            platform_wallet: get_platform_wallet(&env),
+            event_registry: get_event_registry(&env),
+            max_ticket_price: get_max_ticket_price(&env),
+            purchase_limits: get_purchase_limits(&env),
+            rounding_beneficiary: get_rounding_beneficiary(&env),
+            attestor: get_attestor_key(&env),
+            quote_key: get_quote_key(&env),
+            guardian: get_guardian(&env),
+            paused: is_paused(&env),
+            schema_version: get_schema_version(&env),
+            pending_migration: get_pending_migration(&env),
+            build: Self::get_build_info(env),
+        })
+    }
+
+    /// Processes a payment for an event ticket. `amount` is the ticket price
+    /// in token base units (stroops for USDC); the platform fee is taken out
+    /// of it at the event's rate in basis points.
+    ///
+    /// `expires_at` is part of what the buyer signs: once the ledger
+    /// timestamp passes it the purchase is rejected, so a signed transaction
+    /// can't be submitted hours later after prices or availability changed.
+    #[allow(clippy::too_many_arguments)]
     pub fn process_payment(
         env: Env,
         payment_id: String,
@@ -121,130 +388,2635 @@ impl TicketPaymentContract {
         buyer_address: Address,
         token_address: Address,
         amount: i128,
+        expires_at: u64,
     ) -> Result<String, TicketPaymentError> {
-        if !is_initialized(&env) {
-            panic!("Contract not initialized");
-        }
-        buyer_address.require_auth();
+        execute_payment(
+            &env,
+            payment_id,
+            event_id,
+            ticket_tier_id,
+            buyer_address,
+            token_address,
+            amount,
+            PaymentExtras {
+                expires_at,
+                ..Default::default()
+            },
+        )
+    }
 
-        if amount <= 0 {
-            panic!("Amount must be positive");
+    /// Processes a ticket payment with optional extras (an eligibility
+    /// attestation, add-ons, a promo code, questionnaire answers, an
+    /// attribution, a price quote or a custodial beneficiary) in any
+    /// combination, so e.g. a restricted event with a questionnaire can be
+    /// bought with both an attestation and answers. `amount` is the ticket
+    /// price before any promo discount; `options.expires_at` works as
+    /// `expires_at` does for `process_payment`.
+    ///
+    /// An attestation must be signed by the attestor key over the XDR of
+    /// `AttestationPayload`, and a quote by the quote key over `QuotePayload`,
+    /// expiring within `MAX_QUOTE_SECS`. A beneficiary is only accepted from
+    /// a registered custodian, who pays for and holds the ticket.
+    #[allow(clippy::too_many_arguments)]
+    pub fn process_payment_with_options(
+        env: Env,
+        payment_id: String,
+        event_id: String,
+        ticket_tier_id: String,
+        buyer_address: Address,
+        token_address: Address,
+        amount: i128,
+        options: PurchaseOptions,
+    ) -> Result<String, TicketPaymentError> {
+        execute_payment(
+            &env,
+            payment_id,
+            event_id,
+            ticket_tier_id,
+            buyer_address,
+            token_address,
+            amount,
+            PaymentExtras::from_options(&options)?,
+        )
+    }
+
+    /// Adds a batch of single-use promo codes to a campaign, creating the
+    /// campaign on first use. Only the sha256 of each code is stored; the
+    /// codes themselves are handed out off-chain. Must be authorized by the
+    /// event's payout address, and a campaign stays bound to the event and
+    /// discount it was created with.
+    pub fn create_promo_codes(
+        env: Env,
+        event_id: String,
+        campaign_id: String,
+        discount_bps: u32,
+        code_hashes: Vec<BytesN<32>>,
+    ) -> Result<(), TicketPaymentError> {
+        validate_identifier(&campaign_id)?;
+        if code_hashes.is_empty() || code_hashes.len() > MAX_PROMO_BATCH {
+            return Err(TicketPaymentError::BatchTooLarge);
+        }
+        if discount_bps == 0 || discount_bps >= 10000 {
+            return Err(TicketPaymentError::InvalidPromoCampaign);
         }
+        let registry_client = event_registry::Client::new(&env, &get_event_registry(&env));
+        let payment_info = fetch_payment_info(&registry_client, &event_id)?;
+        payment_info.payment_address.require_auth();
 
-        if !is_token_whitelisted(&env, &token_address) {
-            return Err(TicketPaymentError::TokenNotWhitelisted);
+        let mut campaign = match get_promo_campaign(&env, campaign_id.clone()) {
+            Some(campaign)
+                if campaign.event_id != event_id || campaign.discount_bps != discount_bps =>
+            {
+                return Err(TicketPaymentError::InvalidPromoCampaign);
+            }
+            Some(campaign) => campaign,
+            None => PromoCampaign {
+                event_id,
+                discount_bps,
+                codes_issued: 0,
+                redemptions: 0,
+                discount_given: 0,
+            },
+        };
+        for code_hash in code_hashes.iter() {
+            if has_promo_code(&env, &code_hash) {
+                return Err(TicketPaymentError::InvalidPromoCampaign);
+            }
+            set_promo_code(&env, &code_hash, &campaign_id);
         }
+        campaign.codes_issued += code_hashes.len();
+        set_promo_campaign(&env, campaign_id, &campaign);
+        Ok(())
+    }
 
-        // 1. Query Event Registry for payment info and platform fee
-        let event_registry_addr = get_event_registry(&env);
-        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+    /// Returns a campaign's discount, codes issued and redemption totals.
+    pub fn get_campaign_stats(env: Env, campaign_id: String) -> Option<PromoCampaign> {
+        get_promo_campaign(&env, campaign_id)
+    }
 
-        let payment_info = match registry_client.try_get_event_payment_info(&event_id) {
-            Ok(Ok(info)) => info,
-            Err(Ok(e)) => {
-                // Determine which error was thrown
-                if e.is_type(soroban_sdk::xdr::ScErrorType::Contract) && e.get_code() == 2 {
-                    return Err(TicketPaymentError::EventNotFound);
-                } else if e.is_type(soroban_sdk::xdr::ScErrorType::Contract) && e.get_code() == 6 {
-                    return Err(TicketPaymentError::EventInactive);
-                }
-                // Fallback for unexpected contract errors
-                return Err(TicketPaymentError::EventNotFound);
+    /// Requires purchases for an event to commit to answers to the
+    /// questionnaire at `questionnaire_cid`, or drops the requirement with
+    /// `None`. Must be authorized by the event's payout address.
+    pub fn set_event_questionnaire(
+        env: Env,
+        event_id: String,
+        questionnaire_cid: Option<String>,
+    ) -> Result<(), TicketPaymentError> {
+        if let Some(cid) = &questionnaire_cid {
+            if cid.is_empty() || cid.len() > MAX_CID_LEN {
+                return Err(TicketPaymentError::InvalidQuestionnaire);
             }
-            _ => return Err(TicketPaymentError::EventNotFound),
-        };
+        }
+        let registry_client = event_registry::Client::new(&env, &get_event_registry(&env));
+        let payment_info = fetch_payment_info(&registry_client, &event_id)?;
+        payment_info.payment_address.require_auth();
 
-        // 2. Calculate platform fee (platform_fee_percent is in bps, 10000 = 100%)
-        let platform_fee = (amount * payment_info.platform_fee_percent as i128) / 10000;
-        let organizer_amount = amount - platform_fee;
+        set_questionnaire(&env, event_id, questionnaire_cid);
+        Ok(())
+    }
 
-        // 3. Transfer tokens from buyer (splitting payment)
-        let token_client = token::Client::new(&env, &token_address);
-        let platform_wallet = get_platform_wallet(&env);
+    /// Returns the CID of the questionnaire an event's buyers must answer.
+    pub fn get_event_questionnaire(env: Env, event_id: String) -> Option<String> {
+        get_questionnaire(&env, event_id)
+    }
 
-        // Transfer platform fee
-        if platform_fee > 0 {
-            token_client.transfer(&buyer_address, &platform_wallet, &platform_fee);
-        }
+    /// Returns the questionnaire commitment made with a payment, if any.
+    pub fn get_questionnaire_answers(env: Env, payment_id: String) -> Option<QuestionnaireAnswers> {
+        get_questionnaire_answers(&env, payment_id)
+    }
 
-        // Transfer organizer amount
-        if organizer_amount > 0 {
-            token_client.transfer(
-                &buyer_address,
-                &payment_info.payment_address,
-                &organizer_amount,
-            );
-        }
+    /// Checks presented questionnaire answers against the commitment made
+    /// at purchase. Returns false if the payment made no commitment.
+    pub fn verify_questionnaire_answers(env: Env, payment_id: String, answers: Bytes) -> bool {
+        get_questionnaire_answers(&env, payment_id).is_some_and(|commitment| {
+            env.crypto().sha256(&answers).to_bytes() == commitment.answers_hash
+        })
+    }
 
-        // 4. Create payment record
-        let payment = Payment {
-            payment_id: payment_id.clone(),
-            event_id: event_id.clone(),
-            buyer_address: buyer_address.clone(),
-            ticket_tier_id,
-            amount,
-            platform_fee,
-            organizer_amount,
-            status: PaymentStatus::Pending,
-            transaction_hash: String::from_str(&env, ""), // Empty until confirmed
-            created_at: env.ledger().timestamp(),
-            confirmed_at: None,
-        };
+    /// Grants `operator` a standing approval to purchase tickets on the buyer's
+    /// behalf, spending at most `max_per_period` every `period_secs`. Funds are
+    /// pulled with `transfer_from`, so the buyer must also `approve` this
+    /// contract on the token. Replaces any existing approval.
+    pub fn grant_standing_approval(
+        env: Env,
+        buyer_address: Address,
+        operator: Address,
+        max_per_period: i128,
+        period_secs: u64,
+    ) -> Result<(), TicketPaymentError> {
+        buyer_address.require_auth();
 
-        store_payment(&env, payment);
+        if max_per_period <= 0 || period_secs == 0 {
+            return Err(TicketPaymentError::InvalidStandingApproval);
+        }
 
-        // 5. Emit payment event
-        env.events().publish(
-            (AgoraEvent::PaymentProcessed,),
-            PaymentProcessedEvent {
-                payment_id: payment_id.clone(),
-                event_id: event_id.clone(),
-                buyer_address: buyer_address.clone(),
-                amount,
-                platform_fee,
-                timestamp: env.ledger().timestamp(),
+        set_standing_approval(
+            &env,
+            &buyer_address,
+            &StandingApproval {
+                operator,
+                max_per_period,
+                period_secs,
+                period_start: env.ledger().timestamp(),
+                spent_in_period: 0,
             },
         );
+        Ok(())
+    }
 
-        Ok(payment_id)
+    /// Revokes the buyer's standing approval.
+    pub fn revoke_standing_approval(env: Env, buyer_address: Address) {
+        buyer_address.require_auth();
+        remove_standing_approval(&env, &buyer_address);
     }
 
-    /// Confirms a payment after backend verification.
-    pub fn confirm_payment(env: Env, payment_id: String, transaction_hash: String) {
-        if !is_initialized(&env) {
-            panic!("Contract not initialized");
+    /// Caps how much `buyer_address` can spend on tickets per period, across
+    /// every purchase path. With a `controller` (e.g. a parent or venue
+    /// guardian), later changes and removal need the controller's approval
+    /// instead of the buyer's. Changing a cap keeps the current period's spend.
+    pub fn set_spend_cap(
+        env: Env,
+        buyer_address: Address,
+        max_per_period: i128,
+        period_secs: u64,
+        controller: Option<Address>,
+    ) -> Result<(), TicketPaymentError> {
+        let existing = get_spend_cap(&env, &buyer_address);
+        spend_cap_authority(&buyer_address, existing.as_ref()).require_auth();
+        if max_per_period <= 0 || period_secs == 0 {
+            return Err(TicketPaymentError::InvalidSpendCap);
         }
-        // In a real scenario, this would be restricted to a specific backend/admin address.
-        update_payment_status(
+
+        let (period_start, spent_in_period) = match existing {
+            Some(cap) => (cap.period_start, cap.spent_in_period),
+            None => (env.ledger().timestamp(), 0),
+        };
+        set_spend_cap(
             &env,
-            payment_id.clone(),
-            PaymentStatus::Confirmed,
-            Some(env.ledger().timestamp()),
+            &buyer_address,
+            Some(SpendCap {
+                controller,
+                max_per_period,
+                period_secs,
+                period_start,
+                spent_in_period,
+            }),
         );
+        Ok(())
+    }
 
-        // Update the transaction hash
-        if let Some(mut payment) = get_payment(&env, payment_id.clone()) {
-            payment.transaction_hash = transaction_hash.clone();
-            store_payment(&env, payment);
+    /// Removes a spend cap, with the same approval `set_spend_cap` needs.
+    pub fn remove_spend_cap(env: Env, buyer_address: Address) {
+        let existing = get_spend_cap(&env, &buyer_address);
+        spend_cap_authority(&buyer_address, existing.as_ref()).require_auth();
+        set_spend_cap(&env, &buyer_address, None);
+    }
+
+    /// Returns the spend cap configured for a buyer, if any.
+    pub fn get_spend_cap(env: Env, buyer_address: Address) -> Option<SpendCap> {
+        get_spend_cap(&env, &buyer_address)
+    }
+
+    /// Returns the buyer's standing approval, if any.
+    pub fn get_standing_approval(env: Env, buyer_address: Address) -> Option<StandingApproval> {
+        get_standing_approval(&env, &buyer_address)
+    }
+
+    /// Processes a payment under the buyer's standing approval. Authorized by
+    /// the approved operator instead of the buyer; the settled total,
+    /// add-ons included, counts against the approval, whose spend counter
+    /// resets once the approval period has elapsed. `options` are checked as
+    /// for `process_payment_with_options`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn process_approved_payment(
+        env: Env,
+        payment_id: String,
+        event_id: String,
+        ticket_tier_id: String,
+        buyer_address: Address,
+        token_address: Address,
+        amount: i128,
+        options: PurchaseOptions,
+    ) -> Result<String, TicketPaymentError> {
+        let approval = get_standing_approval(&env, &buyer_address)
+            .ok_or(TicketPaymentError::NoStandingApproval)?;
+        approval.operator.require_auth();
+
+        execute_payment(
+            &env,
+            payment_id,
+            event_id,
+            ticket_tier_id,
+            buyer_address,
+            token_address,
+            amount,
+            PaymentExtras {
+                via_allowance: true,
+                ..PaymentExtras::from_options(&options)?
+            },
+        )
+    }
+
+    /// Registers or removes a custodian allowed to buy tickets on behalf of
+    /// users without a wallet. Only callable by the administrator, with the
+    /// current `get_admin_nonce`.
+    pub fn set_custodian(
+        env: Env,
+        custodian: Address,
+        allowed: bool,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        set_custodian(&env, &custodian, allowed);
+        Ok(())
+    }
+
+    /// Returns whether `custodian` may buy tickets on behalf of users.
+    pub fn is_custodian(env: Env, custodian: Address) -> bool {
+        is_custodian(&env, &custodian)
+    }
+
+    /// Opens a claim-to-wallet for a custodial ticket. The custodian hands the
+    /// user an ed25519 claim key off-chain and registers only its public key
+    /// here.
+    pub fn register_ticket_claim(
+        env: Env,
+        payment_id: String,
+        claim_key: BytesN<32>,
+        expires_at: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        if payment.beneficiary_id.is_none() || !is_active_ticket(&payment) {
+            return Err(TicketPaymentError::NotCustodialTicket);
+        }
+        payment.buyer_address.require_auth();
+        if expires_at <= env.ledger().timestamp() {
+            return Err(TicketPaymentError::ClaimExpired);
         }
 
-        // Emit confirmation event
-        env.events().publish(
-            (AgoraEvent::PaymentStatusChanged,),
-            PaymentStatusChangedEvent {
-                payment_id: payment_id.clone(),
-                old_status: PaymentStatus::Pending,
-                new_status: PaymentStatus::Confirmed,
-                transaction_hash: transaction_hash.clone(),
-                timestamp: env.ledger().timestamp(),
+        set_ticket_claim(
+            &env,
+            payment_id,
+            &TicketClaim {
+                claim_key,
+                expires_at,
             },
         );
+        Ok(())
     }
 
-    /// Returns the status and details of a payment.
-    pub fn get_payment_status(env: Env, payment_id: String) -> Option<Payment> {
-        get_payment(&env, payment_id)
+    /// Moves a custodial ticket into `new_owner`'s wallet. `signature` is the
+    /// claim key's signature over the XDR of a `ClaimPayload` naming the
+    /// ticket and `new_owner`, so a pending claim seen in the mempool can't
+    /// be redirected to another wallet.
+    pub fn claim_ticket(
+        env: Env,
+        payment_id: String,
+        signature: BytesN<64>,
+        new_owner: Address,
+    ) -> Result<(), TicketPaymentError> {
+        new_owner.require_auth();
+        let claim =
+            get_ticket_claim(&env, payment_id.clone()).ok_or(TicketPaymentError::ClaimNotFound)?;
+        if env.ledger().timestamp() >= claim.expires_at {
+            return Err(TicketPaymentError::ClaimExpired);
+        }
+        let payload = ClaimPayload {
+            payment_id: payment_id.clone(),
+            new_owner: new_owner.clone(),
+        };
+        // Traps the invocation if the signature does not match
+        env.crypto()
+            .ed25519_verify(&claim.claim_key, &payload.to_xdr(&env), &signature);
+
+        transfer_claimed_ticket(&env, payment_id, new_owner, false)
+    }
+
+    /// Organizer fallback for claims that were never redeemed: once the claim
+    /// has expired, the event's payout address may assign the ticket directly.
+    pub fn reassign_unclaimed_ticket(
+        env: Env,
+        payment_id: String,
+        new_owner: Address,
+    ) -> Result<(), TicketPaymentError> {
+        let claim =
+            get_ticket_claim(&env, payment_id.clone()).ok_or(TicketPaymentError::ClaimNotFound)?;
+        if env.ledger().timestamp() < claim.expires_at {
+            return Err(TicketPaymentError::ClaimNotExpired);
+        }
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.payout_address.require_auth();
+
+        transfer_claimed_ticket(&env, payment_id, new_owner, true)
+    }
+
+    /// Returns the pending claim for a custodial ticket, if any.
+    pub fn get_ticket_claim(env: Env, payment_id: String) -> Option<TicketClaim> {
+        get_ticket_claim(&env, payment_id)
+    }
+
+    /// Attaches a white-label partner to an event, taking `share_bps` of the
+    /// platform fee on each sale; `None` detaches it. Only callable by the
+    /// administrator, with the current `get_admin_nonce`.
+    pub fn set_event_partner(
+        env: Env,
+        event_id: String,
+        partner: Option<PartnerConfig>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        if let Some(config) = &partner {
+            if config.share_bps == 0 || config.share_bps > 10000 {
+                return Err(TicketPaymentError::InvalidPartnerShare);
+            }
+            validate_address(&env, &config.partner)?;
+        }
+        set_event_partner(&env, event_id, partner);
+        Ok(())
+    }
+
+    /// Returns the white-label partner attached to an event, if any.
+    pub fn get_event_partner(env: Env, event_id: String) -> Option<PartnerConfig> {
+        get_event_partner(&env, event_id)
+    }
+
+    /// Withholds `bps` of every payout to `organizer` (the event payout
+    /// address) and sends it to the compliance wallet at settlement; `None`
+    /// clears it. Only callable by the administrator, with the current
+    /// `get_admin_nonce`.
+    pub fn set_withholding(
+        env: Env,
+        organizer: Address,
+        config: Option<WithholdingConfig>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        if let Some(config) = &config {
+            if config.bps == 0 || config.bps > 10000 {
+                return Err(TicketPaymentError::InvalidWithholding);
+            }
+            validate_address(&env, &config.wallet)?;
+        }
+        set_withholding(&env, &organizer, config);
+        Ok(())
+    }
+
+    /// Returns the tax withholding applied to `organizer`'s payouts, if any.
+    pub fn get_withholding(env: Env, organizer: Address) -> Option<WithholdingConfig> {
+        get_withholding(&env, &organizer)
+    }
+
+    /// Returns the partner fees accrued in `token` and not yet withdrawn.
+    pub fn get_partner_balance(env: Env, partner: Address, token_address: Address) -> i128 {
+        get_partner_balance(&env, &partner, &token_address)
+    }
+
+    /// Proof-of-reserve view: the contract's actual balance of `token_address`
+    /// next to the obligations it holds funds for. The only funds this
+    /// contract custodies are unwithdrawn partner fees and unswept rounding dust.
+    pub fn get_reserve_accounting(env: Env, token_address: Address) -> ReserveAccounting {
+        reserve_accounting(&env, token_address)
     }
+
+    /// Read-only health check for monitoring bots to run via simulation.
+    /// Returns each broken invariant once; an empty result means all hold.
+    ///
+    /// Escrow is reconciled for USDC and every token holding rounding dust.
+    /// Per-event supply caps are enforced and checked by the Event Registry,
+    /// so only its aggregate counters are checked here.
+    pub fn check_invariants(env: Env) -> Vec<InvariantViolation> {
+        let mut violations = Vec::new(&env);
+        let mut report = |violation: InvariantViolation| {
+            if !violations.contains(violation) {
+                violations.push_back(violation);
+            }
+        };
+
+        let mut tokens = get_dust_tokens(&env);
+        let usdc = get_usdc_token(&env);
+        if !tokens.contains(&usdc) {
+            tokens.push_back(usdc);
+        }
+        for token_address in tokens.iter() {
+            let reserve = reserve_accounting(&env, token_address);
+            if reserve.partner_fees_owed < 0 || reserve.dust_owed < 0 || reserve.rebates_owed < 0 {
+                report(InvariantViolation::NegativeObligation);
+            }
+            if reserve.surplus < 0 {
+                report(InvariantViolation::ReserveShortfall);
+            }
+        }
+
+        let totals = get_payment_totals(&env);
+        if totals.total_fees_collected > totals.total_volume {
+            report(InvariantViolation::FeesExceedVolume);
+        }
+        if totals.total_refunded > totals.total_volume {
+            report(InvariantViolation::RefundsExceedVolume);
+        }
+
+        let limits = get_purchase_limits(&env);
+        let max_ticket_price = get_max_ticket_price(&env);
+        if (limits.max_purchase_amount > 0 && limits.min_ticket_price > limits.max_purchase_amount)
+            || (max_ticket_price > 0 && limits.min_ticket_price > max_ticket_price)
+        {
+            report(InvariantViolation::LimitsInconsistent);
+        }
+
+        let registry_client = event_registry::Client::new(&env, &get_event_registry(&env));
+        match registry_client.try_get_registry_stats() {
+            Ok(Ok(stats)) if stats.active_events > stats.total_events => {
+                report(InvariantViolation::RegistryStatsInconsistent)
+            }
+            Ok(Ok(_)) => {}
+            _ => report(InvariantViolation::RegistryUnavailable),
+        }
+
+        violations
+    }
+
+    /// Chooses who receives the remainder when the platform fee doesn't
+    /// divide evenly. Only callable by the administrator, with the current
+    /// `get_admin_nonce`.
+    pub fn set_rounding_beneficiary(
+        env: Env,
+        beneficiary: RoundingBeneficiary,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        set_rounding_beneficiary(&env, beneficiary);
+        Ok(())
+    }
+
+    /// Returns who receives fee rounding remainders.
+    pub fn get_rounding_beneficiary(env: Env) -> RoundingBeneficiary {
+        get_rounding_beneficiary(&env)
+    }
+
+    /// Returns the rounding dust held in `token_address` awaiting a sweep.
+    pub fn get_dust_balance(env: Env, token_address: Address) -> i128 {
+        get_dust_balance(&env, &token_address)
+    }
+
+    /// Sweeps accumulated rounding dust to the platform wallet. Only callable
+    /// by the administrator.
+    pub fn sweep_dust(env: Env, token_address: Address) -> Result<i128, TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let amount = get_dust_balance(&env, &token_address);
+        if amount <= 0 {
+            return Err(TicketPaymentError::NothingToWithdraw);
+        }
+
+        set_dust_balance(&env, &token_address, 0);
+        let platform_wallet = get_platform_wallet(&env);
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &platform_wallet,
+            &amount,
+        );
+        Ok(amount)
+    }
+
+    /// Withdraws all partner fees accrued in `token` to the partner.
+    #[allow(deprecated)]
+    pub fn withdraw_partner_fees(
+        env: Env,
+        partner: Address,
+        token_address: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        partner.require_auth();
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let amount = get_partner_balance(&env, &partner, &token_address);
+        if amount <= 0 {
+            return Err(TicketPaymentError::NothingToWithdraw);
+        }
+
+        set_partner_balance(&env, &partner, &token_address, 0);
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &partner,
+            &amount,
+        );
+
+        env.events().publish(
+            (AgoraEvent::PartnerFeesWithdrawn,),
+            PartnerFeesWithdrawnEvent {
+                partner,
+                token_address,
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(amount)
+    }
+
+    /// Starts, changes or (with `None`) ends the early-organizer fee rebate.
+    /// Only sales made while a program is set accrue rebates. Only callable
+    /// by the administrator, with the current `get_admin_nonce`.
+    pub fn set_rebate_program(
+        env: Env,
+        program: Option<RebateProgram>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        if let Some(program) = &program {
+            if program.max_rebate_bps == 0
+                || program.max_rebate_bps > 10000
+                || program.full_rebate_until > program.ends_at
+            {
+                return Err(TicketPaymentError::InvalidRebateProgram);
+            }
+        }
+        set_rebate_program(&env, program);
+        Ok(())
+    }
+
+    /// Returns the active early-organizer rebate program, if any.
+    pub fn get_rebate_program(env: Env) -> Option<RebateProgram> {
+        get_rebate_program(&env)
+    }
+
+    /// Returns the fee rebate an event payout address can claim in `token`.
+    pub fn get_rebate_balance(env: Env, payout_address: Address, token_address: Address) -> i128 {
+        get_rebate_balance(&env, &payout_address, &token_address)
+    }
+
+    /// Pays out all fee rebates accrued to `payout_address` in `token`.
+    /// Purchases settle when they are made, so a rebate can be claimed as
+    /// soon as its sale goes through.
+    pub fn claim_fee_rebate(
+        env: Env,
+        payout_address: Address,
+        token_address: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        payout_address.require_auth();
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let amount = get_rebate_balance(&env, &payout_address, &token_address);
+        if amount <= 0 {
+            return Err(TicketPaymentError::NothingToWithdraw);
+        }
+
+        set_rebate_balance(&env, &payout_address, &token_address, 0);
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &payout_address,
+            &amount,
+        );
+
+        env.events().publish(
+            (AgoraEvent::FeeRebateClaimed, payout_address.clone()),
+            FeeRebateClaimedEvent {
+                payout_address,
+                token_address,
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(amount)
+    }
+
+    /// Approves a hook contract to be notified after every purchase and
+    /// refund. Hooks are best-effort: failures are ignored and never block
+    /// the payment. Only callable by the administrator, with the current
+    /// `get_admin_nonce`.
+    pub fn add_hook(env: Env, hook: Address, nonce: u64) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        let mut hooks = get_hooks(&env);
+        if hooks.contains(&hook) {
+            return Ok(());
+        }
+        if hooks.len() >= MAX_HOOKS {
+            return Err(TicketPaymentError::TooManyHooks);
+        }
+        hooks.push_back(hook);
+        set_hooks(&env, &hooks);
+        Ok(())
+    }
+
+    /// Stops notifying a hook contract. Only callable by the administrator.
+    pub fn remove_hook(env: Env, hook: Address) {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        let mut hooks = get_hooks(&env);
+        if let Some(index) = hooks.first_index_of(&hook) {
+            hooks.remove(index);
+            set_hooks(&env, &hooks);
+        }
+    }
+
+    /// Returns the approved hook contracts, in notification order.
+    pub fn get_hooks(env: Env) -> Vec<Address> {
+        get_hooks(&env)
+    }
+
+    /// Sets the platform-wide maximum price per ticket, a guard against
+    /// fat-finger pricing (0 = no cap). Only callable by the administrator,
+    /// with the current `get_admin_nonce`.
+    pub fn set_max_ticket_price(
+        env: Env,
+        max_price: i128,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        if max_price < 0 {
+            return Err(TicketPaymentError::InvalidPriceLimit);
+        }
+        set_max_ticket_price(&env, max_price);
+        Ok(())
+    }
+
+    /// Sets the platform-wide default fee strategy, replacing the registry fee
+    /// of events without a tenant or subscription plan fee; `None` reverts to
+    /// the registry fee. Only callable by the administrator, with the current
+    /// `get_admin_nonce`.
+    pub fn set_fee_strategy(
+        env: Env,
+        strategy: Option<FeeStrategy>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        if let Some(strategy) = &strategy {
+            validate_fee_strategy(strategy)?;
+        }
+        set_fee_strategy(&env, strategy);
+        Ok(())
+    }
+
+    /// Overrides the fee strategy for a single event; `None` removes the
+    /// override. Only callable by the administrator, with the current
+    /// `get_admin_nonce`.
+    pub fn set_event_fee_strategy(
+        env: Env,
+        event_id: String,
+        strategy: Option<FeeStrategy>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        if let Some(strategy) = &strategy {
+            validate_fee_strategy(strategy)?;
+        }
+        set_event_fee_strategy(&env, event_id, strategy);
+        Ok(())
+    }
+
+    /// Returns the event's fee strategy override, else the platform-wide
+    /// default, or `None` when only the registry fee in basis points is set.
+    /// A tenant or plan fee still takes precedence over the platform default.
+    pub fn get_fee_strategy(env: Env, event_id: String) -> Option<FeeStrategy> {
+        resolve_fee_strategy(&env, event_id)
+    }
+
+    /// Overrides the maximum ticket price for a single event (0 = no cap);
+    /// `None` removes the override. Only callable by the administrator.
+    pub fn set_event_max_ticket_price(
+        env: Env,
+        event_id: String,
+        max_price: Option<i128>,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        if matches!(max_price, Some(price) if price < 0) {
+            return Err(TicketPaymentError::InvalidPriceLimit);
+        }
+        set_event_max_ticket_price(&env, event_id, max_price);
+        Ok(())
+    }
+
+    /// Returns the maximum ticket price in effect for an event (0 = no cap).
+    pub fn get_max_ticket_price(env: Env, event_id: String) -> i128 {
+        effective_max_ticket_price(&env, event_id)
+    }
+
+    /// Sets the platform-wide minimum ticket price and maximum purchase total
+    /// (ticket plus add-ons); 0 disables either bound. Only callable by the
+    /// administrator, with the current `get_admin_nonce`.
+    pub fn set_purchase_limits(
+        env: Env,
+        min_ticket_price: i128,
+        max_purchase_amount: i128,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        if min_ticket_price < 0
+            || max_purchase_amount < 0
+            || (max_purchase_amount > 0 && min_ticket_price > max_purchase_amount)
+        {
+            return Err(TicketPaymentError::InvalidPriceLimit);
+        }
+        set_purchase_limits(
+            &env,
+            &PurchaseLimits {
+                min_ticket_price,
+                max_purchase_amount,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the platform-wide purchase bounds, in token base units.
+    pub fn get_purchase_limits(env: Env) -> PurchaseLimits {
+        get_purchase_limits(&env)
+    }
+
+    /// Sets the ed25519 public key whose signatures are accepted as purchase
+    /// eligibility attestations. Only callable by the administrator, with
+    /// the current `get_admin_nonce`.
+    pub fn set_attestor(
+        env: Env,
+        attestor: BytesN<32>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        set_attestor_key(&env, &attestor);
+        Ok(())
+    }
+
+    /// Returns the configured attestor public key, if any.
+    pub fn get_attestor(env: Env) -> Option<BytesN<32>> {
+        get_attestor_key(&env)
+    }
+
+    /// Sets the ed25519 public key whose signatures are accepted on price
+    /// quotes. Only callable by the administrator, with the current
+    /// `get_admin_nonce`.
+    pub fn set_quote_key(
+        env: Env,
+        quote_key: BytesN<32>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        set_quote_key(&env, &quote_key);
+        Ok(())
+    }
+
+    /// Returns the configured price quote public key, if any.
+    pub fn get_quote_key(env: Env) -> Option<BytesN<32>> {
+        get_quote_key(&env)
+    }
+
+    /// Appoints the incident-response guardian, or removes it with `None`.
+    /// The guardian can only pause the contract and place temporary freezes
+    /// on payments; it can't move funds or change configuration. Only
+    /// callable by the administrator, with the current `get_admin_nonce`.
+    pub fn set_guardian(
+        env: Env,
+        guardian: Option<Address>,
+        nonce: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        consume_admin_nonce(&env, nonce)?;
+        set_guardian(&env, guardian);
+        Ok(())
+    }
+
+    /// Returns the guardian, if one is appointed.
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        get_guardian(&env)
+    }
+
+    /// Blocks purchases, refunds and withdrawals until the administrator
+    /// calls `unpause`. Callable by the guardian or the administrator.
+    pub fn pause(env: Env, caller: Address) -> Result<(), TicketPaymentError> {
+        require_guardian_or_admin(&env, &caller)?;
+        set_paused(&env, true);
+        env.events().publish(
+            (AgoraEvent::PauseChanged,),
+            PauseChangedEvent {
+                paused: true,
+                by: caller,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Lifts a pause. Only callable by the administrator.
+    pub fn unpause(env: Env) {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        set_paused(&env, false);
+        env.events().publish(
+            (AgoraEvent::PauseChanged,),
+            PauseChangedEvent {
+                paused: false,
+                by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Returns whether the contract is paused.
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    /// Freezes a suspicious ticket for `GUARDIAN_FREEZE_SECS`: it can't be
+    /// refunded, burned, claimed or have its refund redirected until the
+    /// freeze lapses or is lifted. Callable by the guardian or the
+    /// administrator; freezing again restarts the window.
+    pub fn freeze_payment(
+        env: Env,
+        caller: Address,
+        payment_id: String,
+    ) -> Result<(), TicketPaymentError> {
+        require_guardian_or_admin(&env, &caller)?;
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        if active_freeze(&env, payment_id.clone()).is_some_and(|freeze| freeze.escalated) {
+            return Err(TicketPaymentError::PaymentFrozen);
+        }
+
+        let now = env.ledger().timestamp();
+        let freeze = PaymentFreeze {
+            frozen_by: caller,
+            frozen_at: now,
+            expires_at: now + GUARDIAN_FREEZE_SECS,
+            escalated: false,
+        };
+        publish_freeze(&env, payment_id.clone(), &payment, &freeze);
+        set_payment_freeze(&env, payment_id, Some(freeze));
+        Ok(())
+    }
+
+    /// Keeps an active freeze in place until `unfreeze_payment` is called.
+    /// Only callable by the administrator.
+    pub fn escalate_freeze(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        let mut freeze =
+            active_freeze(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFrozen)?;
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+
+        freeze.escalated = true;
+        publish_freeze(&env, payment_id.clone(), &payment, &freeze);
+        set_payment_freeze(&env, payment_id, Some(freeze));
+        Ok(())
+    }
+
+    /// Lifts a freeze, escalated or not. Only callable by the administrator.
+    pub fn unfreeze_payment(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        active_freeze(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFrozen)?;
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+
+        set_payment_freeze(&env, payment_id.clone(), None);
+        env.events().publish(
+            event_topics(
+                &env,
+                &payment.event_id,
+                (AgoraEvent::PaymentUnfrozen, payment.payout_address),
+            ),
+            PaymentUnfrozenEvent {
+                payment_id,
+                event_id: payment.event_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the freeze on a payment while it is in force.
+    pub fn get_payment_freeze(env: Env, payment_id: String) -> Option<PaymentFreeze> {
+        active_freeze(&env, payment_id)
+    }
+
+    /// Marks an event as requiring an eligibility attestation for purchases.
+    /// Only callable by the administrator.
+    pub fn set_event_restricted(env: Env, event_id: String, restricted: bool) {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        set_restricted_event(&env, event_id, restricted);
+    }
+
+    /// Returns whether purchases for an event require an eligibility attestation.
+    pub fn is_event_restricted(env: Env, event_id: String) -> bool {
+        is_restricted_event(&env, event_id)
+    }
+
+    /// Confirms a payment after backend verification.
+    pub fn confirm_payment(env: Env, payment_id: String, transaction_hash: String) {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        // In a real scenario, this would be restricted to a specific backend/admin address.
+        update_payment_status(
+            &env,
+            payment_id.clone(),
+            PaymentStatus::Confirmed,
+            Some(env.ledger().timestamp()),
+        );
+
+        // Update the transaction hash
+        if let Some(mut payment) = get_payment(&env, payment_id.clone()) {
+            payment.transaction_hash = transaction_hash.clone();
+            save_payment(&env, &payment);
+        }
+
+        // Emit confirmation event
+        env.events().publish(
+            (AgoraEvent::PaymentStatusChanged,),
+            PaymentStatusChangedEvent {
+                payment_id: payment_id.clone(),
+                old_status: PaymentStatus::Pending,
+                new_status: PaymentStatus::Confirmed,
+                transaction_hash: transaction_hash.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Refunds specific payments of an event in full, e.g. after overbooking or
+    /// when converting a sale into a comp ticket. Refunds are paid from the
+    /// organizer payout address that received the original funds, which must
+    /// authorize the call; the platform fee is not clawed back, and any fee
+    /// rebate the sale accrued goes back to the platform. All payments in
+    /// the batch must belong to `event_id`, share that payout address and not
+    /// already be refunded. Refunded tickets go back to the event's supply.
+    #[allow(deprecated)]
+    pub fn refund_payments(
+        env: Env,
+        event_id: String,
+        payment_ids: Vec<String>,
+        reason: RefundReason,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if payment_ids.len() > MAX_REFUND_BATCH {
+            return Err(TicketPaymentError::BatchTooLarge);
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+
+        let mut refunder: Option<Address> = None;
+        let mut totals = get_payment_totals(&env);
+        let day = env.ledger().timestamp() / SECONDS_PER_DAY;
+        let mut daily = get_daily_stats(&env, event_id.clone(), day);
+        let mut sales = get_event_sales(&env, event_id.clone());
+        let partner_key = event_partner_key(&env, &event_id);
+        for payment_id in payment_ids.iter() {
+            let mut payment =
+                get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+            if payment.event_id != event_id {
+                return Err(TicketPaymentError::PaymentNotRefundable);
+            }
+            ensure_not_frozen(&env, payment_id.clone())?;
+            let refund_amount = refund_quote(&payment)?;
+
+            match &refunder {
+                None => {
+                    payment.payout_address.require_auth();
+                    refunder = Some(payment.payout_address.clone());
+                }
+                Some(addr) if *addr != payment.payout_address => {
+                    return Err(TicketPaymentError::PaymentNotRefundable);
+                }
+                _ => {}
+            }
+
+            let recipient = match get_refund_redirect(&env, payment_id.clone()) {
+                Some(redirect) if redirect.effective_at <= env.ledger().timestamp() => redirect.to,
+                _ => payment.buyer_address.clone(),
+            };
+            set_refund_redirect(&env, payment_id.clone(), None);
+            token::Client::new(&env, &payment.token_address).transfer(
+                &payment.payout_address,
+                &recipient,
+                &refund_amount,
+            );
+            reclaim_rebate(&env, &payment, refund_amount);
+
+            let old_status = payment.status.clone();
+            payment.status = PaymentStatus::Refunded;
+            save_payment(&env, &payment);
+            totals.total_refunded += refund_amount;
+            daily.refunds_count += 1;
+            daily.refunded += refund_amount;
+            sales.active_tickets = sales.active_tickets.saturating_sub(1);
+            sales.refunded += refund_amount;
+
+            let mut summary = get_buyer_summary(&env, &payment.buyer_address);
+            summary.refunds_received += refund_amount;
+            summary.active_tickets = summary.active_tickets.saturating_sub(1);
+            set_buyer_summary(&env, &payment.buyer_address, &summary);
+            increment_refund_reason_count(&env, event_id.clone(), reason);
+            notify_hooks(
+                &env,
+                HookPayload {
+                    kind: HookKind::Refund,
+                    payment_id: payment_id.clone(),
+                    event_id: event_id.clone(),
+                    buyer_address: payment.buyer_address.clone(),
+                    amount: refund_amount,
+                },
+            );
+
+            env.events().publish(
+                with_partner_key(&env, &partner_key, (AgoraEvent::PaymentStatusChanged,)),
+                PaymentStatusChangedEvent {
+                    payment_id: payment_id.clone(),
+                    old_status,
+                    new_status: PaymentStatus::Refunded,
+                    transaction_hash: payment.transaction_hash.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+            env.events().publish(
+                with_partner_key(
+                    &env,
+                    &partner_key,
+                    (
+                        AgoraEvent::PaymentRefunded,
+                        payment.payout_address.clone(),
+                        reason,
+                    ),
+                ),
+                PaymentRefundedEvent {
+                    payment_id,
+                    event_id: event_id.clone(),
+                    buyer_address: payment.buyer_address,
+                    recipient,
+                    amount: refund_amount,
+                    reason,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        set_payment_totals(&env, &totals);
+        set_daily_stats(&env, event_id.clone(), day, &daily);
+        set_event_sales(&env, event_id.clone(), &sales);
+        if !payment_ids.is_empty() {
+            release_inventory(&env, &event_id, payment_ids.len())?;
+        }
+
+        Ok(())
+    }
+
+    /// Directs the refund of a ticket to `to` instead of the buyer, e.g. after
+    /// a wallet rotation. The redirect only applies to refunds made
+    /// `REFUND_REDIRECT_COOLDOWN` seconds or more after it is signed; until
+    /// then refunds still go to the buyer. `None` cancels a redirect at once.
+    pub fn set_refund_address(
+        env: Env,
+        payment_id: String,
+        to: Option<Address>,
+    ) -> Result<(), TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
+        if !is_active_ticket(&payment) {
+            return Err(TicketPaymentError::TicketNotActive);
+        }
+        ensure_not_frozen(&env, payment_id.clone())?;
+
+        let effective_at = env.ledger().timestamp() + REFUND_REDIRECT_COOLDOWN;
+        let redirect = match &to {
+            Some(address) => {
+                if *address == payment.buyer_address {
+                    return Err(TicketPaymentError::InvalidRefundAddress);
+                }
+                validate_address(&env, address)?;
+                Some(RefundRedirect {
+                    to: address.clone(),
+                    effective_at,
+                })
+            }
+            None => None,
+        };
+        set_refund_redirect(&env, payment_id.clone(), redirect);
+
+        env.events().publish(
+            event_topics(
+                &env,
+                &payment.event_id,
+                (AgoraEvent::RefundRedirected, payment.buyer_address.clone()),
+            ),
+            RefundRedirectedEvent {
+                payment_id,
+                buyer_address: payment.buyer_address,
+                to,
+                effective_at,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Redacts the personal fields of a payment (transaction memo, attestation
+    /// hash, beneficiary ID, campaign attribution) to honour a deletion
+    /// request. Amounts, status and the buyer address stay intact so totals,
+    /// receipts and refunds keep working; `redacted_at` marks the record.
+    /// Only callable by the administrator.
+    ///
+    /// Earlier ledger entries and emitted events are outside the contract's
+    /// reach and are not affected.
+    pub fn redact_payment(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        if payment.redacted_at.is_some() {
+            return Err(TicketPaymentError::AlreadyRedacted);
+        }
+
+        payment.transaction_hash = String::from_str(&env, "");
+        payment.attestation_hash = None;
+        payment.beneficiary_id = None;
+        payment.attribution = None;
+        payment.redacted_at = Some(env.ledger().timestamp());
+        save_payment(&env, &payment);
+        set_questionnaire_answers(&env, payment_id.clone(), None);
+
+        env.events().publish(
+            event_topics(
+                &env,
+                &payment.event_id,
+                (AgoraEvent::PaymentRedacted, payment.payout_address),
+            ),
+            PaymentRedactedEvent {
+                payment_id,
+                event_id: payment.event_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the refund redirect signed for a ticket, if any.
+    pub fn get_refund_address(env: Env, payment_id: String) -> Option<RefundRedirect> {
+        get_refund_redirect(&env, payment_id)
+    }
+
+    /// Returns the exact amount `refund_payments` would pay the buyer for this
+    /// payment right now. Refunds are organizer-initiated and return the full
+    /// purchase amount, add-ons included.
+    pub fn quote_refund(env: Env, payment_id: String) -> Result<i128, TicketPaymentError> {
+        let payment = get_payment(&env, payment_id).ok_or(TicketPaymentError::PaymentNotFound)?;
+        refund_quote(&payment)
+    }
+
+    /// Lets the holder voluntarily give up a ticket. The ticket is marked
+    /// `Burned`, drops out of the holder's wallet and its slot goes back to
+    /// the event's supply in the registry; no funds move, since
+    /// sale proceeds sit with the organizer and refunds need their approval
+    /// through `refund_payments`.
+    #[allow(deprecated)]
+    pub fn burn_ticket(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
+        if !is_active_ticket(&payment) {
+            return Err(TicketPaymentError::TicketNotActive);
+        }
+        ensure_not_frozen(&env, payment_id.clone())?;
+
+        let old_status = payment.status.clone();
+        payment.status = PaymentStatus::Burned;
+        save_payment(&env, &payment);
+        remove_ticket_claim(&env, payment_id.clone());
+
+        let mut summary = get_buyer_summary(&env, &payment.buyer_address);
+        summary.active_tickets = summary.active_tickets.saturating_sub(1);
+        set_buyer_summary(&env, &payment.buyer_address, &summary);
+        let mut sales = get_event_sales(&env, payment.event_id.clone());
+        sales.active_tickets = sales.active_tickets.saturating_sub(1);
+        set_event_sales(&env, payment.event_id.clone(), &sales);
+        release_inventory(&env, &payment.event_id, 1)?;
+
+        let partner_key = event_partner_key(&env, &payment.event_id);
+        env.events().publish(
+            with_partner_key(&env, &partner_key, (AgoraEvent::PaymentStatusChanged,)),
+            PaymentStatusChangedEvent {
+                payment_id: payment_id.clone(),
+                old_status,
+                new_status: PaymentStatus::Burned,
+                transaction_hash: payment.transaction_hash.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        env.events().publish(
+            with_partner_key(
+                &env,
+                &partner_key,
+                (AgoraEvent::TicketBurned, payment.payout_address.clone()),
+            ),
+            TicketBurnedEvent {
+                payment_id,
+                event_id: payment.event_id,
+                holder: payment.buyer_address,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Dry-runs the checks of `process_payment` without moving funds or
+    /// writing state. Returns the `TicketPaymentError` codes of every
+    /// violation found; an empty result means the purchase should succeed.
+    pub fn validate_purchase(
+        env: Env,
+        event_id: String,
+        buyer_address: Address,
+        token_address: Address,
+        amount: i128,
+    ) -> Vec<u32> {
+        let mut codes = Vec::new(&env);
+        for violation in
+            Self::validate_purchase_detailed(env, event_id, buyer_address, token_address, amount)
+                .iter()
+        {
+            codes.push_back(violation.code);
+        }
+        codes
+    }
+
+    /// Like `validate_purchase`, but each violation carries the numbers behind
+    /// it: the price cap for `PriceExceedsMaximum`, the buyer's balance for
+    /// `InsufficientBalance`, what is left of the period's allowance for
+    /// `SpendCapExceeded`. Contract errors cannot carry payloads and failed
+    /// calls roll back any state, so this is the way to get error context.
+    pub fn validate_purchase_detailed(
+        env: Env,
+        event_id: String,
+        buyer_address: Address,
+        token_address: Address,
+        amount: i128,
+    ) -> Vec<ViolationDetail> {
+        let mut violations = Vec::new(&env);
+        let violation = |error: TicketPaymentError, limit: i128| ViolationDetail {
+            code: error as u32,
+            limit,
+            requested: amount,
+        };
+        if !is_initialized(&env) {
+            violations.push_back(violation(TicketPaymentError::NotInitialized, 0));
+            return violations;
+        }
+
+        if get_pending_migration(&env).is_some() {
+            violations.push_back(violation(TicketPaymentError::MigrationPending, 0));
+        }
+        if is_paused(&env) {
+            violations.push_back(violation(TicketPaymentError::ContractPaused, 0));
+        }
+        if amount <= 0 {
+            violations.push_back(violation(TicketPaymentError::InvalidAmount, 0));
+        }
+        if validate_identifier(&event_id).is_err() {
+            violations.push_back(violation(
+                TicketPaymentError::InvalidIdentifier,
+                MAX_ID_LEN as i128,
+            ));
+        }
+        if is_restricted_event(&env, event_id.clone()) {
+            violations.push_back(violation(TicketPaymentError::AttestationRequired, 0));
+        }
+        if get_questionnaire(&env, event_id.clone()).is_some() {
+            violations.push_back(violation(TicketPaymentError::QuestionnaireRequired, 0));
+        }
+        let max_price = effective_max_ticket_price(&env, event_id.clone());
+        if max_price > 0 && amount > max_price {
+            violations.push_back(violation(
+                TicketPaymentError::PriceExceedsMaximum,
+                max_price,
+            ));
+        }
+        let limits = get_purchase_limits(&env);
+        if amount < limits.min_ticket_price {
+            violations.push_back(violation(
+                TicketPaymentError::PriceBelowMinimum,
+                limits.min_ticket_price,
+            ));
+        }
+        if limits.max_purchase_amount > 0 && amount > limits.max_purchase_amount {
+            violations.push_back(violation(
+                TicketPaymentError::PurchaseExceedsMaximum,
+                limits.max_purchase_amount,
+            ));
+        }
+        if let Some(cap) = current_spend_cap(&env, &buyer_address) {
+            let remaining = cap.max_per_period - cap.spent_in_period;
+            if amount > remaining {
+                violations.push_back(violation(TicketPaymentError::SpendCapExceeded, remaining));
+            }
+        }
+
+        let registry_client = event_registry::Client::new(&env, &get_event_registry(&env));
+        match fetch_payment_info(&registry_client, &event_id) {
+            Ok(payment_info) if !accepts_token(&payment_info, &token_address) => {
+                violations.push_back(violation(TicketPaymentError::TokenNotWhitelisted, 0));
+            }
+            Ok(_) => {}
+            Err(e) => violations.push_back(violation(e, 0)),
+        }
+
+        if !is_token_whitelisted(&env, &token_address) {
+            violations.push_back(violation(token_rejection(&env, &token_address), 0));
+        } else {
+            let balance = token::Client::new(&env, &token_address).balance(&buyer_address);
+            if balance < amount {
+                violations.push_back(violation(TicketPaymentError::InsufficientBalance, balance));
+            }
+        }
+
+        violations
+    }
+
+    /// Runs `validate_purchase_detailed` as a buyer-signed transaction and
+    /// counts each violation against the event for `get_rejection_stats`.
+    /// A failed `process_payment` rolls back everything it wrote, so a
+    /// checkout that wants lost demand counted submits this before paying.
+    /// Each buyer's rejections count at most once per event per day, so
+    /// repeating the preflight doesn't inflate the stats.
+    pub fn preflight_purchase(
+        env: Env,
+        event_id: String,
+        buyer_address: Address,
+        token_address: Address,
+        amount: i128,
+    ) -> Vec<ViolationDetail> {
+        buyer_address.require_auth();
+        let violations = Self::validate_purchase_detailed(
+            env.clone(),
+            event_id.clone(),
+            buyer_address.clone(),
+            token_address,
+            amount,
+        );
+        if !violations.is_empty() && mark_preflight_counted(&env, event_id.clone(), &buyer_address)
+        {
+            let mut codes = Vec::new(&env);
+            for violation in violations.iter() {
+                codes.push_back(violation.code);
+            }
+            record_rejections(&env, event_id, &codes);
+        }
+        violations
+    }
+
+    /// Preflight rejections recorded by `preflight_purchase` for an event,
+    /// keyed by `TicketPaymentError` code. They only cover checkouts that ran
+    /// the preflight, not every failed purchase. Counts live in temporary
+    /// storage and lapse about 30 days after the last recorded rejection.
+    pub fn get_rejection_stats(env: Env, event_id: String) -> Map<u32, u32> {
+        get_rejection_stats(&env, event_id)
+    }
+
+    /// Returns the buyer's active (not refunded or burned) tickets, newest first, with
+    /// each event's live metadata CID read from the Event Registry, so a
+    /// wallet can render "My Tickets" from one simulated call. At most
+    /// `MAX_PAGE_SIZE` tickets are returned.
+    pub fn get_wallet_view(env: Env, buyer_address: Address) -> Vec<WalletTicket> {
+        let registry_client = event_registry::Client::new(&env, &get_event_registry(&env));
+        let payment_ids = get_buyer_payments(&env, buyer_address);
+        let mut tickets = Vec::new(&env);
+        for index in (0..payment_ids.len()).rev() {
+            if tickets.len() >= MAX_PAGE_SIZE {
+                break;
+            }
+            let Some(payment) = get_payment(&env, payment_ids.get_unchecked(index)) else {
+                continue;
+            };
+            if !is_active_ticket(&payment) {
+                continue;
+            }
+            let metadata_cid = match registry_client.try_get_event_metadata(&payment.event_id) {
+                Ok(Ok(cid)) => cid,
+                _ => payment.metadata_cid.clone(),
+            };
+            tickets.push_back(WalletTicket {
+                payment_id: payment.payment_id,
+                event_id: payment.event_id,
+                ticket_tier_id: payment.ticket_tier_id,
+                metadata_cid,
+                purchased_metadata_cid: payment.metadata_cid,
+                status: payment.status,
+                purchased_at: payment.created_at,
+            });
+        }
+        tickets
+    }
+
+    /// Returns a canonical receipt for a payment, suitable for SEP-style
+    /// interop: the struct encodes to a stable SCVal/XDR form.
+    pub fn get_receipt(env: Env, payment_id: String) -> Result<PaymentReceipt, TicketPaymentError> {
+        let payment = get_payment(&env, payment_id).ok_or(TicketPaymentError::PaymentNotFound)?;
+        Ok(build_receipt(&env, payment))
+    }
+
+    /// Returns whether `receipt` was issued by this contract on this network
+    /// for a ticket that is still active (not refunded or burned).
+    pub fn verify_receipt(env: Env, receipt: PaymentReceipt) -> bool {
+        if receipt.issuer != env.current_contract_address() {
+            return false;
+        }
+        match get_payment(&env, receipt.payment_id.clone()) {
+            Some(payment) if is_active_ticket(&payment) => build_receipt(&env, payment) == receipt,
+            _ => false,
+        }
+    }
+
+    /// Returns the event metadata CID frozen into the ticket at purchase
+    /// alongside the event's current, possibly edited, metadata CID.
+    pub fn get_ticket_metadata(
+        env: Env,
+        payment_id: String,
+    ) -> Result<TicketMetadata, TicketPaymentError> {
+        let payment = get_payment(&env, payment_id).ok_or(TicketPaymentError::PaymentNotFound)?;
+        let registry_client = event_registry::Client::new(&env, &get_event_registry(&env));
+        let live_metadata_cid = match registry_client.try_get_event_metadata(&payment.event_id) {
+            Ok(Ok(cid)) => cid,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+
+        Ok(TicketMetadata {
+            frozen_metadata_cid: payment.metadata_cid,
+            live_metadata_cid,
+        })
+    }
+
+    /// Returns a buyer's purchase history summary in a single read.
+    pub fn get_buyer_summary(env: Env, buyer_address: Address) -> BuyerSummary {
+        get_buyer_summary(&env, &buyer_address)
+    }
+
+    /// Returns an event's lifetime sales and refund totals. Sales made before
+    /// these totals were introduced are not included.
+    pub fn get_event_sales(env: Env, event_id: String) -> EventSales {
+        get_event_sales(&env, event_id)
+    }
+
+    /// Returns an event's sales and refund aggregates for one day, where `day`
+    /// is the ledger timestamp divided by 86400 (days since the Unix epoch).
+    pub fn get_daily_stats(env: Env, event_id: String, day: u64) -> DailyStats {
+        get_daily_stats(&env, event_id, day)
+    }
+
+    /// Returns how many payments of an event were refunded for each reason.
+    pub fn get_refund_stats(env: Env, event_id: String) -> RefundReasonStats {
+        get_refund_reason_stats(&env, event_id)
+    }
+
+    /// Returns the status and details of a payment.
+    pub fn get_payment_status(env: Env, payment_id: String) -> Option<Payment> {
+        get_payment(&env, payment_id)
+    }
+
+    /// Returns platform-wide running totals in a single call: event counters
+    /// read from the Event Registry plus payment counters kept by this contract.
+    pub fn get_platform_stats(env: Env) -> PlatformStats {
+        let registry_client = event_registry::Client::new(&env, &get_event_registry(&env));
+        let registry_stats = registry_client.get_registry_stats();
+        let totals = get_payment_totals(&env);
+
+        PlatformStats {
+            events_registered: registry_stats.total_events,
+            active_events: registry_stats.active_events,
+            total_payments: totals.total_payments,
+            total_volume: totals.total_volume,
+            total_fees_collected: totals.total_fees_collected,
+            total_refunded: totals.total_refunded,
+        }
+    }
+
+    /// Returns an event's payments created in `[from_ts, to_ts)`, oldest first.
+    ///
+    /// Backed by a per-day index so reconciliation jobs only touch the days they
+    /// ask for. `cursor` is the number of matching payments to skip; pass the
+    /// returned `next_cursor` to fetch the following page. `limit` is capped at
+    /// `MAX_PAGE_SIZE` and the range may span at most `MAX_RANGE_DAYS` days.
+    pub fn get_payments_in_range(
+        env: Env,
+        event_id: String,
+        from_ts: u64,
+        to_ts: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<PaymentPage, TicketPaymentError> {
+        if to_ts <= from_ts {
+            return Err(TicketPaymentError::InvalidTimeRange);
+        }
+        let first_day = from_ts / SECONDS_PER_DAY;
+        let last_day = (to_ts - 1) / SECONDS_PER_DAY;
+        if last_day - first_day >= MAX_RANGE_DAYS {
+            return Err(TicketPaymentError::InvalidTimeRange);
+        }
+        let limit = limit.clamp(1, MAX_PAGE_SIZE);
+
+        let mut payments = Vec::new(&env);
+        let mut matched: u32 = 0;
+        for day in first_day..=last_day {
+            let is_edge_day = day == first_day || day == last_day;
+            for payment_id in get_event_payments_by_day(&env, event_id.clone(), day).iter() {
+                // Interior days are fully inside the range, so skipped entries
+                // don't need to be loaded.
+                if !is_edge_day && matched < cursor {
+                    matched += 1;
+                    continue;
+                }
+                let payment = match get_payment(&env, payment_id) {
+                    Some(payment) => payment,
+                    None => continue,
+                };
+                if payment.created_at < from_ts || payment.created_at >= to_ts {
+                    continue;
+                }
+                if matched >= cursor {
+                    if payments.len() == limit {
+                        return Ok(PaymentPage {
+                            payments,
+                            next_cursor: Some(matched),
+                        });
+                    }
+                    payments.push_back(payment);
+                }
+                matched += 1;
+            }
+        }
+
+        Ok(PaymentPage {
+            payments,
+            next_cursor: None,
+        })
+    }
+}
+
+/// Optional purchase inputs, checked by `execute_payment` for every
+/// purchase path.
+#[derive(Default)]
+struct PaymentExtras {
+    /// Ledger timestamp (seconds) the buyer signed as the purchase deadline
+    expires_at: u64,
+    attestation: Option<PurchaseAttestation>,
+    addons: Option<Vec<AddonSelection>>,
+    promo_code: Option<Bytes>,
+    answers: Option<QuestionnaireAnswers>,
+    attribution: Option<BytesN<32>>,
+    /// Price quote; the per-ticket price bounds don't apply to a quoted amount.
+    quote: Option<PriceQuote>,
+    beneficiary_id: Option<BytesN<32>>,
+    /// Pull funds with `transfer_from` under a standing approval instead of
+    /// requiring the buyer's signature on this invocation.
+    via_allowance: bool,
+}
+
+impl PaymentExtras {
+    /// Collects a caller's deadline and purchase extras, each kind at most once.
+    fn from_options(options: &PurchaseOptions) -> Result<Self, TicketPaymentError> {
+        fn put<T>(slot: &mut Option<T>, value: T) -> Result<(), TicketPaymentError> {
+            match slot.replace(value) {
+                Some(_) => Err(TicketPaymentError::DuplicatePurchaseExtra),
+                None => Ok(()),
+            }
+        }
+
+        let mut collected = Self {
+            expires_at: options.expires_at,
+            ..Default::default()
+        };
+        for extra in options.extras.iter() {
+            match extra {
+                PurchaseExtra::Attestation(value) => put(&mut collected.attestation, value)?,
+                PurchaseExtra::Addons(value) => put(&mut collected.addons, value)?,
+                PurchaseExtra::PromoCode(value) => put(&mut collected.promo_code, value)?,
+                PurchaseExtra::Answers(value) => put(&mut collected.answers, value)?,
+                PurchaseExtra::Attribution(value) => put(&mut collected.attribution, value)?,
+                PurchaseExtra::Quote(value) => put(&mut collected.quote, value)?,
+                PurchaseExtra::Beneficiary(value) => put(&mut collected.beneficiary_id, value)?,
+            }
+        }
+        Ok(collected)
+    }
+}
+
+#[allow(clippy::too_many_arguments, deprecated)]
+fn execute_payment(
+    env: &Env,
+    payment_id: String,
+    event_id: String,
+    ticket_tier_id: String,
+    buyer_address: Address,
+    token_address: Address,
+    ticket_amount: i128,
+    extras: PaymentExtras,
+) -> Result<String, TicketPaymentError> {
+    if !is_initialized(env) {
+        panic!("Contract not initialized");
+    }
+    if !extras.via_allowance {
+        buyer_address.require_auth();
+    }
+    // Part of what the buyer (or operator) signed, on every purchase path
+    if env.ledger().timestamp() > extras.expires_at {
+        return Err(TicketPaymentError::PurchaseExpired);
+    }
+
+    if ticket_amount <= 0 {
+        return Err(TicketPaymentError::InvalidAmount);
+    }
+
+    if get_pending_migration(env).is_some() {
+        return Err(TicketPaymentError::MigrationPending);
+    }
+    if is_paused(env) {
+        return Err(TicketPaymentError::ContractPaused);
+    }
+
+    validate_identifier(&payment_id)?;
+    validate_identifier(&event_id)?;
+    validate_identifier(&ticket_tier_id)?;
+    if extras.beneficiary_id.is_some() && !is_custodian(env, &buyer_address) {
+        return Err(TicketPaymentError::NotCustodian);
+    }
+
+    let PaymentExtras {
+        attestation,
+        addons,
+        promo_code,
+        answers,
+        attribution,
+        quote,
+        beneficiary_id,
+        via_allowance,
+        ..
+    } = extras;
+    let attestation_hash = match &attestation {
+        Some(attestation) => Some(verify_attestation(
+            env,
+            &buyer_address,
+            &event_id,
+            attestation,
+        )?),
+        None if is_restricted_event(env, event_id.clone()) => {
+            return Err(TicketPaymentError::AttestationRequired);
+        }
+        None => None,
+    };
+    match &answers {
+        Some(answers)
+            if answers.answers_cid.is_empty() || answers.answers_cid.len() > MAX_CID_LEN =>
+        {
+            return Err(TicketPaymentError::InvalidQuestionnaire);
+        }
+        None if get_questionnaire(env, event_id.clone()).is_some() => {
+            return Err(TicketPaymentError::QuestionnaireRequired);
+        }
+        _ => {}
+    }
+
+    if !is_token_whitelisted(env, &token_address) {
+        return Err(token_rejection(env, &token_address));
+    }
+
+    if let Some(quote) = &quote {
+        if quote.price != ticket_amount {
+            return Err(TicketPaymentError::InvalidAmount);
+        }
+        verify_quote(
+            env,
+            &buyer_address,
+            &event_id,
+            &ticket_tier_id,
+            &token_address,
+            quote,
+        )?;
+    }
+    // Redeem the promo code, taking the campaign's discount off the ticket
+    let mut ticket_amount = ticket_amount;
+    if let Some(promo_code) = promo_code {
+        let code_hash: BytesN<32> = env.crypto().sha256(&promo_code).to_bytes();
+        let campaign_id =
+            take_promo_code(env, &code_hash).ok_or(TicketPaymentError::InvalidPromoCode)?;
+        let mut campaign = get_promo_campaign(env, campaign_id.clone())
+            .ok_or(TicketPaymentError::InvalidPromoCode)?;
+        if campaign.event_id != event_id {
+            return Err(TicketPaymentError::InvalidPromoCode);
+        }
+
+        let discount = ticket_amount * campaign.discount_bps as i128 / 10000;
+        campaign.redemptions += 1;
+        campaign.discount_given += discount;
+        set_promo_campaign(env, campaign_id, &campaign);
+        ticket_amount -= discount;
+    }
+
+    let limits = get_purchase_limits(env);
+    if quote.is_none() {
+        let max_price = effective_max_ticket_price(env, event_id.clone());
+        if max_price > 0 && ticket_amount > max_price {
+            return Err(TicketPaymentError::PriceExceedsMaximum);
+        }
+        if ticket_amount < limits.min_ticket_price {
+            return Err(TicketPaymentError::PriceBelowMinimum);
+        }
+    }
+
+    // 1. Query Event Registry for payment info and platform fee
+    let event_registry_addr = get_event_registry(env);
+    let registry_client = event_registry::Client::new(env, &event_registry_addr);
+
+    let payment_info = fetch_payment_info(&registry_client, &event_id)?;
+    if !accepts_token(&payment_info, &token_address) {
+        return Err(TicketPaymentError::TokenNotWhitelisted);
+    }
+
+    // Reserve and price any add-ons bundled with the ticket
+    let addons = match addons {
+        Some(selections) if !selections.is_empty() => {
+            reserve_addons(&registry_client, &event_id, &selections)?
+        }
+        _ => Vec::new(env),
+    };
+
+    take_inventory(&registry_client, &event_id, &buyer_address)?;
+    let mut amount = ticket_amount;
+    for item in addons.iter() {
+        amount = amount
+            .checked_add(item.line_total)
+            .ok_or(TicketPaymentError::InvalidAddon)?;
+    }
+    if limits.max_purchase_amount > 0 && amount > limits.max_purchase_amount {
+        return Err(TicketPaymentError::PurchaseExceedsMaximum);
+    }
+    charge_spend_cap(env, &buyer_address, amount)?;
+    if via_allowance {
+        charge_standing_approval(env, &buyer_address, amount)?;
+    }
+
+    // 2. Calculate platform fee, routing any rounding remainder to the
+    // configured beneficiary
+    let strategy = payment_fee_strategy(env, event_id.clone(), &payment_info);
+    let (fee_floor, has_remainder) = compute_fee(&strategy, amount);
+    let (platform_fee, rounding_dust) = match get_rounding_beneficiary(env) {
+        RoundingBeneficiary::Platform if has_remainder => (fee_floor + 1, 0),
+        RoundingBeneficiary::DustBucket if has_remainder => (fee_floor, 1),
+        _ => (fee_floor, 0),
+    };
+    let organizer_amount = amount - platform_fee - rounding_dust;
+
+    // 3. Transfer tokens from buyer (splitting payment)
+    let token_client = token::Client::new(env, &token_address);
+    let platform_wallet = get_platform_wallet(env);
+
+    let pay = |to: &Address, value: i128| {
+        if via_allowance {
+            token_client.transfer_from(&env.current_contract_address(), &buyer_address, to, &value);
+        } else {
+            token_client.transfer(&buyer_address, to, &value);
+        }
+    };
+
+    let mut legs = Vec::new(env);
+    let leg = |kind: SettlementLegKind, recipient: &Address, amount: i128| SettlementLeg {
+        kind,
+        recipient: recipient.clone(),
+        amount,
+    };
+
+    // Transfer platform fee, holding back any white-label partner's slice
+    let mut platform_amount = platform_fee;
+    if let Some(partner) = get_event_partner(env, event_id.clone()) {
+        let partner_fee = (platform_fee * partner.share_bps as i128) / 10000;
+        if partner_fee > 0 {
+            pay(&env.current_contract_address(), partner_fee);
+            let balance = get_partner_balance(env, &partner.partner, &token_address);
+            set_partner_balance(env, &partner.partner, &token_address, balance + partner_fee);
+            platform_amount -= partner_fee;
+            legs.push_back(leg(
+                SettlementLegKind::Partner,
+                &partner.partner,
+                partner_fee,
+            ));
+        }
+    }
+    // Hold back the early-organizer rebate, also out of the platform's share
+    let rebate = get_rebate_program(env).map_or(0, |program| {
+        (platform_fee * rebate_bps(&program, payment_info.created_at) as i128 / 10000)
+            .min(platform_amount)
+    });
+    if rebate > 0 {
+        pay(&env.current_contract_address(), rebate);
+        let balance = get_rebate_balance(env, &payment_info.payment_address, &token_address);
+        set_rebate_balance(
+            env,
+            &payment_info.payment_address,
+            &token_address,
+            balance + rebate,
+        );
+        set_payment_rebate(env, payment_id.clone(), rebate);
+        platform_amount -= rebate;
+        legs.push_back(leg(
+            SettlementLegKind::FeeRebate,
+            &env.current_contract_address(),
+            rebate,
+        ));
+        env.events().publish(
+            with_partner_key(
+                env,
+                &payment_info.partner_key,
+                (
+                    AgoraEvent::FeeRebateAccrued,
+                    payment_info.payment_address.clone(),
+                ),
+            ),
+            FeeRebateAccruedEvent {
+                payment_id: payment_id.clone(),
+                event_id: event_id.clone(),
+                payout_address: payment_info.payment_address.clone(),
+                token_address: token_address.clone(),
+                amount: rebate,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+    if platform_amount > 0 {
+        pay(&platform_wallet, platform_amount);
+        legs.push_back(leg(
+            SettlementLegKind::Platform,
+            &platform_wallet,
+            platform_amount,
+        ));
+    }
+    if rounding_dust > 0 {
+        pay(&env.current_contract_address(), rounding_dust);
+        let dust = get_dust_balance(env, &token_address);
+        set_dust_balance(env, &token_address, dust + rounding_dust);
+        legs.push_back(leg(
+            SettlementLegKind::RoundingDust,
+            &env.current_contract_address(),
+            rounding_dust,
+        ));
+    }
+
+    // Transfer organizer amount, splitting off co-host revenue shares
+    let mut payout_amount = organizer_amount;
+    for cohost in payment_info.cohosts.iter() {
+        let share = (organizer_amount * cohost.share_bps as i128) / 10000;
+        if share > 0 {
+            pay(&cohost.address, share);
+            payout_amount -= share;
+            legs.push_back(leg(SettlementLegKind::CoHost, &cohost.address, share));
+        }
+    }
+    // Withhold tax from the organizer's own slice where the admin requires it
+    let withholding = get_withholding(env, &payment_info.payment_address);
+    let mut withheld = 0;
+    if let Some(config) = &withholding {
+        withheld = (payout_amount * config.bps as i128) / 10000;
+        if withheld > 0 {
+            pay(&config.wallet, withheld);
+            payout_amount -= withheld;
+            legs.push_back(leg(
+                SettlementLegKind::Withholding,
+                &config.wallet,
+                withheld,
+            ));
+        }
+    }
+    if payout_amount > 0 {
+        pay(&payment_info.payment_address, payout_amount);
+        legs.push_back(leg(
+            SettlementLegKind::Organizer,
+            &payment_info.payment_address,
+            payout_amount,
+        ));
+    }
+
+    // 4. Create payment record
+    let payment = Payment {
+        payment_id: payment_id.clone(),
+        event_id: event_id.clone(),
+        buyer_address: buyer_address.clone(),
+        ticket_tier_id,
+        token_address: token_address.clone(),
+        payout_address: payment_info.payment_address.clone(),
+        amount,
+        platform_fee,
+        organizer_amount,
+        status: PaymentStatus::Pending,
+        transaction_hash: String::from_str(env, ""), // Empty until confirmed
+        created_at: env.ledger().timestamp(),
+        confirmed_at: None,
+        attestation_hash,
+        addons,
+        beneficiary_id,
+        metadata_cid: payment_info.metadata_cid,
+        rounding_dust,
+        attribution: attribution.clone(),
+        redacted_at: None,
+    };
+
+    store_payment(env, payment);
+    if answers.is_some() {
+        set_questionnaire_answers(env, payment_id.clone(), answers);
+    }
+
+    let mut totals = get_payment_totals(env);
+    totals.total_payments += 1;
+    totals.total_volume += amount;
+    totals.total_fees_collected += platform_fee;
+    set_payment_totals(env, &totals);
+
+    let day = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let mut daily = get_daily_stats(env, event_id.clone(), day);
+    daily.sales_count += 1;
+    daily.gross += amount;
+    daily.fees += platform_fee;
+    set_daily_stats(env, event_id.clone(), day, &daily);
+
+    let mut sales = get_event_sales(env, event_id.clone());
+    sales.tickets_sold += 1;
+    sales.active_tickets += 1;
+    sales.gross += amount;
+    sales.fees += platform_fee;
+    set_event_sales(env, event_id.clone(), &sales);
+
+    let mut summary = get_buyer_summary(env, &buyer_address);
+    summary.purchase_count += 1;
+    summary.total_spent += amount;
+    summary.active_tickets += 1;
+    set_buyer_summary(env, &buyer_address, &summary);
+
+    // 5. Emit payment event
+    env.events().publish(
+        with_partner_key(
+            env,
+            &payment_info.partner_key,
+            (
+                AgoraEvent::PaymentProcessed,
+                payment_info.payment_address.clone(),
+            ),
+        ),
+        PaymentProcessedEvent {
+            payment_id: payment_id.clone(),
+            event_id: event_id.clone(),
+            buyer_address: buyer_address.clone(),
+            amount,
+            platform_fee,
+            attribution,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    env.events().publish(
+        with_partner_key(
+            env,
+            &payment_info.partner_key,
+            (
+                AgoraEvent::PaymentSettled,
+                payment_info.payment_address.clone(),
+            ),
+        ),
+        PaymentSettledEvent {
+            payment_id: payment_id.clone(),
+            event_id: event_id.clone(),
+            token_address: token_address.clone(),
+            amount,
+            platform_fee,
+            organizer_amount,
+            legs,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    if let Some(config) = withholding.filter(|_| withheld > 0) {
+        env.events().publish(
+            with_partner_key(
+                env,
+                &payment_info.partner_key,
+                (
+                    AgoraEvent::TaxWithheld,
+                    payment_info.payment_address.clone(),
+                ),
+            ),
+            TaxWithheldEvent {
+                payment_id: payment_id.clone(),
+                event_id: event_id.clone(),
+                token_address: token_address.clone(),
+                amount: withheld,
+                wallet: config.wallet,
+                period: day,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+    notify_hooks(
+        env,
+        HookPayload {
+            kind: HookKind::Purchase,
+            payment_id: payment_id.clone(),
+            event_id,
+            buyer_address,
+            amount,
+        },
+    );
+
+    Ok(payment_id)
+}
+
+/// Platform fee on `amount` under `strategy`, rounded down, and whether the
+/// basis-point part left a remainder. The fee never exceeds `amount`.
+pub fn compute_fee(strategy: &FeeStrategy, amount: i128) -> (i128, bool) {
+    let (bps, flat) = match strategy {
+        FeeStrategy::FlatBps(bps) => (*bps, 0),
+        FeeStrategy::Tiered(tiers) => {
+            let band = tiers.iter().filter(|tier| amount >= tier.min_amount).last();
+            (band.map_or(0, |tier| tier.bps), 0)
+        }
+        FeeStrategy::PerTicketFlat(flat) => (0, *flat),
+        FeeStrategy::Hybrid(bps, flat) => (*bps, *flat),
+    };
+    let numerator = amount * bps as i128;
+    let fee = numerator / 10000 + flat;
+    if fee >= amount {
+        return (amount, false);
+    }
+    (fee, numerator % 10000 != 0)
+}
+
+fn resolve_fee_strategy(env: &Env, event_id: String) -> Option<FeeStrategy> {
+    get_event_fee_strategy(env, event_id).or_else(|| get_fee_strategy(env))
+}
+
+/// Strategy a purchase is charged under: the event's own override, then the
+/// platform-wide default unless a tenant or plan fee is in force, then the
+/// registry fee in basis points.
+fn payment_fee_strategy(
+    env: &Env,
+    event_id: String,
+    payment_info: &event_registry::PaymentInfo,
+) -> FeeStrategy {
+    get_event_fee_strategy(env, event_id)
+        .or_else(|| {
+            if payment_info.custom_fee {
+                None
+            } else {
+                get_fee_strategy(env)
+            }
+        })
+        .unwrap_or(FeeStrategy::FlatBps(payment_info.platform_fee_percent))
+}
+
+fn validate_fee_strategy(strategy: &FeeStrategy) -> Result<(), TicketPaymentError> {
+    let valid = match strategy {
+        FeeStrategy::FlatBps(bps) => *bps <= 10000,
+        FeeStrategy::PerTicketFlat(flat) => *flat >= 0,
+        FeeStrategy::Hybrid(bps, flat) => *bps <= 10000 && *flat >= 0,
+        FeeStrategy::Tiered(tiers) => {
+            !tiers.is_empty()
+                && tiers.len() <= MAX_FEE_TIERS
+                && tiers.iter().all(|tier| tier.bps <= 10000)
+                && (1..tiers.len()).all(|i| {
+                    tiers.get_unchecked(i - 1).min_amount < tiers.get_unchecked(i).min_amount
+                })
+        }
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(TicketPaymentError::InvalidFeeStrategy)
+    }
+}
+
+fn build_receipt(env: &Env, payment: Payment) -> PaymentReceipt {
+    PaymentReceipt {
+        issuer: env.current_contract_address(),
+        network_id: env.ledger().network_id(),
+        payment_id: payment.payment_id,
+        event_id: payment.event_id,
+        ticket_tier_id: payment.ticket_tier_id,
+        buyer_address: payment.buyer_address,
+        token_address: payment.token_address,
+        amount: payment.amount,
+        platform_fee: payment.platform_fee,
+        organizer_amount: payment.organizer_amount,
+        created_at: payment.created_at,
+    }
+}
+
+/// Calls every approved hook, ignoring any that fail.
+fn notify_hooks(env: &Env, payload: HookPayload) {
+    for hook_address in get_hooks(env).iter() {
+        let _ = hook::Client::new(env, &hook_address).try_on_ticket_event(&payload);
+    }
+}
+
+/// Storage migrations known to this version, keyed by the schema version
+/// they produce. Add an arm for each release that changes stored layouts.
+fn run_migration(migration_id: u32) -> Result<(), TicketPaymentError> {
+    match migration_id {
+        // Baseline: stamps the schema version without moving data
+        1 => Ok(()),
+        _ => Err(TicketPaymentError::InvalidMigration),
+    }
+}
+
+/// IDs are 1..=MAX_ID_LEN characters of ASCII letters, digits, '-', '_' and
+/// ':' (the registry's tenant separator), so they stay cheap to store and
+/// safe to use as index keys.
+fn validate_identifier(id: &String) -> Result<(), TicketPaymentError> {
+    let len = id.len();
+    if len == 0 || len > MAX_ID_LEN {
+        return Err(TicketPaymentError::InvalidIdentifier);
+    }
+
+    let mut buf = [0u8; MAX_ID_LEN as usize];
+    let buf = &mut buf[..len as usize];
+    id.copy_into_slice(buf);
+    if !buf
+        .iter()
+        .all(|c| c.is_ascii_alphanumeric() || *c == b'-' || *c == b'_' || *c == b':')
+    {
+        return Err(TicketPaymentError::InvalidIdentifier);
+    }
+    Ok(())
+}
+
+/// The single source of refund semantics, shared by `quote_refund` and
+/// `refund_payments`.
+fn refund_quote(payment: &Payment) -> Result<i128, TicketPaymentError> {
+    if !is_active_ticket(payment) {
+        return Err(TicketPaymentError::PaymentNotRefundable);
+    }
+    Ok(payment.amount)
+}
+
+fn is_active_ticket(payment: &Payment) -> bool {
+    !matches!(
+        payment.status,
+        PaymentStatus::Refunded | PaymentStatus::Burned
+    )
+}
+
+fn reserve_accounting(env: &Env, token_address: Address) -> ReserveAccounting {
+    let balance = token::Client::new(env, &token_address).balance(&env.current_contract_address());
+    let partner_fees_owed = get_partner_fees_owed(env, &token_address);
+    let dust_owed = get_dust_balance(env, &token_address);
+    let rebates_owed = get_rebates_owed(env, &token_address);
+    ReserveAccounting {
+        token_address,
+        balance,
+        partner_fees_owed,
+        dust_owed,
+        rebates_owed,
+        surplus: balance - partner_fees_owed - dust_owed - rebates_owed,
+    }
+}
+
+/// Address whose approval changes `buyer`'s spend cap.
+fn spend_cap_authority(buyer: &Address, cap: Option<&SpendCap>) -> Address {
+    cap.and_then(|cap| cap.controller.clone())
+        .unwrap_or_else(|| buyer.clone())
+}
+
+/// Loads the buyer's spend cap, starting a new period if the last one ended.
+fn current_spend_cap(env: &Env, buyer: &Address) -> Option<SpendCap> {
+    let mut cap = get_spend_cap(env, buyer)?;
+    let now = env.ledger().timestamp();
+    if now >= cap.period_start.saturating_add(cap.period_secs) {
+        cap.period_start = now;
+        cap.spent_in_period = 0;
+    }
+    Some(cap)
+}
+
+/// Counts a purchase's settled total against the buyer's standing approval,
+/// starting a new period if the last one has ended.
+fn charge_standing_approval(
+    env: &Env,
+    buyer: &Address,
+    amount: i128,
+) -> Result<(), TicketPaymentError> {
+    let mut approval =
+        get_standing_approval(env, buyer).ok_or(TicketPaymentError::NoStandingApproval)?;
+    let now = env.ledger().timestamp();
+    if now >= approval.period_start.saturating_add(approval.period_secs) {
+        approval.period_start = now;
+        approval.spent_in_period = 0;
+    }
+    let spent = approval
+        .spent_in_period
+        .checked_add(amount)
+        .ok_or(TicketPaymentError::StandingApprovalExceeded)?;
+    if spent > approval.max_per_period {
+        return Err(TicketPaymentError::StandingApprovalExceeded);
+    }
+    approval.spent_in_period = spent;
+    set_standing_approval(env, buyer, &approval);
+    Ok(())
+}
+
+/// Counts `amount` against the buyer's spend cap, if they have one.
+fn charge_spend_cap(env: &Env, buyer: &Address, amount: i128) -> Result<(), TicketPaymentError> {
+    let Some(mut cap) = current_spend_cap(env, buyer) else {
+        return Ok(());
+    };
+    let spent = cap
+        .spent_in_period
+        .checked_add(amount)
+        .ok_or(TicketPaymentError::SpendCapExceeded)?;
+    if spent > cap.max_per_period {
+        return Err(TicketPaymentError::SpendCapExceeded);
+    }
+    cap.spent_in_period = spent;
+    set_spend_cap(env, buyer, Some(cap));
+    Ok(())
+}
+
+/// Takes back the fee rebate a refunded sale accrued, in proportion to the
+/// amount refunded, and returns it to the platform wallet along with the rest
+/// of the fee. Rebates the organizer already claimed are not recovered.
+fn reclaim_rebate(env: &Env, payment: &Payment, refund_amount: i128) {
+    let accrued = get_payment_rebate(env, payment.payment_id.clone());
+    if accrued == 0 {
+        return;
+    }
+    set_payment_rebate(env, payment.payment_id.clone(), 0);
+    let balance = get_rebate_balance(env, &payment.payout_address, &payment.token_address);
+    let debit = (accrued * refund_amount / payment.amount).min(balance);
+    if debit > 0 {
+        set_rebate_balance(
+            env,
+            &payment.payout_address,
+            &payment.token_address,
+            balance - debit,
+        );
+        token::Client::new(env, &payment.token_address).transfer(
+            &env.current_contract_address(),
+            get_platform_wallet(env),
+            &debit,
+        );
+    }
+}
+
+/// Share of the platform fee rebated for an event registered at `created_at`.
+fn rebate_bps(program: &RebateProgram, created_at: u64) -> u32 {
+    if created_at <= program.full_rebate_until {
+        program.max_rebate_bps
+    } else if created_at >= program.ends_at {
+        0
+    } else {
+        let remaining = (program.ends_at - created_at) as u128;
+        let window = (program.ends_at - program.full_rebate_until) as u128;
+        (program.max_rebate_bps as u128 * remaining / window) as u32
+    }
+}
+
+fn require_guardian_or_admin(env: &Env, caller: &Address) -> Result<(), TicketPaymentError> {
+    caller.require_auth();
+    let admin = get_admin(env).expect("Admin not set");
+    if *caller != admin && get_guardian(env).as_ref() != Some(caller) {
+        return Err(TicketPaymentError::NotGuardian);
+    }
+    Ok(())
+}
+
+/// Loads a payment's freeze if it is escalated or hasn't lapsed yet.
+fn active_freeze(env: &Env, payment_id: String) -> Option<PaymentFreeze> {
+    get_payment_freeze(env, payment_id)
+        .filter(|freeze| freeze.escalated || env.ledger().timestamp() < freeze.expires_at)
+}
+
+fn ensure_not_frozen(env: &Env, payment_id: String) -> Result<(), TicketPaymentError> {
+    if active_freeze(env, payment_id).is_some() {
+        return Err(TicketPaymentError::PaymentFrozen);
+    }
+    Ok(())
+}
+
+#[allow(deprecated)]
+fn publish_freeze(env: &Env, payment_id: String, payment: &Payment, freeze: &PaymentFreeze) {
+    env.events().publish(
+        event_topics(
+            env,
+            &payment.event_id,
+            (AgoraEvent::PaymentFrozen, payment.payout_address.clone()),
+        ),
+        PaymentFrozenEvent {
+            payment_id,
+            event_id: payment.event_id.clone(),
+            frozen_by: freeze.frozen_by.clone(),
+            expires_at: freeze.expires_at,
+            escalated: freeze.escalated,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+#[allow(deprecated)]
+fn transfer_claimed_ticket(
+    env: &Env,
+    payment_id: String,
+    new_owner: Address,
+    by_organizer: bool,
+) -> Result<(), TicketPaymentError> {
+    let mut payment =
+        get_payment(env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+    if !is_active_ticket(&payment) {
+        return Err(TicketPaymentError::NotCustodialTicket);
+    }
+    ensure_not_frozen(env, payment_id.clone())?;
+    let previous_owner = payment.buyer_address.clone();
+
+    move_buyer_payment(env, &payment_id, &previous_owner, &new_owner);
+    let mut from_summary = get_buyer_summary(env, &previous_owner);
+    from_summary.active_tickets = from_summary.active_tickets.saturating_sub(1);
+    set_buyer_summary(env, &previous_owner, &from_summary);
+    let mut to_summary = get_buyer_summary(env, &new_owner);
+    to_summary.active_tickets += 1;
+    set_buyer_summary(env, &new_owner, &to_summary);
+
+    payment.buyer_address = new_owner.clone();
+    payment.beneficiary_id = None;
+    save_payment(env, &payment);
+    remove_ticket_claim(env, payment_id.clone());
+    // A redirect signed by the previous owner must not follow the ticket
+    set_refund_redirect(env, payment_id.clone(), None);
+
+    env.events().publish(
+        event_topics(env, &payment.event_id, (AgoraEvent::TicketClaimed,)),
+        TicketClaimedEvent {
+            payment_id,
+            previous_owner,
+            new_owner,
+            by_organizer,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    Ok(())
+}
+
+/// Checks `nonce` against the expected admin nonce and advances it.
+fn consume_admin_nonce(env: &Env, nonce: u64) -> Result<(), TicketPaymentError> {
+    let expected = get_admin_nonce(env);
+    if nonce != expected {
+        return Err(TicketPaymentError::InvalidNonce);
+    }
+    set_admin_nonce(env, expected + 1);
+    Ok(())
+}
+
+/// Why a token that isn't whitelisted is refused. Denied tokens are never
+/// whitelisted, so the happy path needs no denial-list lookup.
+fn token_rejection(env: &Env, token: &Address) -> TicketPaymentError {
+    if is_token_denied(env, token) {
+        TicketPaymentError::TokenDenied
+    } else {
+        TicketPaymentError::TokenNotWhitelisted
+    }
+}
+
+/// Looks up the partner key the organizer set on an event in the registry.
+fn event_partner_key(env: &Env, event_id: &String) -> Option<BytesN<32>> {
+    event_registry::Client::new(env, &get_event_registry(env)).get_event_partner_key(event_id)
+}
+
+/// Appends the event's partner key, if one is set, to `topics`.
+fn event_topics(env: &Env, event_id: &String, topics: impl IntoVal<Env, Vec<Val>>) -> Vec<Val> {
+    with_partner_key(env, &event_partner_key(env, event_id), topics)
+}
+
+fn with_partner_key(
+    env: &Env,
+    partner_key: &Option<BytesN<32>>,
+    topics: impl IntoVal<Env, Vec<Val>>,
+) -> Vec<Val> {
+    let mut topics = topics.into_val(env);
+    if let Some(partner_key) = partner_key {
+        topics.push_back(partner_key.into_val(env));
+    }
+    topics
+}
+
+fn fetch_payment_info(
+    registry_client: &event_registry::Client,
+    event_id: &String,
+) -> Result<event_registry::PaymentInfo, TicketPaymentError> {
+    match registry_client.try_get_event_payment_info(event_id) {
+        Ok(Ok(info)) => Ok(info),
+        Err(Ok(e)) => {
+            // Determine which error was thrown
+            if e.is_type(soroban_sdk::xdr::ScErrorType::Contract) && e.get_code() == 2 {
+                return Err(TicketPaymentError::EventNotFound);
+            } else if e.is_type(soroban_sdk::xdr::ScErrorType::Contract) && e.get_code() == 6 {
+                return Err(TicketPaymentError::EventInactive);
+            }
+            // Fallback for unexpected contract errors
+            Err(TicketPaymentError::EventNotFound)
+        }
+        _ => Err(TicketPaymentError::EventNotFound),
+    }
+}
+
+/// Tenant events may narrow the whitelist to their own accepted tokens.
+fn accepts_token(payment_info: &event_registry::PaymentInfo, token_address: &Address) -> bool {
+    payment_info.accepted_tokens.is_empty() || payment_info.accepted_tokens.contains(token_address)
+}
+
+fn effective_max_ticket_price(env: &Env, event_id: String) -> i128 {
+    get_event_max_ticket_price(env, event_id).unwrap_or_else(|| get_max_ticket_price(env))
+}
+
+/// Takes one ticket of the event's supply in the registry: from the buyer's
+/// checkout hold when they have one, otherwise from unheld inventory, so
+/// sales can't outrun `max_supply` or tickets other buyers hold.
+fn take_inventory(
+    registry_client: &event_registry::Client,
+    event_id: &String,
+    buyer_address: &Address,
+) -> Result<(), TicketPaymentError> {
+    let result = if registry_client
+        .get_inventory_hold(event_id, buyer_address)
+        .is_some()
+    {
+        registry_client.try_consume_inventory_hold(event_id, buyer_address)
+    } else {
+        registry_client.try_increment_inventory(event_id)
+    };
+    match result {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(e)) if e.is_type(soroban_sdk::xdr::ScErrorType::Contract) => match e.get_code() {
+            6 => Err(TicketPaymentError::EventInactive),
+            10 => Err(TicketPaymentError::TicketsSoldOut),
+            _ => Err(TicketPaymentError::EventNotFound),
+        },
+        _ => Err(TicketPaymentError::EventNotFound),
+    }
+}
+
+/// Returns `quantity` burned or refunded tickets to the event's supply in the
+/// registry so they can be sold again.
+fn release_inventory(
+    env: &Env,
+    event_id: &String,
+    quantity: u32,
+) -> Result<(), TicketPaymentError> {
+    match event_registry::Client::new(env, &get_event_registry(env))
+        .try_release_inventory(event_id, &quantity)
+    {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(TicketPaymentError::EventNotFound),
+    }
+}
+
+fn reserve_addons(
+    registry_client: &event_registry::Client,
+    event_id: &String,
+    selections: &Vec<AddonSelection>,
+) -> Result<Vec<AddonLineItem>, TicketPaymentError> {
+    match registry_client.try_reserve_addons(event_id, selections) {
+        Ok(Ok(line_items)) => Ok(line_items),
+        Err(Ok(e)) if e.is_type(soroban_sdk::xdr::ScErrorType::Contract) => match e.get_code() {
+            6 => Err(TicketPaymentError::EventInactive),
+            13 => Err(TicketPaymentError::AddonNotFound),
+            14 => Err(TicketPaymentError::AddonSoldOut),
+            _ => Err(TicketPaymentError::InvalidAddon),
+        },
+        _ => Err(TicketPaymentError::InvalidAddon),
+    }
+}
+
+fn verify_attestation(
+    env: &Env,
+    buyer_address: &Address,
+    event_id: &String,
+    attestation: &PurchaseAttestation,
+) -> Result<BytesN<32>, TicketPaymentError> {
+    let attestor = get_attestor_key(env).ok_or(TicketPaymentError::AttestorNotSet)?;
+
+    if attestation.expires_at <= env.ledger().timestamp() {
+        return Err(TicketPaymentError::AttestationExpired);
+    }
+
+    let payload = AttestationPayload {
+        buyer_address: buyer_address.clone(),
+        event_id: event_id.clone(),
+        expires_at: attestation.expires_at,
+    };
+    // Traps the invocation if the signature does not match
+    env.crypto()
+        .ed25519_verify(&attestor, &payload.to_xdr(env), &attestation.signature);
+
+    Ok(env
+        .crypto()
+        .sha256(&attestation.clone().to_xdr(env))
+        .to_bytes())
+}
+
+/// Checks a price quote's signature and expiry and marks it redeemed.
+fn verify_quote(
+    env: &Env,
+    buyer_address: &Address,
+    event_id: &String,
+    ticket_tier_id: &String,
+    token_address: &Address,
+    quote: &PriceQuote,
+) -> Result<(), TicketPaymentError> {
+    let quote_key = get_quote_key(env).ok_or(TicketPaymentError::QuoteKeyNotSet)?;
+
+    let now = env.ledger().timestamp();
+    if quote.expires_at <= now || quote.expires_at > now + MAX_QUOTE_SECS {
+        return Err(TicketPaymentError::InvalidQuoteExpiry);
+    }
+    let quote_hash: BytesN<32> = env.crypto().sha256(&quote.clone().to_xdr(env)).to_bytes();
+    if is_quote_used(env, &quote_hash) {
+        return Err(TicketPaymentError::QuoteAlreadyUsed);
+    }
+
+    let payload = QuotePayload {
+        buyer_address: buyer_address.clone(),
+        event_id: event_id.clone(),
+        ticket_tier_id: ticket_tier_id.clone(),
+        token_address: token_address.clone(),
+        price: quote.price,
+        expires_at: quote.expires_at,
+    };
+    // Traps the invocation if the signature does not match
+    env.crypto()
+        .ed25519_verify(&quote_key, &payload.to_xdr(env), &quote.signature);
+
+    // Outlive the quote so it cannot be replayed before it expires
+    let ttl_ledgers = ((quote.expires_at - now) / LEDGER_CLOSE_SECS + 1) as u32;
+    mark_quote_used(env, &quote_hash, ttl_ledgers);
+    Ok(())
 }
 
 fn validate_address(env: &Env, address: &Address) -> Result<(), TicketPaymentError> {
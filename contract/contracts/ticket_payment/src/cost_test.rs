@@ -0,0 +1,76 @@
+//! Resource budget regression tests for the payment hot paths.
+//!
+//! Each test pins the CPU instruction and memory cost of one invocation and
+//! fails when a change pushes it more than `TOLERANCE_PCT` above the baseline.
+//! When a cost rises on purpose, re-measure and update the baseline in the
+//! same change so the increase is reviewed.
+
+use crate::test::setup_test;
+use crate::types::RefundReason;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+const TOLERANCE_PCT: u64 = 20;
+
+fn assert_within_budget(env: &Env, name: &str, cpu_baseline: u64, mem_baseline: u64) {
+    let budget = env.cost_estimate().budget();
+    let cpu = budget.cpu_instruction_cost();
+    let mem = budget.memory_bytes_cost();
+    assert!(
+        cpu <= cpu_baseline * (100 + TOLERANCE_PCT) / 100,
+        "{name} CPU cost {cpu} regressed past baseline {cpu_baseline}"
+    );
+    assert!(
+        mem <= mem_baseline * (100 + TOLERANCE_PCT) / 100,
+        "{name} memory cost {mem} regressed past baseline {mem_baseline}"
+    );
+}
+
+#[test]
+fn test_process_payment_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+
+    client.process_payment(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &u64::MAX,
+    );
+    assert_within_budget(&env, "process_payment", 1_350_000, 500_000);
+}
+
+#[test]
+fn test_refund_payments_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+    let payment_id = String::from_str(&env, "p1");
+    client.process_payment(
+        &payment_id,
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &u64::MAX,
+    );
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&payment.payout_address, &100i128);
+
+    client.refund_payments(
+        &payment.event_id,
+        &vec![&env, payment_id],
+        &RefundReason::Goodwill,
+    );
+    assert_within_budget(&env, "refund_payments", 870_000, 315_000);
+}
@@ -10,6 +10,61 @@ pub enum TicketPaymentError {
     EventNotFound = 4,
     EventInactive = 5,
     TokenNotWhitelisted = 6,
+    AttestationRequired = 7,
+    AttestationExpired = 8,
+    AttestorNotSet = 9,
+    InvalidTimeRange = 10,
+    AddonNotFound = 11,
+    AddonSoldOut = 12,
+    InvalidAddon = 13,
+    NoStandingApproval = 14,
+    StandingApprovalExceeded = 15,
+    InvalidStandingApproval = 16,
+    PaymentNotFound = 17,
+    PaymentNotRefundable = 18,
+    BatchTooLarge = 19,
+    PriceExceedsMaximum = 20,
+    InvalidPriceLimit = 21,
+    NotCustodian = 22,
+    NotCustodialTicket = 23,
+    ClaimNotFound = 24,
+    ClaimExpired = 25,
+    ClaimNotExpired = 26,
+    InvalidClaimCode = 27,
+    InvalidAmount = 28,
+    InsufficientBalance = 29,
+    InvalidPartnerShare = 30,
+    NothingToWithdraw = 31,
+    TicketNotActive = 32,
+    InvalidIdentifier = 33,
+    PriceBelowMinimum = 34,
+    PurchaseExceedsMaximum = 35,
+    MigrationPending = 36,
+    InvalidMigration = 37,
+    TooManyHooks = 38,
+    InvalidWithholding = 39,
+    InvalidRefundAddress = 40,
+    InvalidFeeStrategy = 41,
+    AlreadyRedacted = 42,
+    QuoteKeyNotSet = 43,
+    InvalidQuoteExpiry = 44,
+    QuoteAlreadyUsed = 45,
+    NotGuardian = 46,
+    ContractPaused = 47,
+    PaymentFrozen = 48,
+    PaymentNotFrozen = 49,
+    PurchaseExpired = 50,
+    InvalidPromoCampaign = 51,
+    InvalidPromoCode = 52,
+    InvalidSpendCap = 53,
+    SpendCapExceeded = 54,
+    QuestionnaireRequired = 55,
+    InvalidQuestionnaire = 56,
+    InvalidRebateProgram = 57,
+    TokenDenied = 58,
+    InvalidNonce = 59,
+    DuplicatePurchaseExtra = 60,
+    TicketsSoldOut = 61,
 }
 
 impl core::fmt::Display for TicketPaymentError {
@@ -23,6 +78,121 @@ impl core::fmt::Display for TicketPaymentError {
             TicketPaymentError::EventNotFound => write!(f, "Event not found in registry"),
             TicketPaymentError::EventInactive => write!(f, "Event is inactive"),
             TicketPaymentError::TokenNotWhitelisted => write!(f, "Token not whitelisted"),
+            TicketPaymentError::AttestationRequired => {
+                write!(f, "Event requires an eligibility attestation")
+            }
+            TicketPaymentError::AttestationExpired => write!(f, "Attestation has expired"),
+            TicketPaymentError::AttestorNotSet => write!(f, "Attestor key not configured"),
+            TicketPaymentError::InvalidTimeRange => write!(f, "Invalid payment time range"),
+            TicketPaymentError::AddonNotFound => write!(f, "Add-on not found for event"),
+            TicketPaymentError::AddonSoldOut => write!(f, "Add-on is sold out"),
+            TicketPaymentError::InvalidAddon => write!(f, "Invalid add-on selection"),
+            TicketPaymentError::NoStandingApproval => write!(f, "No standing approval for buyer"),
+            TicketPaymentError::StandingApprovalExceeded => {
+                write!(f, "Purchase exceeds standing approval for this period")
+            }
+            TicketPaymentError::InvalidStandingApproval => {
+                write!(f, "Standing approval limit and period must be positive")
+            }
+            TicketPaymentError::PaymentNotFound => write!(f, "Payment not found"),
+            TicketPaymentError::PaymentNotRefundable => {
+                write!(f, "Payment cannot be refunded in this batch")
+            }
+            TicketPaymentError::BatchTooLarge => write!(f, "Too many items in one batch"),
+            TicketPaymentError::PriceExceedsMaximum => {
+                write!(f, "Ticket price exceeds the configured maximum")
+            }
+            TicketPaymentError::InvalidPriceLimit => write!(f, "Price limit must not be negative"),
+            TicketPaymentError::NotCustodian => write!(f, "Address is not a registered custodian"),
+            TicketPaymentError::NotCustodialTicket => {
+                write!(f, "Ticket was not purchased by a custodian")
+            }
+            TicketPaymentError::ClaimNotFound => write!(f, "No pending claim for this ticket"),
+            TicketPaymentError::ClaimExpired => write!(f, "Ticket claim has expired"),
+            TicketPaymentError::ClaimNotExpired => write!(f, "Ticket claim has not expired yet"),
+            TicketPaymentError::InvalidClaimCode => write!(f, "Invalid claim code"),
+            TicketPaymentError::InvalidAmount => write!(f, "Amount must be positive"),
+            TicketPaymentError::InsufficientBalance => {
+                write!(f, "Buyer balance is too low for this purchase")
+            }
+            TicketPaymentError::InvalidPartnerShare => {
+                write!(f, "Partner share must be between 1 and 10000 bps")
+            }
+            TicketPaymentError::NothingToWithdraw => write!(f, "No balance to withdraw"),
+            TicketPaymentError::TicketNotActive => {
+                write!(f, "Ticket has already been refunded or burned")
+            }
+            TicketPaymentError::InvalidIdentifier => {
+                write!(f, "ID is empty, too long or has invalid characters")
+            }
+            TicketPaymentError::PriceBelowMinimum => {
+                write!(f, "Ticket price is below the platform minimum")
+            }
+            TicketPaymentError::PurchaseExceedsMaximum => {
+                write!(f, "Purchase total exceeds the platform maximum")
+            }
+            TicketPaymentError::MigrationPending => {
+                write!(f, "Contract is waiting for a post-upgrade migration")
+            }
+            TicketPaymentError::InvalidMigration => {
+                write!(f, "Migration is not pending or not known to this version")
+            }
+            TicketPaymentError::TooManyHooks => write!(f, "Hook limit reached"),
+            TicketPaymentError::InvalidWithholding => {
+                write!(f, "Withholding must be between 1 and 10000 bps")
+            }
+            TicketPaymentError::InvalidRefundAddress => {
+                write!(f, "Refund address must differ from the buyer")
+            }
+            TicketPaymentError::InvalidFeeStrategy => {
+                write!(f, "Fee strategy has invalid rates or unordered tiers")
+            }
+            TicketPaymentError::AlreadyRedacted => {
+                write!(f, "Payment has already been redacted")
+            }
+            TicketPaymentError::QuoteKeyNotSet => write!(f, "No price quote key configured"),
+            TicketPaymentError::InvalidQuoteExpiry => {
+                write!(f, "Price quote has expired or expires too far ahead")
+            }
+            TicketPaymentError::QuoteAlreadyUsed => {
+                write!(f, "Price quote has already been redeemed")
+            }
+            TicketPaymentError::NotGuardian => {
+                write!(f, "Caller is neither the guardian nor the admin")
+            }
+            TicketPaymentError::ContractPaused => write!(f, "Contract is paused"),
+            TicketPaymentError::PaymentFrozen => write!(f, "Payment is frozen"),
+            TicketPaymentError::PaymentNotFrozen => write!(f, "Payment is not frozen"),
+            TicketPaymentError::PurchaseExpired => {
+                write!(f, "Purchase authorization has expired")
+            }
+            TicketPaymentError::InvalidPromoCampaign => {
+                write!(f, "Promo campaign discount, event or codes are invalid")
+            }
+            TicketPaymentError::InvalidPromoCode => {
+                write!(f, "Promo code is unknown, used or for another event")
+            }
+            TicketPaymentError::InvalidSpendCap => {
+                write!(f, "Spend cap amount and period must be positive")
+            }
+            TicketPaymentError::SpendCapExceeded => {
+                write!(f, "Purchase would exceed the buyer's spend cap")
+            }
+            TicketPaymentError::QuestionnaireRequired => {
+                write!(f, "Event requires questionnaire answers with the purchase")
+            }
+            TicketPaymentError::InvalidQuestionnaire => {
+                write!(f, "Questionnaire or answers CID is invalid")
+            }
+            TicketPaymentError::InvalidRebateProgram => {
+                write!(f, "Rebate share or program window is invalid")
+            }
+            TicketPaymentError::TokenDenied => write!(f, "Token is on the denial-list"),
+            TicketPaymentError::InvalidNonce => write!(f, "Admin nonce is stale or out of order"),
+            TicketPaymentError::DuplicatePurchaseExtra => {
+                write!(f, "Purchase extra supplied more than once")
+            }
+            TicketPaymentError::TicketsSoldOut => write!(f, "Event has no tickets left"),
         }
     }
 }
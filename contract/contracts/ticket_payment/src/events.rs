@@ -1,6 +1,8 @@
-use crate::types::PaymentStatus;
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use crate::types::{PaymentStatus, RefundReason};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
+/// Events that affect an organizer carry the event's payout address as the second topic,
+/// so organizer notification services can subscribe with one topic filter.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgoraEvent {
@@ -8,6 +10,19 @@ pub enum AgoraEvent {
     PaymentStatusChanged,
     ContractInitialized,
     ContractUpgraded,
+    PaymentRefunded,
+    TicketClaimed,
+    PartnerFeesWithdrawn,
+    TicketBurned,
+    TaxWithheld,
+    RefundRedirected,
+    PaymentRedacted,
+    PaymentSettled,
+    PauseChanged,
+    PaymentFrozen,
+    PaymentUnfrozen,
+    FeeRebateAccrued,
+    FeeRebateClaimed,
 }
 
 #[contracttype]
@@ -18,6 +33,8 @@ pub struct PaymentProcessedEvent {
     pub buyer_address: Address,
     pub amount: i128,
     pub platform_fee: i128,
+    /// Hashed marketing campaign ID, for per-channel sales reports
+    pub attribution: Option<BytesN<32>>,
     pub timestamp: u64,
 }
 
@@ -45,3 +62,165 @@ pub struct ContractUpgraded {
     pub old_wasm_hash: BytesN<32>,
     pub new_wasm_hash: BytesN<32>,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentRefundedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub buyer_address: Address,
+    /// Address the refund was paid to; differs from the buyer after a redirect
+    pub recipient: Address,
+    pub amount: i128,
+    pub reason: RefundReason,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketClaimedEvent {
+    pub payment_id: String,
+    pub previous_owner: Address,
+    pub new_owner: Address,
+    pub by_organizer: bool,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartnerFeesWithdrawnEvent {
+    pub partner: Address,
+    pub token_address: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketBurnedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub holder: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaxWithheldEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub token_address: Address,
+    pub amount: i128,
+    pub wallet: Address,
+    /// Day bucket (timestamp / 86400) the withholding is reported under
+    pub period: u64,
+    pub timestamp: u64,
+}
+
+/// Who a settlement leg paid.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SettlementLegKind {
+    Platform,
+    /// White-label partner's fee share, held by this contract until withdrawn
+    Partner,
+    /// Fee rounding remainder, held by this contract in the dust bucket
+    RoundingDust,
+    /// Early-organizer rebate out of the platform fee, held until claimed
+    FeeRebate,
+    CoHost,
+    Withholding,
+    Organizer,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementLeg {
+    pub kind: SettlementLegKind,
+    pub recipient: Address,
+    /// Token base units transferred to `recipient`
+    pub amount: i128,
+}
+
+/// Every transfer of one purchase in a single payload; the legs sum to `amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentSettledEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    /// Settlement currency; all amounts are in its base units
+    pub token_address: Address,
+    pub amount: i128,
+    pub platform_fee: i128,
+    pub organizer_amount: i128,
+    pub legs: Vec<SettlementLeg>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundRedirectedEvent {
+    pub payment_id: String,
+    pub buyer_address: Address,
+    /// New refund address, or `None` when the redirect was cancelled
+    pub to: Option<Address>,
+    pub effective_at: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentRedactedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseChangedEvent {
+    pub paused: bool,
+    /// Guardian or admin who changed it
+    pub by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentFrozenEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub frozen_by: Address,
+    pub expires_at: u64,
+    /// True when the admin escalated an existing freeze
+    pub escalated: bool,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentUnfrozenEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRebateAccruedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub payout_address: Address,
+    pub token_address: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRebateClaimedEvent {
+    pub payout_address: Address,
+    pub token_address: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
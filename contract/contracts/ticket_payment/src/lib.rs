@@ -7,3 +7,15 @@ pub mod types;
 
 #[cfg(test)]
 mod test;
+
+#[cfg(test)]
+mod chaos_test;
+
+#[cfg(test)]
+mod cost_test;
+
+#[cfg(test)]
+mod storage_bench;
+
+#[cfg(test)]
+mod spec_test;
@@ -0,0 +1,184 @@
+//! Checks the exported contract spec that TypeScript and Rust bindings are
+//! generated from: every public entrypoint must be in it, with its docs.
+
+extern crate std;
+
+use crate::contract::TicketPaymentContract;
+use crate::types::Payment;
+use soroban_sdk::xdr::{Limits, ReadXdr, ScSpecEntry, ScSpecFunctionV0, ScSpecTypeDef};
+use std::{string::ToString, vec::Vec};
+
+fn decode(spec_xdr: &[u8]) -> ScSpecEntry {
+    ScSpecEntry::from_xdr(spec_xdr, Limits::none()).unwrap()
+}
+
+macro_rules! decode_all {
+    ($($spec:expr),* $(,)?) => {
+        [$(decode(&$spec)),*]
+    };
+}
+
+fn exported_functions() -> Vec<ScSpecFunctionV0> {
+    decode_all![
+        TicketPaymentContract::spec_xdr_initialize(),
+        TicketPaymentContract::spec_xdr_upgrade(),
+        TicketPaymentContract::spec_xdr_upgrade_with_migration(),
+        TicketPaymentContract::spec_xdr_get_admin_nonce(),
+        TicketPaymentContract::spec_xdr_on_upgrade(),
+        TicketPaymentContract::spec_xdr_get_pending_actions(),
+        TicketPaymentContract::spec_xdr_get_migration_state(),
+        TicketPaymentContract::spec_xdr_add_token(),
+        TicketPaymentContract::spec_xdr_remove_token(),
+        TicketPaymentContract::spec_xdr_is_token_allowed(),
+        TicketPaymentContract::spec_xdr_deny_token(),
+        TicketPaymentContract::spec_xdr_remove_denied_token(),
+        TicketPaymentContract::spec_xdr_is_token_denied(),
+        TicketPaymentContract::spec_xdr_get_build_info(),
+        TicketPaymentContract::spec_xdr_get_config(),
+        TicketPaymentContract::spec_xdr_process_payment(),
+        TicketPaymentContract::spec_xdr_process_payment_with_options(),
+        TicketPaymentContract::spec_xdr_create_promo_codes(),
+        TicketPaymentContract::spec_xdr_get_campaign_stats(),
+        TicketPaymentContract::spec_xdr_set_event_questionnaire(),
+        TicketPaymentContract::spec_xdr_get_event_questionnaire(),
+        TicketPaymentContract::spec_xdr_get_questionnaire_answers(),
+        TicketPaymentContract::spec_xdr_verify_questionnaire_answers(),
+        TicketPaymentContract::spec_xdr_grant_standing_approval(),
+        TicketPaymentContract::spec_xdr_revoke_standing_approval(),
+        TicketPaymentContract::spec_xdr_get_standing_approval(),
+        TicketPaymentContract::spec_xdr_set_spend_cap(),
+        TicketPaymentContract::spec_xdr_remove_spend_cap(),
+        TicketPaymentContract::spec_xdr_get_spend_cap(),
+        TicketPaymentContract::spec_xdr_process_approved_payment(),
+        TicketPaymentContract::spec_xdr_set_custodian(),
+        TicketPaymentContract::spec_xdr_is_custodian(),
+        TicketPaymentContract::spec_xdr_register_ticket_claim(),
+        TicketPaymentContract::spec_xdr_claim_ticket(),
+        TicketPaymentContract::spec_xdr_reassign_unclaimed_ticket(),
+        TicketPaymentContract::spec_xdr_get_ticket_claim(),
+        TicketPaymentContract::spec_xdr_set_event_partner(),
+        TicketPaymentContract::spec_xdr_get_event_partner(),
+        TicketPaymentContract::spec_xdr_set_withholding(),
+        TicketPaymentContract::spec_xdr_get_withholding(),
+        TicketPaymentContract::spec_xdr_get_partner_balance(),
+        TicketPaymentContract::spec_xdr_get_reserve_accounting(),
+        TicketPaymentContract::spec_xdr_set_rebate_program(),
+        TicketPaymentContract::spec_xdr_get_rebate_program(),
+        TicketPaymentContract::spec_xdr_get_rebate_balance(),
+        TicketPaymentContract::spec_xdr_claim_fee_rebate(),
+        TicketPaymentContract::spec_xdr_check_invariants(),
+        TicketPaymentContract::spec_xdr_set_rounding_beneficiary(),
+        TicketPaymentContract::spec_xdr_get_rounding_beneficiary(),
+        TicketPaymentContract::spec_xdr_get_dust_balance(),
+        TicketPaymentContract::spec_xdr_sweep_dust(),
+        TicketPaymentContract::spec_xdr_withdraw_partner_fees(),
+        TicketPaymentContract::spec_xdr_add_hook(),
+        TicketPaymentContract::spec_xdr_remove_hook(),
+        TicketPaymentContract::spec_xdr_get_hooks(),
+        TicketPaymentContract::spec_xdr_set_max_ticket_price(),
+        TicketPaymentContract::spec_xdr_set_fee_strategy(),
+        TicketPaymentContract::spec_xdr_set_event_fee_strategy(),
+        TicketPaymentContract::spec_xdr_get_fee_strategy(),
+        TicketPaymentContract::spec_xdr_set_event_max_ticket_price(),
+        TicketPaymentContract::spec_xdr_get_max_ticket_price(),
+        TicketPaymentContract::spec_xdr_set_purchase_limits(),
+        TicketPaymentContract::spec_xdr_get_purchase_limits(),
+        TicketPaymentContract::spec_xdr_set_attestor(),
+        TicketPaymentContract::spec_xdr_get_attestor(),
+        TicketPaymentContract::spec_xdr_set_quote_key(),
+        TicketPaymentContract::spec_xdr_get_quote_key(),
+        TicketPaymentContract::spec_xdr_set_guardian(),
+        TicketPaymentContract::spec_xdr_get_guardian(),
+        TicketPaymentContract::spec_xdr_pause(),
+        TicketPaymentContract::spec_xdr_unpause(),
+        TicketPaymentContract::spec_xdr_is_paused(),
+        TicketPaymentContract::spec_xdr_freeze_payment(),
+        TicketPaymentContract::spec_xdr_escalate_freeze(),
+        TicketPaymentContract::spec_xdr_unfreeze_payment(),
+        TicketPaymentContract::spec_xdr_get_payment_freeze(),
+        TicketPaymentContract::spec_xdr_set_event_restricted(),
+        TicketPaymentContract::spec_xdr_is_event_restricted(),
+        TicketPaymentContract::spec_xdr_confirm_payment(),
+        TicketPaymentContract::spec_xdr_refund_payments(),
+        TicketPaymentContract::spec_xdr_set_refund_address(),
+        TicketPaymentContract::spec_xdr_redact_payment(),
+        TicketPaymentContract::spec_xdr_get_refund_address(),
+        TicketPaymentContract::spec_xdr_quote_refund(),
+        TicketPaymentContract::spec_xdr_burn_ticket(),
+        TicketPaymentContract::spec_xdr_validate_purchase(),
+        TicketPaymentContract::spec_xdr_validate_purchase_detailed(),
+        TicketPaymentContract::spec_xdr_preflight_purchase(),
+        TicketPaymentContract::spec_xdr_get_rejection_stats(),
+        TicketPaymentContract::spec_xdr_get_wallet_view(),
+        TicketPaymentContract::spec_xdr_get_receipt(),
+        TicketPaymentContract::spec_xdr_verify_receipt(),
+        TicketPaymentContract::spec_xdr_get_ticket_metadata(),
+        TicketPaymentContract::spec_xdr_get_buyer_summary(),
+        TicketPaymentContract::spec_xdr_get_event_sales(),
+        TicketPaymentContract::spec_xdr_get_daily_stats(),
+        TicketPaymentContract::spec_xdr_get_refund_stats(),
+        TicketPaymentContract::spec_xdr_get_payment_status(),
+        TicketPaymentContract::spec_xdr_get_platform_stats(),
+        TicketPaymentContract::spec_xdr_get_payments_in_range(),
+    ]
+    .into_iter()
+    .map(|entry| match entry {
+        ScSpecEntry::FunctionV0(function) => function,
+        other => panic!("unexpected spec entry {other:?}"),
+    })
+    .collect()
+}
+
+/// Public entrypoint names declared in the `#[contractimpl]` block.
+fn source_functions() -> Vec<&'static str> {
+    let source = include_str!("contract.rs");
+    let start = source.find("#[contractimpl]").unwrap();
+    let end = start + source[start..].find("\n}\n").unwrap();
+    source[start..end]
+        .lines()
+        .filter_map(|line| line.strip_prefix("    pub fn "))
+        .map(|rest| rest.split(['(', '<']).next().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_spec_exports_every_public_function() {
+    let mut exported: Vec<_> = exported_functions()
+        .iter()
+        .map(|function| function.name.to_string())
+        .collect();
+    let mut declared: Vec<_> = source_functions()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    exported.sort();
+    declared.sort();
+    assert_eq!(exported, declared);
+}
+
+#[test]
+fn test_spec_documents_every_function() {
+    for function in exported_functions() {
+        assert!(
+            !function.doc.is_empty(),
+            "{} has no doc comment in the contract spec",
+            *function.name
+        );
+    }
+}
+
+#[test]
+fn test_spec_documents_payment_amounts_and_timestamps() {
+    let ScSpecEntry::UdtStructV0(spec) = decode(&Payment::spec_xdr()) else {
+        panic!("Payment is not exported as a struct");
+    };
+    for field in spec.fields.iter() {
+        if matches!(field.type_, ScSpecTypeDef::I128 | ScSpecTypeDef::U64) {
+            assert!(
+                !field.doc.is_empty(),
+                "Payment.{} has no doc comment in the contract spec",
+                field.name
+            );
+        }
+    }
+}
@@ -1,5 +1,10 @@
-use crate::types::{DataKey, Payment, PaymentStatus};
-use soroban_sdk::{vec, Address, Env, String, Vec};
+use crate::types::{
+    BuyerSummary, DailyStats, DataKey, EventSales, FeeStrategy, PartnerConfig, Payment,
+    PaymentFreeze, PaymentStatus, PaymentTotals, PromoCampaign, PurchaseLimits,
+    QuestionnaireAnswers, RebateProgram, RefundReason, RefundReasonStats, RefundRedirect,
+    RoundingBeneficiary, SpendCap, StandingApproval, TicketClaim, WithholdingConfig,
+};
+use soroban_sdk::{vec, Address, BytesN, Env, Map, String, Vec};
 
 pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().persistent().set(&DataKey::Admin, admin);
@@ -9,9 +14,20 @@ pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().persistent().get(&DataKey::Admin)
 }
 
-pub fn store_payment(env: &Env, payment: Payment) {
+pub const SECONDS_PER_DAY: u64 = 86_400;
+/// Rejection counters expire about 30 days (5s ledgers) after the last rejection.
+pub const REJECTION_STATS_TTL_LEDGERS: u32 = 518_400;
+/// A buyer's preflight rejections count once per event per day (5s ledgers).
+pub const PREFLIGHT_DEDUPE_TTL_LEDGERS: u32 = 17_280;
+
+/// Persists the payment record only, without touching any index.
+pub fn save_payment(env: &Env, payment: &Payment) {
     let key = DataKey::Payment(payment.payment_id.clone());
-    env.storage().persistent().set(&key, &payment);
+    env.storage().persistent().set(&key, payment);
+}
+
+pub fn store_payment(env: &Env, payment: Payment) {
+    save_payment(env, &payment);
 
     // Index by event
     let event_key = DataKey::EventPayments(payment.event_id.clone());
@@ -32,6 +48,17 @@ pub fn store_payment(env: &Env, payment: Payment) {
         .unwrap_or(vec![env]);
     buyer_payments.push_back(payment.payment_id.clone());
     env.storage().persistent().set(&buyer_key, &buyer_payments);
+
+    // Index by event and creation day
+    let day = payment.created_at / SECONDS_PER_DAY;
+    let day_key = DataKey::EventPaymentsByDay(payment.event_id.clone(), day);
+    let mut day_payments: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&day_key)
+        .unwrap_or(vec![env]);
+    day_payments.push_back(payment.payment_id.clone());
+    env.storage().persistent().set(&day_key, &day_payments);
 }
 
 pub fn get_payment(env: &Env, payment_id: String) -> Option<Payment> {
@@ -45,14 +72,28 @@ pub fn update_payment_status(
     status: PaymentStatus,
     confirmed_at: Option<u64>,
 ) {
-    if let Some(mut payment) = get_payment(env, payment_id.clone()) {
+    if let Some(mut payment) = get_payment(env, payment_id) {
         payment.status = status;
         payment.confirmed_at = confirmed_at;
-        let key = DataKey::Payment(payment_id);
-        env.storage().persistent().set(&key, &payment);
+        save_payment(env, &payment);
     }
 }
 
+/// Moves a payment from one buyer's index to another's.
+pub fn move_buyer_payment(env: &Env, payment_id: &String, from: &Address, to: &Address) {
+    let from_key = DataKey::BuyerPayments(from.clone());
+    let mut from_payments = get_buyer_payments(env, from.clone());
+    if let Some(index) = from_payments.first_index_of(payment_id) {
+        from_payments.remove(index);
+    }
+    env.storage().persistent().set(&from_key, &from_payments);
+
+    let to_key = DataKey::BuyerPayments(to.clone());
+    let mut to_payments = get_buyer_payments(env, to.clone());
+    to_payments.push_back(payment_id.clone());
+    env.storage().persistent().set(&to_key, &to_payments);
+}
+
 pub fn get_event_payments(env: &Env, event_id: String) -> Vec<String> {
     let key = DataKey::EventPayments(event_id);
     env.storage().persistent().get(&key).unwrap_or(vec![env])
@@ -63,6 +104,11 @@ pub fn get_buyer_payments(env: &Env, buyer_address: Address) -> Vec<String> {
     env.storage().persistent().get(&key).unwrap_or(vec![env])
 }
 
+pub fn get_event_payments_by_day(env: &Env, event_id: String, day: u64) -> Vec<String> {
+    let key = DataKey::EventPaymentsByDay(event_id, day);
+    env.storage().persistent().get(&key).unwrap_or(vec![env])
+}
+
 // Configuration getters/setters
 pub fn set_usdc_token(env: &Env, address: Address) {
     env.storage()
@@ -134,3 +180,643 @@ pub fn is_token_whitelisted(env: &Env, token: &Address) -> bool {
         .get(&DataKey::TokenWhitelist(token.clone()))
         .unwrap_or(false)
 }
+
+pub fn set_token_denied(env: &Env, token: &Address, denied: bool) {
+    let key = DataKey::DeniedToken(token.clone());
+    if denied {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_token_denied(env: &Env, token: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::DeniedToken(token.clone()))
+}
+
+pub fn set_attestor_key(env: &Env, attestor: &BytesN<32>) {
+    env.storage().persistent().set(&DataKey::Attestor, attestor);
+}
+
+pub fn get_attestor_key(env: &Env) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&DataKey::Attestor)
+}
+
+pub fn set_quote_key(env: &Env, quote_key: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::QuoteKey, quote_key);
+}
+
+pub fn get_quote_key(env: &Env) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&DataKey::QuoteKey)
+}
+
+pub fn set_guardian(env: &Env, guardian: Option<Address>) {
+    match guardian {
+        Some(guardian) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::Guardian, &guardian),
+        None => env.storage().persistent().remove(&DataKey::Guardian),
+    }
+}
+
+pub fn get_guardian(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::Guardian)
+}
+
+pub fn get_admin_nonce(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AdminNonce)
+        .unwrap_or(0)
+}
+
+pub fn set_admin_nonce(env: &Env, nonce: u64) {
+    env.storage().persistent().set(&DataKey::AdminNonce, &nonce);
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    if paused {
+        env.storage().persistent().set(&DataKey::Paused, &true);
+    } else {
+        env.storage().persistent().remove(&DataKey::Paused);
+    }
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().persistent().has(&DataKey::Paused)
+}
+
+pub fn set_payment_freeze(env: &Env, payment_id: String, freeze: Option<PaymentFreeze>) {
+    let key = DataKey::PaymentFreeze(payment_id);
+    match freeze {
+        Some(freeze) => env.storage().persistent().set(&key, &freeze),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_payment_freeze(env: &Env, payment_id: String) -> Option<PaymentFreeze> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PaymentFreeze(payment_id))
+}
+
+pub fn is_quote_used(env: &Env, quote_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .temporary()
+        .has(&DataKey::UsedQuote(quote_hash.clone()))
+}
+
+/// Marks a quote as redeemed for at least `ttl_ledgers`, which must outlast
+/// the quote's expiry.
+pub fn mark_quote_used(env: &Env, quote_hash: &BytesN<32>, ttl_ledgers: u32) {
+    let key = DataKey::UsedQuote(quote_hash.clone());
+    env.storage().temporary().set(&key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, ttl_ledgers, ttl_ledgers);
+}
+
+pub fn set_restricted_event(env: &Env, event_id: String, restricted: bool) {
+    let key = DataKey::RestrictedEvent(event_id);
+    if restricted {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_restricted_event(env: &Env, event_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RestrictedEvent(event_id))
+        .unwrap_or(false)
+}
+
+pub fn get_payment_totals(env: &Env) -> PaymentTotals {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PaymentTotals)
+        .unwrap_or_default()
+}
+
+pub fn set_payment_totals(env: &Env, totals: &PaymentTotals) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PaymentTotals, totals);
+}
+
+pub fn set_standing_approval(env: &Env, buyer: &Address, approval: &StandingApproval) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::StandingApproval(buyer.clone()), approval);
+}
+
+pub fn get_standing_approval(env: &Env, buyer: &Address) -> Option<StandingApproval> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StandingApproval(buyer.clone()))
+}
+
+pub fn set_spend_cap(env: &Env, buyer: &Address, cap: Option<SpendCap>) {
+    let key = DataKey::SpendCap(buyer.clone());
+    match cap {
+        Some(cap) => env.storage().persistent().set(&key, &cap),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_spend_cap(env: &Env, buyer: &Address) -> Option<SpendCap> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SpendCap(buyer.clone()))
+}
+
+pub fn set_questionnaire(env: &Env, event_id: String, questionnaire_cid: Option<String>) {
+    let key = DataKey::Questionnaire(event_id);
+    match questionnaire_cid {
+        Some(cid) => env.storage().persistent().set(&key, &cid),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_questionnaire(env: &Env, event_id: String) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Questionnaire(event_id))
+}
+
+pub fn set_questionnaire_answers(
+    env: &Env,
+    payment_id: String,
+    answers: Option<QuestionnaireAnswers>,
+) {
+    let key = DataKey::QuestionnaireAnswers(payment_id);
+    match answers {
+        Some(answers) => env.storage().persistent().set(&key, &answers),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_questionnaire_answers(env: &Env, payment_id: String) -> Option<QuestionnaireAnswers> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::QuestionnaireAnswers(payment_id))
+}
+
+pub fn remove_standing_approval(env: &Env, buyer: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::StandingApproval(buyer.clone()));
+}
+
+pub fn increment_refund_reason_count(env: &Env, event_id: String, reason: RefundReason) {
+    let key = DataKey::RefundReasonCount(event_id, reason);
+    let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(count + 1));
+}
+
+pub fn get_refund_reason_stats(env: &Env, event_id: String) -> RefundReasonStats {
+    let count = |reason: RefundReason| -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundReasonCount(event_id.clone(), reason))
+            .unwrap_or(0)
+    };
+    RefundReasonStats {
+        overbooking: count(RefundReason::Overbooking),
+        comp_conversion: count(RefundReason::CompConversion),
+        goodwill: count(RefundReason::Goodwill),
+        other: count(RefundReason::Other),
+    }
+}
+
+/// Adds one to the counter of each error code in `codes`.
+pub fn record_rejections(env: &Env, event_id: String, codes: &Vec<u32>) {
+    let key = DataKey::RejectionStats(event_id.clone());
+    let mut stats = get_rejection_stats(env, event_id);
+    for code in codes.iter() {
+        stats.set(code, stats.get(code).unwrap_or(0).saturating_add(1));
+    }
+    env.storage().temporary().set(&key, &stats);
+    env.storage().temporary().extend_ttl(
+        &key,
+        REJECTION_STATS_TTL_LEDGERS,
+        REJECTION_STATS_TTL_LEDGERS,
+    );
+}
+
+/// Marks a buyer's preflight rejections for an event as counted for the next
+/// day. Returns false if they were already counted in that window.
+pub fn mark_preflight_counted(env: &Env, event_id: String, buyer: &Address) -> bool {
+    let key = DataKey::PreflightCounted(event_id, buyer.clone());
+    if env.storage().temporary().has(&key) {
+        return false;
+    }
+    env.storage().temporary().set(&key, &true);
+    env.storage().temporary().extend_ttl(
+        &key,
+        PREFLIGHT_DEDUPE_TTL_LEDGERS,
+        PREFLIGHT_DEDUPE_TTL_LEDGERS,
+    );
+    true
+}
+
+pub fn get_rejection_stats(env: &Env, event_id: String) -> Map<u32, u32> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::RejectionStats(event_id))
+        .unwrap_or(Map::new(env))
+}
+
+pub fn get_daily_stats(env: &Env, event_id: String, day: u64) -> DailyStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventDailyStats(event_id, day))
+        .unwrap_or_default()
+}
+
+pub fn set_daily_stats(env: &Env, event_id: String, day: u64, stats: &DailyStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EventDailyStats(event_id, day), stats);
+}
+
+pub fn get_event_sales(env: &Env, event_id: String) -> EventSales {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventSales(event_id))
+        .unwrap_or_default()
+}
+
+pub fn set_event_sales(env: &Env, event_id: String, sales: &EventSales) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EventSales(event_id), sales);
+}
+
+pub fn get_promo_campaign(env: &Env, campaign_id: String) -> Option<PromoCampaign> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoCampaign(campaign_id))
+}
+
+pub fn set_promo_campaign(env: &Env, campaign_id: String, campaign: &PromoCampaign) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoCampaign(campaign_id), campaign);
+}
+
+pub fn has_promo_code(env: &Env, code_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::PromoCode(code_hash.clone()))
+}
+
+pub fn set_promo_code(env: &Env, code_hash: &BytesN<32>, campaign_id: &String) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoCode(code_hash.clone()), campaign_id);
+}
+
+/// Removes an unredeemed code and returns its campaign, so each code can be
+/// redeemed once.
+pub fn take_promo_code(env: &Env, code_hash: &BytesN<32>) -> Option<String> {
+    let key = DataKey::PromoCode(code_hash.clone());
+    let campaign_id = env.storage().persistent().get(&key)?;
+    env.storage().persistent().remove(&key);
+    Some(campaign_id)
+}
+
+pub fn get_buyer_summary(env: &Env, buyer: &Address) -> BuyerSummary {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BuyerSummary(buyer.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_buyer_summary(env: &Env, buyer: &Address, summary: &BuyerSummary) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::BuyerSummary(buyer.clone()), summary);
+}
+
+pub fn set_max_ticket_price(env: &Env, max_price: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MaxTicketPrice, &max_price);
+}
+
+pub fn get_max_ticket_price(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaxTicketPrice)
+        .unwrap_or(0)
+}
+
+pub fn set_event_max_ticket_price(env: &Env, event_id: String, max_price: Option<i128>) {
+    let key = DataKey::EventMaxTicketPrice(event_id);
+    match max_price {
+        Some(price) => env.storage().persistent().set(&key, &price),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_event_max_ticket_price(env: &Env, event_id: String) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventMaxTicketPrice(event_id))
+}
+
+pub fn set_fee_strategy(env: &Env, strategy: Option<FeeStrategy>) {
+    match strategy {
+        Some(strategy) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::FeeStrategy, &strategy),
+        None => env.storage().persistent().remove(&DataKey::FeeStrategy),
+    }
+}
+
+pub fn get_fee_strategy(env: &Env) -> Option<FeeStrategy> {
+    env.storage().persistent().get(&DataKey::FeeStrategy)
+}
+
+pub fn set_event_fee_strategy(env: &Env, event_id: String, strategy: Option<FeeStrategy>) {
+    let key = DataKey::EventFeeStrategy(event_id);
+    match strategy {
+        Some(strategy) => env.storage().persistent().set(&key, &strategy),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_event_fee_strategy(env: &Env, event_id: String) -> Option<FeeStrategy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventFeeStrategy(event_id))
+}
+
+pub fn set_purchase_limits(env: &Env, limits: &PurchaseLimits) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PurchaseLimits, limits);
+}
+
+pub fn get_purchase_limits(env: &Env) -> PurchaseLimits {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PurchaseLimits)
+        .unwrap_or_default()
+}
+
+pub fn set_custodian(env: &Env, custodian: &Address, allowed: bool) {
+    let key = DataKey::Custodian(custodian.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_custodian(env: &Env, custodian: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Custodian(custodian.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_ticket_claim(env: &Env, payment_id: String, claim: &TicketClaim) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TicketClaim(payment_id), claim);
+}
+
+pub fn get_ticket_claim(env: &Env, payment_id: String) -> Option<TicketClaim> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TicketClaim(payment_id))
+}
+
+pub fn remove_ticket_claim(env: &Env, payment_id: String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TicketClaim(payment_id));
+}
+
+pub fn set_refund_redirect(env: &Env, payment_id: String, redirect: Option<RefundRedirect>) {
+    let key = DataKey::RefundRedirect(payment_id);
+    match redirect {
+        Some(redirect) => env.storage().persistent().set(&key, &redirect),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_refund_redirect(env: &Env, payment_id: String) -> Option<RefundRedirect> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundRedirect(payment_id))
+}
+
+pub fn set_event_partner(env: &Env, event_id: String, config: Option<PartnerConfig>) {
+    let key = DataKey::EventPartner(event_id);
+    match config {
+        Some(config) => env.storage().persistent().set(&key, &config),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn set_withholding(env: &Env, organizer: &Address, config: Option<WithholdingConfig>) {
+    let key = DataKey::Withholding(organizer.clone());
+    match config {
+        Some(config) => env.storage().persistent().set(&key, &config),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_withholding(env: &Env, organizer: &Address) -> Option<WithholdingConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Withholding(organizer.clone()))
+}
+
+pub fn get_event_partner(env: &Env, event_id: String) -> Option<PartnerConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventPartner(event_id))
+}
+
+pub fn get_partner_balance(env: &Env, partner: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartnerBalance(partner.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets a partner's balance and keeps the per-token total of held funds in step.
+pub fn set_partner_balance(env: &Env, partner: &Address, token: &Address, balance: i128) {
+    let delta = balance - get_partner_balance(env, partner, token);
+    env.storage().persistent().set(
+        &DataKey::PartnerFeesOwed(token.clone()),
+        &(get_partner_fees_owed(env, token) + delta),
+    );
+    env.storage().persistent().set(
+        &DataKey::PartnerBalance(partner.clone(), token.clone()),
+        &balance,
+    );
+}
+
+pub fn set_rebate_program(env: &Env, program: Option<RebateProgram>) {
+    match program {
+        Some(program) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::RebateProgram, &program),
+        None => env.storage().persistent().remove(&DataKey::RebateProgram),
+    }
+}
+
+pub fn get_rebate_program(env: &Env) -> Option<RebateProgram> {
+    env.storage().persistent().get(&DataKey::RebateProgram)
+}
+
+pub fn get_rebate_balance(env: &Env, payout_address: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RebateBalance(
+            payout_address.clone(),
+            token.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+/// Sets an organizer's rebate balance and keeps the per-token total in step.
+pub fn set_rebate_balance(env: &Env, payout_address: &Address, token: &Address, balance: i128) {
+    let delta = balance - get_rebate_balance(env, payout_address, token);
+    env.storage().persistent().set(
+        &DataKey::RebatesOwed(token.clone()),
+        &(get_rebates_owed(env, token) + delta),
+    );
+    env.storage().persistent().set(
+        &DataKey::RebateBalance(payout_address.clone(), token.clone()),
+        &balance,
+    );
+}
+
+/// Records the fee rebate a sale accrued, so a refund can take it back.
+pub fn set_payment_rebate(env: &Env, payment_id: String, rebate: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PaymentRebate(payment_id), &rebate);
+}
+
+pub fn get_payment_rebate(env: &Env, payment_id: String) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PaymentRebate(payment_id))
+        .unwrap_or(0)
+}
+
+pub fn get_rebates_owed(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RebatesOwed(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_partner_fees_owed(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartnerFeesOwed(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_rounding_beneficiary(env: &Env, beneficiary: RoundingBeneficiary) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RoundingBeneficiary, &beneficiary);
+}
+
+pub fn get_rounding_beneficiary(env: &Env) -> RoundingBeneficiary {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RoundingBeneficiary)
+        .unwrap_or(RoundingBeneficiary::Organizer)
+}
+
+pub fn get_dust_balance(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DustBalance(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets a token's dust balance and keeps the list of tokens holding dust in step.
+pub fn set_dust_balance(env: &Env, token: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DustBalance(token.clone()), &balance);
+
+    let mut tokens = get_dust_tokens(env);
+    match (tokens.first_index_of(token), balance > 0) {
+        (None, true) => tokens.push_back(token.clone()),
+        (Some(index), false) => {
+            tokens.remove(index);
+        }
+        _ => return,
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::DustTokens, &tokens);
+}
+
+pub fn get_dust_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DustTokens)
+        .unwrap_or(vec![env])
+}
+
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
+pub fn get_pending_migration(env: &Env) -> Option<u32> {
+    env.storage().persistent().get(&DataKey::PendingMigration)
+}
+
+pub fn set_pending_migration(env: &Env, migration_id: Option<u32>) {
+    match migration_id {
+        Some(id) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::PendingMigration, &id),
+        None => env
+            .storage()
+            .persistent()
+            .remove(&DataKey::PendingMigration),
+    }
+}
+
+pub fn get_hooks(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Hooks)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_hooks(env: &Env, hooks: &Vec<Address>) {
+    env.storage().persistent().set(&DataKey::Hooks, hooks);
+}
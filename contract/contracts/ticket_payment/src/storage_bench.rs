@@ -0,0 +1,138 @@
+//! Cost-estimate benchmark of the payment storage layout.
+//!
+//! Compares the cost of storing one more payment once `n` payments already
+//! exist, under the current key-per-record layout (`DataKey::Payment`) and
+//! under a single `Map` of every payment kept in one entry. The Map layout
+//! reads and rewrites the whole collection on each purchase, so its cost grows
+//! with `n`; the key-per-record layout should stay flat.
+//!
+//! The 100k case takes minutes to seed and is ignored by default:
+//! `cargo test -p ticket-payment storage_bench -- --include-ignored`.
+
+use crate::contract::TicketPaymentContract;
+use crate::storage::save_payment;
+use crate::types::{Payment, PaymentStatus};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, Map, String, Symbol, Vec};
+
+const ALL_PAYMENTS: Symbol = symbol_short!("ALL_PAY");
+
+#[derive(Clone, Copy)]
+enum Layout {
+    KeyPerRecord,
+    SingleMap,
+}
+
+fn sample_payment(env: &Env, index: u32, buyer: &Address) -> Payment {
+    let mut id = [b'p'; 10];
+    let mut rest = index;
+    for digit in id.iter_mut().skip(1).rev() {
+        *digit = b'0' + (rest % 10) as u8;
+        rest /= 10;
+    }
+    let payment_id = String::from_bytes(env, &id);
+    Payment {
+        payment_id,
+        event_id: String::from_str(env, "event_1"),
+        buyer_address: buyer.clone(),
+        ticket_tier_id: String::from_str(env, "tier_1"),
+        token_address: buyer.clone(),
+        payout_address: buyer.clone(),
+        amount: 1000,
+        platform_fee: 50,
+        organizer_amount: 950,
+        status: PaymentStatus::Pending,
+        transaction_hash: String::from_str(env, ""),
+        created_at: 0,
+        confirmed_at: None,
+        attestation_hash: None,
+        addons: Vec::new(env),
+        beneficiary_id: None,
+        metadata_cid: String::from_str(env, ""),
+        rounding_dust: 0,
+        attribution: None,
+        redacted_at: None,
+    }
+}
+
+fn insert(env: &Env, layout: Layout, payment: Payment) {
+    match layout {
+        Layout::KeyPerRecord => save_payment(env, &payment),
+        Layout::SingleMap => {
+            let mut all: Map<String, Payment> = env
+                .storage()
+                .persistent()
+                .get(&ALL_PAYMENTS)
+                .unwrap_or(Map::new(env));
+            all.set(payment.payment_id.clone(), payment);
+            env.storage().persistent().set(&ALL_PAYMENTS, &all);
+        }
+    }
+}
+
+/// Seeds `existing` payments, then returns the (CPU, memory) cost of one more.
+fn cost_of_next_insert(layout: Layout, existing: u32) -> (u64, u64) {
+    let env = Env::default();
+    // Seeding and the Map layout itself blow the per-transaction limits
+    env.cost_estimate().disable_resource_limits();
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_id = env.register(TicketPaymentContract, ());
+    let buyer = Address::generate(&env);
+
+    env.as_contract(&contract_id, || match layout {
+        Layout::KeyPerRecord => {
+            for index in 0..existing {
+                save_payment(&env, &sample_payment(&env, index, &buyer));
+            }
+        }
+        Layout::SingleMap => {
+            let mut all = Map::new(&env);
+            for index in 0..existing {
+                let payment = sample_payment(&env, index, &buyer);
+                all.set(payment.payment_id.clone(), payment);
+            }
+            env.storage().persistent().set(&ALL_PAYMENTS, &all);
+        }
+    });
+
+    // Measure in a fresh env so only the entries the insert touches are
+    // loaded, as in a real transaction's footprint
+    let env = Env::from_ledger_snapshot(env.to_ledger_snapshot());
+    env.cost_estimate().disable_resource_limits();
+    let next = sample_payment(&env, existing, &Address::generate(&env));
+    env.cost_estimate().budget().reset_unlimited();
+    env.as_contract(&contract_id, || insert(&env, layout, next));
+    let budget = env.cost_estimate().budget();
+    (budget.cpu_instruction_cost(), budget.memory_bytes_cost())
+}
+
+fn assert_flat_vs_growing(large: u32) {
+    let (record_small, _) = cost_of_next_insert(Layout::KeyPerRecord, 10);
+    let (map_small, _) = cost_of_next_insert(Layout::SingleMap, 10);
+    let (record_large, record_large_mem) = cost_of_next_insert(Layout::KeyPerRecord, large);
+    let (map_large, map_large_mem) = cost_of_next_insert(Layout::SingleMap, large);
+
+    assert!(
+        record_large <= record_small * 12 / 10,
+        "key-per-record insert grew from {record_small} to {record_large} CPU"
+    );
+    assert!(
+        map_large > map_small * 10,
+        "single-map insert only grew from {map_small} to {map_large} CPU"
+    );
+    assert!(
+        map_large > record_large * 10 && map_large_mem > record_large_mem * 10,
+        "single-map insert ({map_large} CPU, {map_large_mem} B) should dwarf \
+         key-per-record ({record_large} CPU, {record_large_mem} B)"
+    );
+}
+
+#[test]
+fn test_storage_layouts_at_1k_payments() {
+    assert_flat_vs_growing(1_000);
+}
+
+#[test]
+#[ignore]
+fn test_storage_layouts_at_100k_payments() {
+    assert_flat_vs_growing(100_000);
+}
@@ -1,10 +1,23 @@
-use super::contract::{event_registry, TicketPaymentContract, TicketPaymentContractClient};
+use super::contract::{
+    compute_fee, event_registry, TicketPaymentContract, TicketPaymentContractClient,
+    GUARDIAN_FREEZE_SECS, MAX_QUOTE_SECS, REFUND_REDIRECT_COOLDOWN,
+};
 use super::storage::*;
-use super::types::{Payment, PaymentStatus};
+use super::types::{
+    AddonLineItem, AddonSelection, AttestationPayload, ClaimPayload, FeeStrategy, FeeTier,
+    HookKind, HookPayload, InvariantViolation, PartnerConfig, Payment, PaymentStatus,
+    PendingAction, PriceQuote, PurchaseAttestation, PurchaseExtra, PurchaseOptions,
+    QuestionnaireAnswers, QuotePayload, RebateProgram, RefundReason, RoundingBeneficiary,
+    ViolationDetail, WithholdingConfig,
+};
 use crate::error::TicketPaymentError;
+use crate::events::{PaymentSettledEvent, SettlementLeg, SettlementLegKind};
+use ed25519_dalek::{Signer, SigningKey};
 use soroban_sdk::{
-    testutils::{Address as _, Events},
-    token, Address, Env, IntoVal, String, Symbol, TryIntoVal,
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    token, vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, TryIntoVal, Vec,
 };
 
 // Mock Event Registry Contract
@@ -13,12 +26,123 @@ pub struct MockEventRegistry;
 
 #[soroban_sdk::contractimpl]
 impl MockEventRegistry {
-    pub fn get_event_payment_info(env: Env, _event_id: String) -> event_registry::PaymentInfo {
+    // The payout address is generated once and reused so refunds can batch
+    pub fn get_event_payment_info(env: Env, event_id: String) -> event_registry::PaymentInfo {
+        let key = Symbol::new(&env, "payout");
+        let payment_address: Address = match env.storage().instance().get(&key) {
+            Some(addr) => addr,
+            None => {
+                let addr = Address::generate(&env);
+                env.storage().instance().set(&key, &addr);
+                addr
+            }
+        };
         event_registry::PaymentInfo {
-            payment_address: Address::generate(&env),
+            payment_address,
             platform_fee_percent: 500, // 5%
+            metadata_cid: Self::get_event_metadata(env.clone(), event_id.clone()),
+            cohosts: env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "cohosts"))
+                .unwrap_or(Vec::new(&env)),
+            created_at: env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "created_at"))
+                .unwrap_or(0),
+            partner_key: Self::get_event_partner_key(env.clone(), event_id),
+            custom_fee: env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "custom_fee"))
+                .unwrap_or(false),
+            accepted_tokens: env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "tokens"))
+                .unwrap_or(Vec::new(&env)),
+        }
+    }
+
+    pub fn set_accepted_tokens(env: Env, tokens: Vec<Address>) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "tokens"), &tokens);
+    }
+
+    pub fn set_custom_fee(env: Env, custom_fee: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "custom_fee"), &custom_fee);
+    }
+
+    pub fn set_event_cohosts(env: Env, cohosts: Vec<event_registry::CoHost>) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "cohosts"), &cohosts);
+    }
+
+    pub fn get_event_metadata(env: Env, _event_id: String) -> String {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "cid"))
+            .unwrap_or(String::from_str(&env, "bafy_v1"))
+    }
+
+    pub fn set_event_metadata(env: Env, metadata_cid: String) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "cid"), &metadata_cid);
+    }
+
+    pub fn get_event_partner_key(env: Env, _event_id: String) -> Option<BytesN<32>> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "partner_key"))
+    }
+
+    pub fn get_registry_stats(_env: Env) -> event_registry::RegistryStats {
+        event_registry::RegistryStats {
+            total_events: 3,
+            active_events: 2,
+        }
+    }
+
+    // Every add-on costs 50 per unit; "sold_out" simulates AddonSoldOut (14)
+    pub fn reserve_addons(
+        env: Env,
+        _event_id: String,
+        selections: Vec<AddonSelection>,
+    ) -> Result<Vec<AddonLineItem>, soroban_sdk::Error> {
+        let mut line_items = Vec::new(&env);
+        for selection in selections.iter() {
+            if selection.sku == String::from_str(&env, "sold_out") {
+                return Err(soroban_sdk::Error::from_contract_error(14));
+            }
+            line_items.push_back(AddonLineItem {
+                sku: selection.sku,
+                quantity: selection.quantity,
+                unit_price: 50,
+                line_total: 50 * selection.quantity as i128,
+            });
         }
+        Ok(line_items)
     }
+
+    pub fn get_inventory_hold(
+        _env: Env,
+        _event_id: String,
+        _buyer: Address,
+    ) -> Option<event_registry::InventoryHold> {
+        None
+    }
+
+    pub fn consume_inventory_hold(_env: Env, _event_id: String, _buyer: Address) {}
+
+    pub fn increment_inventory(_env: Env, _event_id: String) {}
+
+    pub fn release_inventory(_env: Env, _event_id: String, _quantity: u32) {}
 }
 
 // Another Mock for different fee
@@ -31,8 +155,26 @@ impl MockEventRegistry2 {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 250, // 2.5%
+            metadata_cid: String::from_str(&env, "bafy_v1"),
+            cohosts: Vec::new(&env),
+            accepted_tokens: Vec::new(&env),
+            created_at: 0,
+            partner_key: None,
+            custom_fee: false,
         }
     }
+
+    pub fn get_inventory_hold(
+        _env: Env,
+        _event_id: String,
+        _buyer: Address,
+    ) -> Option<event_registry::InventoryHold> {
+        None
+    }
+
+    pub fn increment_inventory(_env: Env, _event_id: String) {}
+
+    pub fn release_inventory(_env: Env, _event_id: String, _quantity: u32) {}
 }
 
 // Mock Event Registry returning EventNotFound
@@ -52,6 +194,34 @@ impl MockEventRegistryNotFound {
 // But since the interface doesn't return Result in the mock, panicking triggers a contract error in the VM.
 // Let's implement actual error returning mocks and see if it catches it correctly.
 
+// Hook that records the last payload it was notified with
+#[soroban_sdk::contract]
+pub struct RecordingHook;
+
+#[soroban_sdk::contractimpl]
+impl RecordingHook {
+    pub fn on_ticket_event(env: Env, payload: HookPayload) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "last"), &payload);
+    }
+
+    pub fn last(env: Env) -> Option<HookPayload> {
+        env.storage().instance().get(&Symbol::new(&env, "last"))
+    }
+}
+
+// Hook that always fails
+#[soroban_sdk::contract]
+pub struct FailingHook;
+
+#[soroban_sdk::contractimpl]
+impl FailingHook {
+    pub fn on_ticket_event(_env: Env, _payload: HookPayload) {
+        panic!("hook failure");
+    }
+}
+
 // Dummy contract used to provide a valid alternate Wasm hash for upgrade tests.
 #[soroban_sdk::contract]
 pub struct DummyUpgradeable;
@@ -61,7 +231,7 @@ impl DummyUpgradeable {
     pub fn ping(_env: Env) {}
 }
 
-fn setup_test(
+pub(crate) fn setup_test(
     env: &Env,
 ) -> (
     TicketPaymentContractClient<'static>,
@@ -79,6 +249,13 @@ fn setup_test(
         .address();
     let platform_wallet = Address::generate(env);
     let event_registry_id = env.register(MockEventRegistry, ());
+    // Seed the mock's payout address from outside any contract frame, so it
+    // cannot collide with a contract address generated mid-invocation
+    env.as_contract(&event_registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, "payout"), &Address::generate(env));
+    });
 
     client.initialize(&admin, &usdc_id, &platform_wallet, &event_registry_id);
 
@@ -107,8 +284,15 @@ fn test_process_payment_success() {
     let event_id = String::from_str(&env, "event_1");
     let tier_id = String::from_str(&env, "tier_1");
 
-    let result_id =
-        client.process_payment(&payment_id, &event_id, &tier_id, &buyer, &usdc_id, &amount);
+    let result_id = client.process_payment(
+        &payment_id,
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &amount,
+        &u64::MAX,
+    );
     assert_eq!(result_id, payment_id);
 
     // Check balances
@@ -165,6 +349,8 @@ fn test_confirm_payment() {
         event_id: String::from_str(&env, "e1"),
         buyer_address: buyer,
         ticket_tier_id: String::from_str(&env, "t1"),
+        token_address: Address::generate(&env),
+        payout_address: Address::generate(&env),
         amount: 100,
         platform_fee: 5,
         organizer_amount: 95,
@@ -172,6 +358,13 @@ fn test_confirm_payment() {
         transaction_hash: String::from_str(&env, ""),
         created_at: 100,
         confirmed_at: None,
+        attestation_hash: None,
+        addons: Vec::new(&env),
+        beneficiary_id: None,
+        metadata_cid: String::from_str(&env, "bafy_v1"),
+        rounding_dust: 0,
+        attribution: None,
+        redacted_at: None,
     };
 
     env.as_contract(&client.address, || {
@@ -187,7 +380,6 @@ fn test_confirm_payment() {
 }
 
 #[test]
-#[should_panic(expected = "Amount must be positive")]
 fn test_process_payment_zero_amount() {
     let env = Env::default();
     env.mock_all_auths();
@@ -196,14 +388,16 @@ fn test_process_payment_zero_amount() {
     let buyer = Address::generate(&env);
     let payment_id = String::from_str(&env, "pay_1");
 
-    client.process_payment(
+    let res = client.try_process_payment(
         &payment_id,
         &String::from_str(&env, "e1"),
         &String::from_str(&env, "t1"),
         &buyer,
         &usdc_id,
         &0,
+        &u64::MAX,
     );
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidAmount)));
 }
 
 #[test]
@@ -233,6 +427,7 @@ fn test_fee_calculation_variants() {
         &buyer,
         &usdc_id,
         &10000i128,
+        &u64::MAX,
     );
 
     let payment = client
@@ -269,6 +464,7 @@ fn test_process_payment_not_found() {
         &buyer,
         &usdc_id,
         &10000i128,
+        &u64::MAX,
     );
     // Since panic inside get_event_payment_info cannot easily map to get_code() == 2 right now without explicit Error returning in the mock,
     // this might return a generic EventNotFound due to our fallback logic.
@@ -342,7 +538,7 @@ fn test_upgrade_preserves_initialization_addresses_and_emits_event() {
         Some(soroban_sdk::Executable::Wasm(hash)) => hash,
         _ => panic!("Dummy contract is not a Wasm contract"),
     };
-    client.upgrade(&new_wasm_hash);
+    client.upgrade(&new_wasm_hash, &0);
 
     // After upgrade, executable hash should change.
     let upgraded_wasm_hash = match client.address.executable() {
@@ -402,7 +598,7 @@ fn test_upgrade_unauthorized_panics() {
     };
 
     // No env.mock_all_auths() here, so require_auth should fail.
-    client.upgrade(&new_wasm_hash);
+    client.upgrade(&new_wasm_hash, &0);
 }
 
 #[test]
@@ -446,11 +642,54 @@ fn test_process_payment_with_non_whitelisted_token() {
         &buyer,
         &non_whitelisted_token,
         &10000i128,
+        &u64::MAX,
     );
 
     assert_eq!(res, Err(Ok(TicketPaymentError::TokenNotWhitelisted)));
 }
 
+#[test]
+fn test_denied_token_is_rejected_until_removed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+
+    client.deny_token(&usdc_id, &0);
+    assert!(client.is_token_denied(&usdc_id));
+    assert!(!client.is_token_allowed(&usdc_id));
+    assert_eq!(
+        client.try_add_token(&usdc_id),
+        Err(Ok(TicketPaymentError::TokenDenied))
+    );
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::TokenDenied)));
+    assert!(client
+        .validate_purchase(
+            &String::from_str(&env, "event_1"),
+            &buyer,
+            &usdc_id,
+            &1000i128
+        )
+        .contains(TicketPaymentError::TokenDenied as u32));
+
+    // Lifting the denial doesn't whitelist the token again by itself
+    client.remove_denied_token(&usdc_id);
+    assert!(!client.is_token_allowed(&usdc_id));
+    client.add_token(&usdc_id);
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+}
+
 #[test]
 fn test_process_payment_with_multiple_tokens() {
     let env = Env::default();
@@ -480,6 +719,7 @@ fn test_process_payment_with_multiple_tokens() {
         &buyer1,
         &usdc_id,
         &usdc_amount,
+        &u64::MAX,
     );
 
     client.process_payment(
@@ -489,6 +729,7 @@ fn test_process_payment_with_multiple_tokens() {
         &buyer2,
         &xlm_id,
         &xlm_amount,
+        &u64::MAX,
     );
 
     let usdc_platform_balance = token::Client::new(&env, &usdc_id).balance(&platform_wallet);
@@ -510,3 +751,2775 @@ fn test_process_payment_with_multiple_tokens() {
     assert_eq!(payment1.amount, usdc_amount);
     assert_eq!(payment2.amount, xlm_amount);
 }
+
+fn sign_attestation(
+    env: &Env,
+    signing_key: &SigningKey,
+    buyer: &Address,
+    event_id: &String,
+    expires_at: u64,
+) -> PurchaseAttestation {
+    let payload = AttestationPayload {
+        buyer_address: buyer.clone(),
+        event_id: event_id.clone(),
+        expires_at,
+    };
+    let message = payload.to_xdr(env);
+    let mut buf = [0u8; 256];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+
+    let signature = signing_key.sign(&buf[..len]);
+    PurchaseAttestation {
+        expires_at,
+        signature: BytesN::from_array(env, &signature.to_bytes()),
+    }
+}
+
+#[test]
+fn test_restricted_event_requires_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let event_id = String::from_str(&env, "event_18plus");
+    client.set_event_restricted(&event_id, &true);
+    assert!(client.is_event_restricted(&event_id));
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10000i128);
+
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &String::from_str(&env, "t1"),
+        &buyer,
+        &usdc_id,
+        &10000i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::AttestationRequired)));
+}
+
+#[test]
+fn test_restricted_payment_with_valid_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_attestor(
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+        &0,
+    );
+    assert!(client.get_attestor().is_some());
+
+    let event_id = String::from_str(&env, "event_18plus");
+    client.set_event_restricted(&event_id, &true);
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10000i128);
+
+    let attestation = sign_attestation(&env, &signing_key, &buyer, &event_id, 2_000);
+    let payment_id = String::from_str(&env, "p1");
+    client.process_payment_with_options(
+        &payment_id,
+        &event_id,
+        &String::from_str(&env, "t1"),
+        &buyer,
+        &usdc_id,
+        &10000i128,
+        &purchase_options(&env, &[PurchaseExtra::Attestation(attestation.clone())]),
+    );
+
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    let expected_hash: BytesN<32> = env.crypto().sha256(&attestation.to_xdr(&env)).to_bytes();
+    assert_eq!(payment.attestation_hash, Some(expected_hash));
+}
+
+#[test]
+fn test_restricted_payment_expired_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_attestor(
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+        &0,
+    );
+
+    let event_id = String::from_str(&env, "event_18plus");
+    let buyer = Address::generate(&env);
+    let attestation = sign_attestation(&env, &signing_key, &buyer, &event_id, 4_000);
+
+    let res = client.try_process_payment_with_options(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &String::from_str(&env, "t1"),
+        &buyer,
+        &usdc_id,
+        &10000i128,
+        &purchase_options(&env, &[PurchaseExtra::Attestation(attestation.clone())]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::AttestationExpired)));
+}
+
+#[test]
+fn test_restricted_payment_without_attestor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let event_id = String::from_str(&env, "event_18plus");
+    let buyer = Address::generate(&env);
+    let attestation = sign_attestation(&env, &signing_key, &buyer, &event_id, 4_000);
+
+    let res = client.try_process_payment_with_options(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &String::from_str(&env, "t1"),
+        &buyer,
+        &usdc_id,
+        &10000i128,
+        &purchase_options(&env, &[PurchaseExtra::Attestation(attestation.clone())]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::AttestorNotSet)));
+}
+
+#[test]
+#[should_panic]
+fn test_restricted_payment_attestation_for_other_buyer_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_attestor(
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+        &0,
+    );
+
+    let event_id = String::from_str(&env, "event_18plus");
+    let attested_buyer = Address::generate(&env);
+    let other_buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&other_buyer, &10000i128);
+
+    // Signature covers a different buyer, so verification traps
+    let attestation = sign_attestation(&env, &signing_key, &attested_buyer, &event_id, 4_000);
+    client.process_payment_with_options(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &String::from_str(&env, "t1"),
+        &other_buyer,
+        &usdc_id,
+        &10000i128,
+        &purchase_options(&env, &[PurchaseExtra::Attestation(attestation.clone())]),
+    );
+}
+
+#[test]
+fn test_payment_options_combine_attestation_and_answers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_attestor(
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+        &0,
+    );
+    let event_id = String::from_str(&env, "event_1");
+    client.set_event_restricted(&event_id, &true);
+    client.set_event_questionnaire(
+        &event_id,
+        &Some(String::from_str(&env, "bafyquestionnaire")),
+    );
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10000i128);
+    let attestation = sign_attestation(&env, &signing_key, &buyer, &event_id, 2_000);
+    let answers = QuestionnaireAnswers {
+        answers_cid: String::from_str(&env, "bafyanswers"),
+        answers_hash: BytesN::from_array(&env, &[3u8; 32]),
+    };
+    let attribution = BytesN::from_array(&env, &[9u8; 32]);
+
+    let pay = |extras: &[PurchaseExtra]| {
+        client.try_process_payment_with_options(
+            &String::from_str(&env, "p1"),
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &1000i128,
+            &purchase_options(&env, extras),
+        )
+    };
+    assert_eq!(
+        pay(&[PurchaseExtra::Attestation(attestation.clone())]),
+        Err(Ok(TicketPaymentError::QuestionnaireRequired))
+    );
+    assert_eq!(
+        pay(&[
+            PurchaseExtra::Attribution(attribution.clone()),
+            PurchaseExtra::Attribution(attribution.clone()),
+        ]),
+        Err(Ok(TicketPaymentError::DuplicatePurchaseExtra))
+    );
+
+    let payment_id = String::from_str(&env, "p1");
+    pay(&[
+        PurchaseExtra::Attestation(attestation.clone()),
+        PurchaseExtra::Answers(answers.clone()),
+        PurchaseExtra::Attribution(attribution.clone()),
+    ])
+    .unwrap()
+    .unwrap();
+
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    assert!(payment.attestation_hash.is_some());
+    assert_eq!(payment.attribution, Some(attribution));
+    assert_eq!(client.get_questionnaire_answers(&payment_id), Some(answers));
+}
+
+fn purchase_options(env: &Env, extras: &[PurchaseExtra]) -> PurchaseOptions {
+    PurchaseOptions {
+        expires_at: u64::MAX,
+        extras: Vec::from_slice(env, extras),
+    }
+}
+
+fn pay_at(
+    env: &Env,
+    client: &TicketPaymentContractClient,
+    usdc_id: &Address,
+    payment_id: &str,
+    timestamp: u64,
+) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+    let buyer = Address::generate(env);
+    token::StellarAssetClient::new(env, usdc_id).mint(&buyer, &1000i128);
+    client.process_payment(
+        &String::from_str(env, payment_id),
+        &String::from_str(env, "event_1"),
+        &String::from_str(env, "tier_1"),
+        &buyer,
+        usdc_id,
+        &1000i128,
+        &u64::MAX,
+    );
+}
+
+#[test]
+fn test_get_payments_in_range_filters_by_day() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let day = 86_400u64;
+    pay_at(&env, &client, &usdc_id, "p_day0", 10 * day + 100);
+    pay_at(&env, &client, &usdc_id, "p_day1_a", 11 * day + 5);
+    pay_at(&env, &client, &usdc_id, "p_day1_b", 11 * day + 7_200);
+    pay_at(&env, &client, &usdc_id, "p_day2", 12 * day);
+
+    let event_id = String::from_str(&env, "event_1");
+    let page = client.get_payments_in_range(&event_id, &(11 * day), &(12 * day), &0, &10);
+    assert_eq!(page.payments.len(), 2);
+    assert_eq!(
+        page.payments.get(0).unwrap().payment_id,
+        String::from_str(&env, "p_day1_a")
+    );
+    assert_eq!(
+        page.payments.get(1).unwrap().payment_id,
+        String::from_str(&env, "p_day1_b")
+    );
+    assert_eq!(page.next_cursor, None);
+
+    // Partial-day bounds are honored on edge buckets
+    let page =
+        client.get_payments_in_range(&event_id, &(10 * day + 200), &(11 * day + 10), &0, &10);
+    assert_eq!(page.payments.len(), 1);
+    assert_eq!(
+        page.payments.get(0).unwrap().payment_id,
+        String::from_str(&env, "p_day1_a")
+    );
+}
+
+#[test]
+fn test_get_payments_in_range_pagination() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let day = 86_400u64;
+    pay_at(&env, &client, &usdc_id, "p1", 3 * day + 1);
+    pay_at(&env, &client, &usdc_id, "p2", 4 * day + 1);
+    pay_at(&env, &client, &usdc_id, "p3", 5 * day + 1);
+
+    let event_id = String::from_str(&env, "event_1");
+    let first = client.get_payments_in_range(&event_id, &(3 * day), &(6 * day), &0, &2);
+    assert_eq!(first.payments.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let second = client.get_payments_in_range(&event_id, &(3 * day), &(6 * day), &2, &2);
+    assert_eq!(second.payments.len(), 1);
+    assert_eq!(
+        second.payments.get(0).unwrap().payment_id,
+        String::from_str(&env, "p3")
+    );
+    assert_eq!(second.next_cursor, None);
+}
+
+#[test]
+fn test_get_payments_in_range_invalid_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _, _, _) = setup_test(&env);
+    let event_id = String::from_str(&env, "event_1");
+
+    let res = client.try_get_payments_in_range(&event_id, &100, &100, &0, &10);
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidTimeRange)));
+
+    let res = client.try_get_payments_in_range(&event_id, &0, &(40 * 86_400), &0, &10);
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidTimeRange)));
+}
+
+#[test]
+fn test_confirm_payment_does_not_duplicate_indexes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 500);
+    client.confirm_payment(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "tx_hash"),
+    );
+
+    let event_id = String::from_str(&env, "event_1");
+    let page = client.get_payments_in_range(&event_id, &0, &86_400, &0, &10);
+    assert_eq!(page.payments.len(), 1);
+    assert_eq!(
+        page.payments.get(0).unwrap().status,
+        PaymentStatus::Confirmed
+    );
+    let indexed = env.as_contract(&client.address, || get_event_payments(&env, event_id));
+    assert_eq!(indexed.len(), 1);
+}
+
+#[test]
+fn test_get_platform_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+    pay_at(&env, &client, &usdc_id, "p2", 200);
+
+    let stats = client.get_platform_stats();
+    assert_eq!(stats.events_registered, 3);
+    assert_eq!(stats.active_events, 2);
+    assert_eq!(stats.total_payments, 2);
+    assert_eq!(stats.total_volume, 2000);
+    assert_eq!(stats.total_fees_collected, 100); // 5% of each 1000
+    assert_eq!(stats.total_refunded, 0);
+}
+
+#[test]
+fn test_process_payment_with_addons_itemizes_and_charges_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10000i128);
+
+    let parking = String::from_str(&env, "parking");
+    let shirt = String::from_str(&env, "tshirt");
+    let addons = vec![
+        &env,
+        AddonSelection {
+            sku: parking.clone(),
+            quantity: 2,
+        },
+        AddonSelection {
+            sku: shirt.clone(),
+            quantity: 1,
+        },
+    ];
+
+    let payment_id = String::from_str(&env, "p_addons");
+    client.process_payment_with_options(
+        &payment_id,
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(&env, &[PurchaseExtra::Addons(addons.clone())]),
+    );
+
+    // 1000 ticket + 3 add-on units at 50
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    assert_eq!(payment.amount, 1150);
+    assert_eq!(payment.platform_fee, 57); // 5% of 1150, rounded down
+    assert_eq!(payment.organizer_amount, 1093);
+    assert_eq!(payment.addons.len(), 2);
+    assert_eq!(
+        payment.addons.get(0).unwrap(),
+        AddonLineItem {
+            sku: parking,
+            quantity: 2,
+            unit_price: 50,
+            line_total: 100,
+        }
+    );
+
+    let usdc = token::Client::new(&env, &usdc_id);
+    assert_eq!(usdc.balance(&buyer), 10000 - 1150);
+    assert_eq!(usdc.balance(&platform_wallet), 57);
+}
+
+#[test]
+fn test_process_payment_with_sold_out_addon() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10000i128);
+
+    let addons = vec![
+        &env,
+        AddonSelection {
+            sku: String::from_str(&env, "sold_out"),
+            quantity: 1,
+        },
+    ];
+    let res = client.try_process_payment_with_options(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(&env, &[PurchaseExtra::Addons(addons.clone())]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::AddonSoldOut)));
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&buyer), 10000);
+}
+
+fn setup_standing_approval(
+    env: &Env,
+) -> (
+    TicketPaymentContractClient<'static>,
+    Address,
+    Address,
+    Address,
+) {
+    let (client, _admin, usdc_id, _, _) = setup_test(env);
+    let buyer = Address::generate(env);
+    let operator = Address::generate(env);
+    token::StellarAssetClient::new(env, &usdc_id).mint(&buyer, &10000i128);
+    token::Client::new(env, &usdc_id).approve(&buyer, &client.address, &10000i128, &1000);
+
+    client.grant_standing_approval(&buyer, &operator, &2500i128, &86_400);
+    (client, usdc_id, buyer, operator)
+}
+
+#[test]
+fn test_approved_payment_within_period_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, usdc_id, buyer, operator) = setup_standing_approval(&env);
+
+    client.process_approved_payment(
+        &String::from_str(&env, "season_1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(&env, &[]),
+    );
+    client.process_approved_payment(
+        &String::from_str(&env, "season_2"),
+        &String::from_str(&env, "event_2"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(&env, &[]),
+    );
+
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&buyer), 8000);
+    let approval = client.get_standing_approval(&buyer).unwrap();
+    assert_eq!(approval.operator, operator);
+    assert_eq!(approval.spent_in_period, 2000);
+
+    // Third purchase would exceed the 2500 cap for this period
+    let res = client.try_process_approved_payment(
+        &String::from_str(&env, "season_3"),
+        &String::from_str(&env, "event_3"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(&env, &[]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::StandingApprovalExceeded)));
+
+    // Add-ons count too: 400 plus 3 x 50 overshoots the 500 left
+    let addons = PurchaseExtra::Addons(vec![
+        &env,
+        AddonSelection {
+            sku: String::from_str(&env, "parking"),
+            quantity: 3,
+        },
+    ]);
+    let res = client.try_process_approved_payment(
+        &String::from_str(&env, "season_3"),
+        &String::from_str(&env, "event_3"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &400i128,
+        &purchase_options(&env, &[addons]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::StandingApprovalExceeded)));
+    assert_eq!(
+        client
+            .get_standing_approval(&buyer)
+            .unwrap()
+            .spent_in_period,
+        2000
+    );
+}
+
+#[test]
+fn test_approved_payment_limit_resets_each_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, usdc_id, buyer, _operator) = setup_standing_approval(&env);
+    client.process_approved_payment(
+        &String::from_str(&env, "season_1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &2500i128,
+        &purchase_options(&env, &[]),
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 86_400);
+    client.process_approved_payment(
+        &String::from_str(&env, "season_2"),
+        &String::from_str(&env, "event_2"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &2500i128,
+        &purchase_options(&env, &[]),
+    );
+
+    let approval = client.get_standing_approval(&buyer).unwrap();
+    assert_eq!(approval.period_start, 1_000 + 86_400);
+    assert_eq!(approval.spent_in_period, 2500);
+}
+
+#[test]
+fn test_revoked_standing_approval_rejects_purchases() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, usdc_id, buyer, _operator) = setup_standing_approval(&env);
+    client.revoke_standing_approval(&buyer);
+    assert!(client.get_standing_approval(&buyer).is_none());
+
+    let res = client.try_process_approved_payment(
+        &String::from_str(&env, "season_1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(&env, &[]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::NoStandingApproval)));
+}
+
+#[test]
+fn test_grant_standing_approval_invalid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let operator = Address::generate(&env);
+
+    let res = client.try_grant_standing_approval(&buyer, &operator, &0i128, &86_400);
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidStandingApproval)));
+    let res = client.try_grant_standing_approval(&buyer, &operator, &100i128, &0);
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidStandingApproval)));
+}
+
+#[test]
+fn test_approved_payment_needs_only_operator_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, usdc_id, buyer, operator) = setup_standing_approval(&env);
+
+    let payment_id = String::from_str(&env, "season_1");
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let amount = 1000i128;
+
+    // Only the operator signs; the buyer's funds move via the allowance
+    env.set_auths(&[]);
+    client
+        .mock_auths(&[MockAuth {
+            address: &operator,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "process_approved_payment",
+                args: (
+                    payment_id.clone(),
+                    event_id.clone(),
+                    tier_id.clone(),
+                    buyer.clone(),
+                    usdc_id.clone(),
+                    amount,
+                    purchase_options(&env, &[]),
+                )
+                    .into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .process_approved_payment(
+            &payment_id,
+            &event_id,
+            &tier_id,
+            &buyer,
+            &usdc_id,
+            &amount,
+            &purchase_options(&env, &[]),
+        );
+
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&buyer), 9000);
+}
+
+#[test]
+fn test_refund_payments_returns_full_amount_and_counts_reasons() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+    pay_at(&env, &client, &usdc_id, "p2", 200);
+    pay_at(&env, &client, &usdc_id, "p3", 300);
+
+    let p1 = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap();
+    let p2 = client
+        .get_payment_status(&String::from_str(&env, "p2"))
+        .unwrap();
+    let usdc = token::Client::new(&env, &usdc_id);
+    // Organizer covers the platform fee on refunds it initiates
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&p1.payout_address, &100i128);
+    assert_eq!(client.quote_refund(&p1.payment_id), 1000);
+
+    let event_id = String::from_str(&env, "event_1");
+    client.refund_payments(
+        &event_id,
+        &vec![&env, p1.payment_id.clone(), p2.payment_id.clone()],
+        &RefundReason::Overbooking,
+    );
+
+    assert_eq!(usdc.balance(&p1.buyer_address), 1000);
+    assert_eq!(usdc.balance(&p2.buyer_address), 1000);
+    assert_eq!(usdc.balance(&p1.payout_address), 950 * 3 + 100 - 2000);
+    assert_eq!(
+        client.get_payment_status(&p1.payment_id).unwrap().status,
+        PaymentStatus::Refunded
+    );
+    assert_eq!(
+        client
+            .get_payment_status(&String::from_str(&env, "p3"))
+            .unwrap()
+            .status,
+        PaymentStatus::Pending
+    );
+
+    let stats = client.get_refund_stats(&event_id);
+    assert_eq!(stats.overbooking, 2);
+    assert_eq!(stats.comp_conversion, 0);
+    assert_eq!(client.get_platform_stats().total_refunded, 2000);
+}
+
+#[test]
+fn test_refund_payments_rejects_invalid_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+    let p1 = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap();
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&p1.payout_address, &100i128);
+
+    // Payment from a different event
+    let res = client.try_refund_payments(
+        &String::from_str(&env, "other_event"),
+        &vec![&env, p1.payment_id.clone()],
+        &RefundReason::Other,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PaymentNotRefundable)));
+
+    // Unknown payment
+    let event_id = String::from_str(&env, "event_1");
+    let res = client.try_refund_payments(
+        &event_id,
+        &vec![&env, String::from_str(&env, "missing")],
+        &RefundReason::Other,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PaymentNotFound)));
+
+    // Double refund
+    client.refund_payments(
+        &event_id,
+        &vec![&env, p1.payment_id.clone()],
+        &RefundReason::CompConversion,
+    );
+    let res = client.try_refund_payments(
+        &event_id,
+        &vec![&env, p1.payment_id.clone()],
+        &RefundReason::CompConversion,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PaymentNotRefundable)));
+    assert_eq!(
+        client.try_quote_refund(&p1.payment_id),
+        Err(Ok(TicketPaymentError::PaymentNotRefundable))
+    );
+    assert_eq!(client.get_refund_stats(&event_id).comp_conversion, 1);
+}
+
+#[test]
+fn test_daily_stats_aggregate_sales_and_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let day = 86_400u64;
+    pay_at(&env, &client, &usdc_id, "p1", 20 * day + 10);
+    pay_at(&env, &client, &usdc_id, "p2", 20 * day + 20);
+    pay_at(&env, &client, &usdc_id, "p3", 21 * day + 30);
+
+    let event_id = String::from_str(&env, "event_1");
+    let p1 = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap();
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&p1.payout_address, &1000i128);
+    // Refund lands in the bucket of the day it happens (day 21)
+    client.refund_payments(
+        &event_id,
+        &vec![&env, p1.payment_id.clone()],
+        &RefundReason::Goodwill,
+    );
+
+    let day_20 = client.get_daily_stats(&event_id, &20);
+    assert_eq!(day_20.sales_count, 2);
+    assert_eq!(day_20.gross, 2000);
+    assert_eq!(day_20.fees, 100);
+    assert_eq!(day_20.refunds_count, 0);
+
+    let day_21 = client.get_daily_stats(&event_id, &21);
+    assert_eq!(day_21.sales_count, 1);
+    assert_eq!(day_21.gross, 1000);
+    assert_eq!(day_21.refunds_count, 1);
+    assert_eq!(day_21.refunded, 1000);
+
+    assert_eq!(client.get_daily_stats(&event_id, &22), Default::default());
+}
+
+#[test]
+fn test_buyer_summary_tracks_purchases_and_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &5000i128);
+
+    for (payment_id, amount) in [("b1", 1000i128), ("b2", 1500i128)] {
+        client.process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &u64::MAX,
+        );
+    }
+
+    let summary = client.get_buyer_summary(&buyer);
+    assert_eq!(summary.purchase_count, 2);
+    assert_eq!(summary.total_spent, 2500);
+    assert_eq!(summary.active_tickets, 2);
+    assert_eq!(summary.refunds_received, 0);
+
+    let b1 = client
+        .get_payment_status(&String::from_str(&env, "b1"))
+        .unwrap();
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&b1.payout_address, &1000i128);
+    client.refund_payments(
+        &String::from_str(&env, "event_1"),
+        &vec![&env, b1.payment_id],
+        &RefundReason::Other,
+    );
+
+    let summary = client.get_buyer_summary(&buyer);
+    assert_eq!(summary.purchase_count, 2);
+    assert_eq!(summary.active_tickets, 1);
+    assert_eq!(summary.refunds_received, 1000);
+
+    assert_eq!(
+        client.get_buyer_summary(&Address::generate(&env)),
+        Default::default()
+    );
+}
+
+#[test]
+fn test_max_ticket_price_with_event_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &100_000i128);
+    let event_id = String::from_str(&env, "event_1");
+    let gala_id = String::from_str(&env, "gala");
+
+    client.set_max_ticket_price(&5000i128, &0);
+    assert_eq!(client.get_max_ticket_price(&event_id), 5000);
+
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &50_000i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PriceExceedsMaximum)));
+
+    // Admin lifts the cap for a premium event only
+    client.set_event_max_ticket_price(&gala_id, &Some(60_000i128));
+    assert_eq!(client.get_max_ticket_price(&gala_id), 60_000);
+    client.process_payment(
+        &String::from_str(&env, "p2"),
+        &gala_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &50_000i128,
+        &u64::MAX,
+    );
+
+    client.set_event_max_ticket_price(&gala_id, &None);
+    assert_eq!(client.get_max_ticket_price(&gala_id), 5000);
+
+    let res = client.try_set_max_ticket_price(&-1i128, &1);
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidPriceLimit)));
+}
+
+#[test]
+fn test_custodial_payment_records_beneficiary() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let custodian = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&custodian, &1000i128);
+    let beneficiary_id = BytesN::from_array(&env, &[7u8; 32]);
+
+    let pay = |payment_id: &str| {
+        client.try_process_payment_with_options(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &custodian,
+            &usdc_id,
+            &1000i128,
+            &purchase_options(&env, &[PurchaseExtra::Beneficiary(beneficiary_id.clone())]),
+        )
+    };
+
+    assert_eq!(pay("c1"), Err(Ok(TicketPaymentError::NotCustodian)));
+
+    client.set_custodian(&custodian, &true, &0);
+    assert!(client.is_custodian(&custodian));
+    pay("c1").unwrap().unwrap();
+
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "c1"))
+        .unwrap();
+    assert_eq!(payment.buyer_address, custodian);
+    assert_eq!(payment.beneficiary_id, Some(beneficiary_id.clone()));
+
+    client.set_custodian(&custodian, &false, &1);
+    assert!(!client.is_custodian(&custodian));
+}
+
+fn setup_custodial_ticket(
+    env: &Env,
+    client: &TicketPaymentContractClient<'static>,
+    usdc_id: &Address,
+    payment_id: &String,
+) -> Address {
+    let custodian = Address::generate(env);
+    token::StellarAssetClient::new(env, usdc_id).mint(&custodian, &1000i128);
+    client.set_custodian(&custodian, &true, &client.get_admin_nonce());
+    client.process_payment_with_options(
+        payment_id,
+        &String::from_str(env, "event_1"),
+        &String::from_str(env, "tier_1"),
+        &custodian,
+        usdc_id,
+        &1000i128,
+        &purchase_options(
+            env,
+            &[PurchaseExtra::Beneficiary(BytesN::from_array(
+                env, &[7u8; 32],
+            ))],
+        ),
+    );
+    custodian
+}
+
+fn sign_claim(
+    env: &Env,
+    claim_key: &SigningKey,
+    payment_id: &String,
+    new_owner: &Address,
+) -> BytesN<64> {
+    let payload = ClaimPayload {
+        payment_id: payment_id.clone(),
+        new_owner: new_owner.clone(),
+    };
+    let message = payload.to_xdr(env);
+    let mut buf = [0u8; 256];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    BytesN::from_array(env, &claim_key.sign(&buf[..len]).to_bytes())
+}
+
+#[test]
+fn test_claim_custodial_ticket() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let payment_id = String::from_str(&env, "c1");
+    let custodian = setup_custodial_ticket(&env, &client, &usdc_id, &payment_id);
+
+    let claim_key = SigningKey::from_bytes(&[9u8; 32]);
+    client.register_ticket_claim(
+        &payment_id,
+        &BytesN::from_array(&env, &claim_key.verifying_key().to_bytes()),
+        &1000u64,
+    );
+
+    // The signature only moves the ticket to the wallet it names, so a
+    // front-runner can't redirect it, and other keys are refused
+    let owner = Address::generate(&env);
+    let signature = sign_claim(&env, &claim_key, &payment_id, &owner);
+    let attacker = Address::generate(&env);
+    assert!(client
+        .try_claim_ticket(&payment_id, &signature, &attacker)
+        .is_err());
+    let forged = sign_claim(
+        &env,
+        &SigningKey::from_bytes(&[8u8; 32]),
+        &payment_id,
+        &owner,
+    );
+    assert!(client
+        .try_claim_ticket(&payment_id, &forged, &owner)
+        .is_err());
+
+    client.claim_ticket(&payment_id, &signature, &owner);
+
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    assert_eq!(payment.buyer_address, owner);
+    assert_eq!(payment.beneficiary_id, None);
+    assert_eq!(client.get_ticket_claim(&payment_id), None);
+    assert_eq!(client.get_buyer_summary(&owner).active_tickets, 1);
+    assert_eq!(client.get_buyer_summary(&custodian).active_tickets, 0);
+
+    let res = client.try_claim_ticket(&payment_id, &signature, &owner);
+    assert_eq!(res, Err(Ok(TicketPaymentError::ClaimNotFound)));
+}
+
+#[test]
+fn test_organizer_reassigns_expired_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let payment_id = String::from_str(&env, "c1");
+    setup_custodial_ticket(&env, &client, &usdc_id, &payment_id);
+
+    let claim_key = SigningKey::from_bytes(&[9u8; 32]);
+    client.register_ticket_claim(
+        &payment_id,
+        &BytesN::from_array(&env, &claim_key.verifying_key().to_bytes()),
+        &1000u64,
+    );
+
+    let owner = Address::generate(&env);
+    let res = client.try_reassign_unclaimed_ticket(&payment_id, &owner);
+    assert_eq!(res, Err(Ok(TicketPaymentError::ClaimNotExpired)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let signature = sign_claim(&env, &claim_key, &payment_id, &owner);
+    let res = client.try_claim_ticket(&payment_id, &signature, &owner);
+    assert_eq!(res, Err(Ok(TicketPaymentError::ClaimExpired)));
+
+    client.reassign_unclaimed_ticket(&payment_id, &owner);
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    assert_eq!(payment.buyer_address, owner);
+}
+
+#[test]
+fn test_ticket_metadata_frozen_at_purchase() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, registry_id) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+
+    MockEventRegistryClient::new(&env, &registry_id)
+        .set_event_metadata(&String::from_str(&env, "bafy_v2"));
+
+    let metadata = client.get_ticket_metadata(&String::from_str(&env, "p1"));
+    assert_eq!(
+        metadata.frozen_metadata_cid,
+        String::from_str(&env, "bafy_v1")
+    );
+    assert_eq!(
+        metadata.live_metadata_cid,
+        String::from_str(&env, "bafy_v2")
+    );
+
+    let res = client.try_get_ticket_metadata(&String::from_str(&env, "missing"));
+    assert_eq!(res, Err(Ok(TicketPaymentError::PaymentNotFound)));
+}
+
+#[test]
+fn test_validate_purchase_reports_all_violations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &500i128);
+
+    let violations = client.validate_purchase(&event_id, &buyer, &usdc_id, &400i128);
+    assert!(violations.is_empty());
+
+    client.set_event_restricted(&event_id, &true);
+    client.set_max_ticket_price(&300i128, &0);
+    let violations = client.validate_purchase(&event_id, &buyer, &usdc_id, &400i128);
+    assert_eq!(
+        violations,
+        vec![
+            &env,
+            TicketPaymentError::AttestationRequired as u32,
+            TicketPaymentError::PriceExceedsMaximum as u32,
+        ]
+    );
+
+    let violations = client.validate_purchase(&event_id, &buyer, &usdc_id, &0i128);
+    assert!(violations.contains(TicketPaymentError::InvalidAmount as u32));
+
+    client.set_max_ticket_price(&0i128, &1);
+    let violations = client.validate_purchase(&event_id, &buyer, &usdc_id, &1000i128);
+    assert!(violations.contains(TicketPaymentError::InsufficientBalance as u32));
+
+    let other_token = Address::generate(&env);
+    let violations = client.validate_purchase(&event_id, &buyer, &other_token, &100i128);
+    assert!(violations.contains(TicketPaymentError::TokenNotWhitelisted as u32));
+
+    // Nothing was written or transferred
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&buyer), 500i128);
+    assert_eq!(client.get_buyer_summary(&buyer).purchase_count, 0);
+}
+
+#[test]
+fn test_validate_purchase_detailed_context() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &250i128);
+    client.set_max_ticket_price(&300i128, &0);
+
+    let violations = client.validate_purchase_detailed(&event_id, &buyer, &usdc_id, &400i128);
+    assert_eq!(
+        violations,
+        vec![
+            &env,
+            ViolationDetail {
+                code: TicketPaymentError::PriceExceedsMaximum as u32,
+                limit: 300,
+                requested: 400,
+            },
+            ViolationDetail {
+                code: TicketPaymentError::InsufficientBalance as u32,
+                limit: 250,
+                requested: 400,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_cohost_revenue_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, registry_id) = setup_test(&env);
+    let venue = Address::generate(&env);
+    MockEventRegistryClient::new(&env, &registry_id).set_event_cohosts(&vec![
+        &env,
+        event_registry::CoHost {
+            address: venue.clone(),
+            share_bps: 2500,
+            permissions: 0,
+        },
+    ]);
+
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+
+    // 1000 paid, 5% fee -> 950 organizer amount, of which 25% goes to the venue
+    let usdc = token::Client::new(&env, &usdc_id);
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap();
+    assert_eq!(usdc.balance(&platform_wallet), 50);
+    assert_eq!(usdc.balance(&venue), 237);
+    assert_eq!(usdc.balance(&payment.payout_address), 713);
+}
+
+#[test]
+fn test_partner_fee_slice_accrues_and_withdraws() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _) = setup_test(&env);
+    let agency = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+
+    let res = client.try_set_event_partner(
+        &event_id,
+        &Some(PartnerConfig {
+            partner: agency.clone(),
+            share_bps: 0,
+        }),
+        &0,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidPartnerShare)));
+
+    client.set_event_partner(
+        &event_id,
+        &Some(PartnerConfig {
+            partner: agency.clone(),
+            share_bps: 4000,
+        }),
+        &0,
+    );
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+
+    // 5% fee on 1000 = 50, of which 40% (20) is held for the partner
+    let usdc = token::Client::new(&env, &usdc_id);
+    assert_eq!(usdc.balance(&platform_wallet), 30);
+    assert_eq!(client.get_partner_balance(&agency, &usdc_id), 20);
+
+    assert_eq!(client.withdraw_partner_fees(&agency, &usdc_id), 20);
+    assert_eq!(usdc.balance(&agency), 20);
+    assert_eq!(client.get_partner_balance(&agency, &usdc_id), 0);
+    assert_eq!(
+        client.try_withdraw_partner_fees(&agency, &usdc_id),
+        Err(Ok(TicketPaymentError::NothingToWithdraw))
+    );
+
+    client.set_event_partner(&event_id, &None, &1);
+    assert_eq!(client.get_event_partner(&event_id), None);
+}
+
+#[test]
+fn test_fee_rebate_tapers_with_event_age() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, registry_id) = setup_test(&env);
+    let res = client.try_set_rebate_program(
+        &Some(RebateProgram {
+            full_rebate_until: 200,
+            ends_at: 100,
+            max_rebate_bps: 5000,
+        }),
+        &0,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidRebateProgram)));
+    client.set_rebate_program(
+        &Some(RebateProgram {
+            full_rebate_until: 100,
+            ends_at: 200,
+            max_rebate_bps: 5000,
+        }),
+        &0,
+    );
+
+    // Registered halfway through the taper, so half of the 50% maximum applies
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "created_at"), &150u64);
+    });
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+
+    // 5% fee on 1000 = 50, of which 25% (12) is held for the organizer
+    let usdc = token::Client::new(&env, &usdc_id);
+    let payout = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap()
+        .payout_address;
+    assert_eq!(usdc.balance(&platform_wallet), 38);
+    assert_eq!(client.get_rebate_balance(&payout, &usdc_id), 12);
+    assert!(client.check_invariants().is_empty());
+
+    let before = usdc.balance(&payout);
+    assert_eq!(client.claim_fee_rebate(&payout, &usdc_id), 12);
+    assert_eq!(usdc.balance(&payout), before + 12);
+    assert_eq!(
+        client.try_claim_fee_rebate(&payout, &usdc_id),
+        Err(Ok(TicketPaymentError::NothingToWithdraw))
+    );
+
+    // Events registered after the program window earn nothing
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "created_at"), &200u64);
+    });
+    pay_at(&env, &client, &usdc_id, "p2", 0);
+    assert_eq!(usdc.balance(&platform_wallet), 88);
+    assert_eq!(client.get_rebate_balance(&payout, &usdc_id), 0);
+}
+
+#[test]
+fn test_refund_takes_back_accrued_fee_rebate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _) = setup_test(&env);
+    client.set_rebate_program(
+        &Some(RebateProgram {
+            full_rebate_until: 100,
+            ends_at: 200,
+            max_rebate_bps: 5000,
+        }),
+        &0,
+    );
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+    pay_at(&env, &client, &usdc_id, "p2", 0);
+
+    // Each sale holds back half of its 50 fee for the organizer
+    let usdc = token::Client::new(&env, &usdc_id);
+    let payout = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap()
+        .payout_address;
+    assert_eq!(usdc.balance(&platform_wallet), 50);
+    assert_eq!(client.get_rebate_balance(&payout, &usdc_id), 50);
+
+    // Refunding one sale returns its rebate to the platform
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&payout, &100i128);
+    client.refund_payments(
+        &String::from_str(&env, "event_1"),
+        &vec![&env, String::from_str(&env, "p1")],
+        &RefundReason::Other,
+    );
+    assert_eq!(client.get_rebate_balance(&payout, &usdc_id), 25);
+    assert_eq!(usdc.balance(&platform_wallet), 75);
+    assert!(client.check_invariants().is_empty());
+}
+
+#[test]
+fn test_wallet_view_lists_active_tickets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, registry_id) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let usdc = token::StellarAssetClient::new(&env, &usdc_id);
+    usdc.mint(&buyer, &3000i128);
+    for payment_id in ["w1", "w2", "w3"] {
+        client.process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &1000i128,
+            &u64::MAX,
+        );
+    }
+
+    let payout = client
+        .get_payment_status(&String::from_str(&env, "w2"))
+        .unwrap()
+        .payout_address;
+    usdc.mint(&payout, &1000i128);
+    client.refund_payments(
+        &String::from_str(&env, "event_1"),
+        &vec![&env, String::from_str(&env, "w2")],
+        &RefundReason::Goodwill,
+    );
+    MockEventRegistryClient::new(&env, &registry_id)
+        .set_event_metadata(&String::from_str(&env, "bafy_v2"));
+
+    let view = client.get_wallet_view(&buyer);
+    assert_eq!(view.len(), 2);
+    let newest = view.get(0).unwrap();
+    assert_eq!(newest.payment_id, String::from_str(&env, "w3"));
+    assert_eq!(newest.metadata_cid, String::from_str(&env, "bafy_v2"));
+    assert_eq!(
+        newest.purchased_metadata_cid,
+        String::from_str(&env, "bafy_v1")
+    );
+    assert_eq!(newest.status, PaymentStatus::Pending);
+    assert_eq!(
+        view.get(1).unwrap().payment_id,
+        String::from_str(&env, "w1")
+    );
+}
+
+#[test]
+fn test_reserve_accounting_reconciles() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let agency_a = Address::generate(&env);
+    let agency_b = Address::generate(&env);
+    let usdc = token::StellarAssetClient::new(&env, &usdc_id);
+    let buyer = Address::generate(&env);
+    usdc.mint(&buyer, &1_000_000i128);
+
+    let assert_reconciled = || {
+        let reserve = client.get_reserve_accounting(&usdc_id);
+        assert_eq!(reserve.balance, reserve.partner_fees_owed);
+        assert_eq!(reserve.surplus, 0);
+    };
+    assert_reconciled();
+
+    let events = ["ev_a", "ev_b", "ev_c"];
+    client.set_event_partner(
+        &String::from_str(&env, events[0]),
+        &Some(PartnerConfig {
+            partner: agency_a.clone(),
+            share_bps: 3333,
+        }),
+        &0,
+    );
+    client.set_event_partner(
+        &String::from_str(&env, events[1]),
+        &Some(PartnerConfig {
+            partner: agency_b.clone(),
+            share_bps: 10000,
+        }),
+        &1,
+    );
+
+    let payment_ids = [
+        "r0", "r1", "r2", "r3", "r4", "r5", "r6", "r7", "r8", "r9", "r10", "r11",
+    ];
+    for (i, payment_id) in payment_ids.iter().enumerate() {
+        client.process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, events[i % 3]),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &(997i128 + i as i128 * 131),
+            &u64::MAX,
+        );
+        assert_reconciled();
+        if i == 5 {
+            client.withdraw_partner_fees(&agency_a, &usdc_id);
+            assert_reconciled();
+        }
+    }
+
+    client.withdraw_partner_fees(&agency_a, &usdc_id);
+    client.withdraw_partner_fees(&agency_b, &usdc_id);
+    assert_reconciled();
+    assert_eq!(client.get_reserve_accounting(&usdc_id).balance, 0);
+}
+
+#[test]
+fn test_build_info() {
+    let env = Env::default();
+    let (client, _admin, _, _, _) = setup_test(&env);
+
+    let info = client.get_build_info();
+    assert_eq!(
+        info.version,
+        String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    );
+    assert!(!info.git_commit.is_empty());
+}
+
+#[test]
+fn test_rounding_beneficiary_reconciles_every_unit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _) = setup_test(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10_000i128);
+    assert_eq!(
+        client.get_rounding_beneficiary(),
+        RoundingBeneficiary::Organizer
+    );
+
+    // 5% of 1019 = 50.95: the three modes route the remainder differently
+    let cases = [
+        ("p_org", RoundingBeneficiary::Organizer, 50, 969, 0),
+        ("p_plat", RoundingBeneficiary::Platform, 51, 968, 0),
+        ("p_dust", RoundingBeneficiary::DustBucket, 50, 968, 1),
+    ];
+    for (nonce, (payment_id, beneficiary, fee, organizer, dust)) in (0u64..).zip(cases) {
+        client.set_rounding_beneficiary(&beneficiary, &nonce);
+        let payment_id = String::from_str(&env, payment_id);
+        client.process_payment(
+            &payment_id,
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &1019i128,
+            &u64::MAX,
+        );
+        let payment = client.get_payment_status(&payment_id).unwrap();
+        assert_eq!(payment.platform_fee, fee);
+        assert_eq!(payment.organizer_amount, organizer);
+        assert_eq!(payment.rounding_dust, dust);
+        assert_eq!(
+            payment.platform_fee + payment.organizer_amount + payment.rounding_dust,
+            payment.amount
+        );
+    }
+
+    assert_eq!(usdc.balance(&platform_wallet), 50 + 51 + 50);
+    assert_eq!(client.get_dust_balance(&usdc_id), 1);
+    assert_eq!(client.get_reserve_accounting(&usdc_id).surplus, 0);
+
+    assert_eq!(client.sweep_dust(&usdc_id), 1);
+    assert_eq!(usdc.balance(&platform_wallet), 152);
+    assert_eq!(client.get_reserve_accounting(&usdc_id).balance, 0);
+}
+
+#[test]
+fn test_burn_ticket_removes_it_from_wallet() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+    let p1 = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap();
+    assert_eq!(
+        client.get_buyer_summary(&p1.buyer_address).active_tickets,
+        1
+    );
+
+    client.burn_ticket(&p1.payment_id);
+    assert_eq!(
+        client.get_payment_status(&p1.payment_id).unwrap().status,
+        PaymentStatus::Burned
+    );
+    let sales = client.get_event_sales(&p1.event_id);
+    assert_eq!((sales.tickets_sold, sales.active_tickets), (1, 0));
+    assert_eq!(sales.gross, 1000);
+    assert_eq!(
+        client.get_buyer_summary(&p1.buyer_address).active_tickets,
+        0
+    );
+    assert_eq!(client.get_wallet_view(&p1.buyer_address).len(), 0);
+
+    // A burned ticket can be neither burned again nor refunded
+    assert_eq!(
+        client.try_burn_ticket(&p1.payment_id),
+        Err(Ok(TicketPaymentError::TicketNotActive))
+    );
+    let res = client.try_refund_payments(
+        &p1.event_id,
+        &vec![&env, p1.payment_id.clone()],
+        &RefundReason::Other,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PaymentNotRefundable)));
+}
+
+#[test]
+fn test_process_payment_rejects_malformed_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+
+    let valid = String::from_str(&env, "event_1");
+    let too_long = String::from_str(
+        &env,
+        "p12345678901234567890123456789012345678901234567890123456789012345",
+    );
+    for (payment_id, event_id) in [
+        (String::from_str(&env, ""), valid.clone()),
+        (String::from_str(&env, "pay 1"), valid.clone()),
+        (too_long, valid.clone()),
+        (
+            String::from_str(&env, "p1"),
+            String::from_str(&env, "event;1"),
+        ),
+    ] {
+        let res = client.try_process_payment(
+            &payment_id,
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &100i128,
+            &u64::MAX,
+        );
+        assert_eq!(res, Err(Ok(TicketPaymentError::InvalidIdentifier)));
+    }
+
+    let violations = client.validate_purchase(
+        &String::from_str(&env, "event 1"),
+        &buyer,
+        &usdc_id,
+        &100i128,
+    );
+    assert!(violations.contains(TicketPaymentError::InvalidIdentifier as u32));
+    assert_eq!(client.get_buyer_summary(&buyer).purchase_count, 0);
+}
+
+#[test]
+fn test_purchase_limits_reject_dust_and_oversized_purchases() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10_000i128);
+
+    assert_eq!(
+        client.try_set_purchase_limits(&500i128, &100i128, &0),
+        Err(Ok(TicketPaymentError::InvalidPriceLimit))
+    );
+    client.set_purchase_limits(&100i128, &5_000i128, &0);
+    assert_eq!(client.get_purchase_limits().min_ticket_price, 100);
+
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p_dust"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &1i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PriceBelowMinimum)));
+
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p_big"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &5_001i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PurchaseExceedsMaximum)));
+
+    let violations = client.validate_purchase(&event_id, &buyer, &usdc_id, &50i128);
+    assert!(violations.contains(TicketPaymentError::PriceBelowMinimum as u32));
+
+    client.process_payment(
+        &String::from_str(&env, "p_ok"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &5_000i128,
+        &u64::MAX,
+    );
+    assert_eq!(client.get_buyer_summary(&buyer).purchase_count, 1);
+}
+
+#[test]
+fn test_pending_migration_blocks_purchases_until_on_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    assert_eq!(
+        client.try_on_upgrade(&1),
+        Err(Ok(TicketPaymentError::InvalidMigration))
+    );
+
+    // Simulate the state `upgrade_with_migration` leaves behind
+    env.as_contract(&client.address, || set_pending_migration(&env, Some(1)));
+    assert_eq!(client.get_migration_state(), (0, Some(1)));
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &100i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::MigrationPending)));
+    assert_eq!(
+        client.validate_purchase(
+            &String::from_str(&env, "event_1"),
+            &buyer,
+            &usdc_id,
+            &100i128
+        ),
+        vec![&env, TicketPaymentError::MigrationPending as u32]
+    );
+
+    assert_eq!(
+        client.try_on_upgrade(&2),
+        Err(Ok(TicketPaymentError::InvalidMigration))
+    );
+    client.on_upgrade(&1);
+    assert_eq!(client.get_migration_state(), (1, None));
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+}
+
+#[test]
+fn test_tenant_accepted_tokens_narrow_the_whitelist() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc_id, _, registry_id) = setup_test(&env);
+    let other_token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.add_token(&other_token);
+    MockEventRegistryClient::new(&env, &registry_id).set_accepted_tokens(&vec![&env, other_token]);
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+    let event_id = String::from_str(&env, "acme:event_1");
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &100i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::TokenNotWhitelisted)));
+    assert!(client
+        .validate_purchase(&event_id, &buyer, &usdc_id, &100i128)
+        .contains(TicketPaymentError::TokenNotWhitelisted as u32));
+}
+
+#[test]
+fn test_get_config_resolves_every_setting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TicketPaymentContract, ());
+    let uninitialized = TicketPaymentContractClient::new(&env, &contract_id);
+    assert_eq!(
+        uninitialized.try_get_config(),
+        Err(Ok(TicketPaymentError::NotInitialized))
+    );
+
+    let (client, admin, usdc_id, platform_wallet, registry_id) = setup_test(&env);
+    client.set_max_ticket_price(&5_000i128, &0);
+    client.set_rounding_beneficiary(&RoundingBeneficiary::Platform, &1);
+
+    let config = client.get_config();
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.usdc_token, usdc_id);
+    assert_eq!(config.platform_wallet, platform_wallet);
+    assert_eq!(config.event_registry, registry_id);
+    assert_eq!(config.max_ticket_price, 5_000);
+    assert_eq!(config.rounding_beneficiary, RoundingBeneficiary::Platform);
+    assert_eq!(config.attestor, None);
+    assert_eq!(config.pending_migration, None);
+    assert_eq!(config.build, client.get_build_info());
+}
+
+#[test]
+fn test_hooks_are_notified_and_failures_ignored() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let failing = env.register(FailingHook, ());
+    let recording = env.register(RecordingHook, ());
+    client.add_hook(&failing, &0);
+    client.add_hook(&recording, &1);
+    client.add_hook(&recording, &2);
+    assert_eq!(client.get_hooks().len(), 2);
+
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+    let hook = RecordingHookClient::new(&env, &recording);
+    let last = hook.last().unwrap();
+    assert_eq!(last.kind, HookKind::Purchase);
+    assert_eq!(last.payment_id, String::from_str(&env, "p1"));
+    assert_eq!(last.amount, 1000);
+
+    let p1 = client.get_payment_status(&last.payment_id).unwrap();
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&p1.payout_address, &100i128);
+    client.refund_payments(
+        &p1.event_id,
+        &vec![&env, p1.payment_id.clone()],
+        &RefundReason::Goodwill,
+    );
+    assert_eq!(hook.last().unwrap().kind, HookKind::Refund);
+
+    client.remove_hook(&failing);
+    assert_eq!(client.get_hooks(), vec![&env, recording]);
+    for nonce in 3..7 {
+        client.add_hook(&env.register(RecordingHook, ()), &nonce);
+    }
+    assert_eq!(
+        client.try_add_hook(&env.register(RecordingHook, ()), &7),
+        Err(Ok(TicketPaymentError::TooManyHooks))
+    );
+}
+
+#[test]
+fn test_receipt_verifies_until_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+    let payment_id = String::from_str(&env, "p1");
+    let receipt = client.get_receipt(&payment_id);
+    assert_eq!(receipt.issuer, client.address);
+    assert_eq!(receipt.amount, 1000);
+    assert!(client.verify_receipt(&receipt));
+
+    let mut forged = receipt.clone();
+    forged.amount = 10;
+    assert!(!client.verify_receipt(&forged));
+    assert_eq!(
+        client.try_get_receipt(&String::from_str(&env, "missing")),
+        Err(Ok(TicketPaymentError::PaymentNotFound))
+    );
+
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&payment.payout_address, &100i128);
+    client.refund_payments(
+        &payment.event_id,
+        &vec![&env, payment_id],
+        &RefundReason::Other,
+    );
+    assert!(!client.verify_receipt(&receipt));
+}
+
+#[test]
+fn test_withholding_routes_organizer_share_to_compliance_wallet() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _) = setup_test(&env);
+    let compliance = Address::generate(&env);
+    pay_at(&env, &client, &usdc_id, "p0", 0);
+    let organizer = client
+        .get_payment_status(&String::from_str(&env, "p0"))
+        .unwrap()
+        .payout_address;
+
+    assert_eq!(
+        client.try_set_withholding(
+            &organizer,
+            &Some(WithholdingConfig {
+                bps: 10001,
+                wallet: compliance.clone(),
+            }),
+            &0,
+        ),
+        Err(Ok(TicketPaymentError::InvalidWithholding))
+    );
+    client.set_withholding(
+        &organizer,
+        &Some(WithholdingConfig {
+            bps: 1000,
+            wallet: compliance.clone(),
+        }),
+        &0,
+    );
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+
+    // 1000 - 5% fee = 950 for the organizer, 10% of which (95) is withheld
+    let usdc = token::Client::new(&env, &usdc_id);
+    assert_eq!(usdc.balance(&compliance), 95);
+    assert_eq!(usdc.balance(&organizer), 950 + 855);
+    assert_eq!(usdc.balance(&platform_wallet), 100);
+
+    client.set_withholding(&organizer, &None, &1);
+    assert_eq!(client.get_withholding(&organizer), None);
+    pay_at(&env, &client, &usdc_id, "p2", 0);
+    assert_eq!(usdc.balance(&compliance), 95);
+}
+
+#[test]
+fn test_refund_redirect_applies_after_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 1_000);
+    pay_at(&env, &client, &usdc_id, "p2", 1_000);
+    let p1 = String::from_str(&env, "p1");
+    let p2 = String::from_str(&env, "p2");
+    let payment = client.get_payment_status(&p1).unwrap();
+    let new_wallet = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_refund_address(&p1, &Some(payment.buyer_address.clone())),
+        Err(Ok(TicketPaymentError::InvalidRefundAddress))
+    );
+    client.set_refund_address(&p1, &Some(new_wallet.clone()));
+    client.set_refund_address(&p2, &Some(new_wallet.clone()));
+    let redirect = client.get_refund_address(&p1).unwrap();
+    assert_eq!(redirect.effective_at, 1_000 + REFUND_REDIRECT_COOLDOWN);
+
+    // Within the cooldown the refund still goes to the buyer
+    let usdc = token::Client::new(&env, &usdc_id);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&payment.payout_address, &2000i128);
+    client.refund_payments(
+        &payment.event_id,
+        &vec![&env, p1.clone()],
+        &RefundReason::Other,
+    );
+    assert_eq!(usdc.balance(&payment.buyer_address), 1000);
+    assert_eq!(usdc.balance(&new_wallet), 0);
+    assert_eq!(client.get_refund_address(&p1), None);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + REFUND_REDIRECT_COOLDOWN);
+    client.refund_payments(&payment.event_id, &vec![&env, p2], &RefundReason::Other);
+    assert_eq!(usdc.balance(&new_wallet), 1000);
+}
+
+#[test]
+fn test_compute_fee_covers_every_strategy() {
+    let env = Env::default();
+    let tiers = vec![
+        &env,
+        FeeTier {
+            min_amount: 100,
+            bps: 500,
+        },
+        FeeTier {
+            min_amount: 1000,
+            bps: 250,
+        },
+    ];
+
+    assert_eq!(compute_fee(&FeeStrategy::FlatBps(500), 1000), (50, false));
+    assert_eq!(compute_fee(&FeeStrategy::FlatBps(500), 1001), (50, true));
+    assert_eq!(compute_fee(&FeeStrategy::FlatBps(0), 1000), (0, false));
+    assert_eq!(
+        compute_fee(&FeeStrategy::FlatBps(10000), 1000),
+        (1000, false)
+    );
+
+    let tiered = FeeStrategy::Tiered(tiers);
+    assert_eq!(compute_fee(&tiered, 99), (0, false));
+    assert_eq!(compute_fee(&tiered, 100), (5, false));
+    assert_eq!(compute_fee(&tiered, 999), (49, true));
+    assert_eq!(compute_fee(&tiered, 1000), (25, false));
+
+    assert_eq!(
+        compute_fee(&FeeStrategy::PerTicketFlat(30), 1000),
+        (30, false)
+    );
+    assert_eq!(
+        compute_fee(&FeeStrategy::PerTicketFlat(30), 20),
+        (20, false)
+    );
+
+    assert_eq!(
+        compute_fee(&FeeStrategy::Hybrid(250, 10), 1000),
+        (35, false)
+    );
+    assert_eq!(compute_fee(&FeeStrategy::Hybrid(250, 10), 1001), (35, true));
+    assert_eq!(
+        compute_fee(&FeeStrategy::Hybrid(9000, 500), 1000),
+        (1000, false)
+    );
+}
+
+#[test]
+fn test_event_fee_strategy_overrides_platform_and_registry_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _) = setup_test(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let usdc = token::Client::new(&env, &usdc_id);
+
+    assert_eq!(
+        client.try_set_fee_strategy(&Some(FeeStrategy::FlatBps(10001)), &0),
+        Err(Ok(TicketPaymentError::InvalidFeeStrategy))
+    );
+    let unordered = vec![
+        &env,
+        FeeTier {
+            min_amount: 500,
+            bps: 100,
+        },
+        FeeTier {
+            min_amount: 500,
+            bps: 200,
+        },
+    ];
+    assert_eq!(
+        client.try_set_fee_strategy(&Some(FeeStrategy::Tiered(unordered)), &0),
+        Err(Ok(TicketPaymentError::InvalidFeeStrategy))
+    );
+    assert_eq!(client.get_fee_strategy(&event_id), None);
+
+    client.set_fee_strategy(&Some(FeeStrategy::PerTicketFlat(20)), &0);
+    assert_eq!(
+        client.try_set_fee_strategy(&None, &0),
+        Err(Ok(TicketPaymentError::InvalidNonce))
+    );
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+    assert_eq!(usdc.balance(&platform_wallet), 20);
+
+    client.set_event_fee_strategy(&event_id, &Some(FeeStrategy::Hybrid(100, 5)), &1);
+    assert_eq!(
+        client.get_fee_strategy(&event_id),
+        Some(FeeStrategy::Hybrid(100, 5))
+    );
+    pay_at(&env, &client, &usdc_id, "p2", 0);
+    assert_eq!(usdc.balance(&platform_wallet), 20 + 15);
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "p2"))
+        .unwrap();
+    assert_eq!(payment.platform_fee, 15);
+    assert_eq!(payment.organizer_amount, 985);
+
+    // Clearing both overrides falls back to the registry's 5%
+    client.set_event_fee_strategy(&event_id, &None, &2);
+    client.set_fee_strategy(&None, &3);
+    pay_at(&env, &client, &usdc_id, "p3", 0);
+    assert_eq!(usdc.balance(&platform_wallet), 35 + 50);
+}
+
+#[test]
+fn test_platform_fee_strategy_yields_to_tenant_or_plan_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, registry_id) = setup_test(&env);
+    let event_id = String::from_str(&env, "event_1");
+    MockEventRegistryClient::new(&env, &registry_id).set_custom_fee(&true);
+
+    // The registry's tenant or plan fee of 5% wins over the platform default
+    client.set_fee_strategy(&Some(FeeStrategy::PerTicketFlat(20)), &0);
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap();
+    assert_eq!(payment.platform_fee, 50);
+
+    // An event's own strategy still overrides it
+    client.set_event_fee_strategy(&event_id, &Some(FeeStrategy::Hybrid(100, 5)), &1);
+    pay_at(&env, &client, &usdc_id, "p2", 0);
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "p2"))
+        .unwrap();
+    assert_eq!(payment.platform_fee, 15);
+}
+
+#[test]
+fn test_attributed_payment_stores_campaign_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+    let campaign = BytesN::from_array(&env, &[9; 32]);
+    let payment_id = String::from_str(&env, "p1");
+
+    client.process_payment_with_options(
+        &payment_id,
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(&env, &[PurchaseExtra::Attribution(campaign.clone())]),
+    );
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    assert_eq!(payment.attribution, Some(campaign));
+
+    pay_at(&env, &client, &usdc_id, "p2", 0);
+    let unattributed = client
+        .get_payment_status(&String::from_str(&env, "p2"))
+        .unwrap();
+    assert_eq!(unattributed.attribution, None);
+}
+
+#[test]
+fn test_pending_actions_list_migration_and_dust() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    assert!(client.get_pending_actions().is_empty());
+
+    client.set_rounding_beneficiary(&RoundingBeneficiary::DustBucket, &0);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &999i128);
+    client.process_payment(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &999i128,
+        &u64::MAX,
+    );
+    env.as_contract(&client.address, || set_pending_migration(&env, Some(1)));
+    assert_eq!(
+        client.get_pending_actions(),
+        vec![
+            &env,
+            PendingAction::Migration(1),
+            PendingAction::DustSweep(usdc_id.clone(), 1),
+        ]
+    );
+
+    client.on_upgrade(&1);
+    client.sweep_dust(&usdc_id);
+    assert!(client.get_pending_actions().is_empty());
+}
+
+#[test]
+fn test_redact_payment_keeps_financials() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+    let payment_id = String::from_str(&env, "p1");
+    client.process_payment_with_options(
+        &payment_id,
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(
+            &env,
+            &[PurchaseExtra::Attribution(BytesN::from_array(
+                &env, &[9; 32],
+            ))],
+        ),
+    );
+    client.confirm_payment(
+        &payment_id,
+        &String::from_str(&env, "memo: seat near aisle"),
+    );
+    let receipt = client.get_receipt(&payment_id);
+    let before = client.get_payment_status(&payment_id).unwrap();
+    let totals = client.get_platform_stats();
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.redact_payment(&payment_id);
+
+    let after = client.get_payment_status(&payment_id).unwrap();
+    assert_eq!(after.transaction_hash, String::from_str(&env, ""));
+    assert_eq!(after.attribution, None);
+    assert_eq!(after.redacted_at, Some(500));
+    assert_eq!(after.amount, before.amount);
+    assert_eq!(after.platform_fee, before.platform_fee);
+    assert_eq!(after.status, before.status);
+    assert_eq!(client.get_platform_stats(), totals);
+    assert!(client.verify_receipt(&receipt));
+
+    assert_eq!(
+        client.try_redact_payment(&payment_id),
+        Err(Ok(TicketPaymentError::AlreadyRedacted))
+    );
+    assert_eq!(
+        client.try_redact_payment(&String::from_str(&env, "missing")),
+        Err(Ok(TicketPaymentError::PaymentNotFound))
+    );
+}
+
+#[test]
+fn test_preflight_purchase_counts_rejections_by_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &500i128);
+
+    assert!(client
+        .preflight_purchase(&event_id, &buyer, &usdc_id, &400i128)
+        .is_empty());
+    assert!(client.get_rejection_stats(&event_id).is_empty());
+
+    client.set_max_ticket_price(&300i128, &0);
+    client.preflight_purchase(&event_id, &buyer, &usdc_id, &400i128);
+    let other_buyer = Address::generate(&env);
+    client.preflight_purchase(&event_id, &other_buyer, &usdc_id, &1000i128);
+
+    // Repeating a buyer's preflight the same day isn't counted again
+    assert_eq!(
+        client
+            .preflight_purchase(&event_id, &buyer, &usdc_id, &1000i128)
+            .len(),
+        2
+    );
+    let stats = client.get_rejection_stats(&event_id);
+    assert_eq!(
+        stats.get(TicketPaymentError::PriceExceedsMaximum as u32),
+        Some(2)
+    );
+    assert_eq!(
+        stats.get(TicketPaymentError::InsufficientBalance as u32),
+        Some(1)
+    );
+    assert!(client
+        .get_rejection_stats(&String::from_str(&env, "event_2"))
+        .is_empty());
+}
+
+#[test]
+fn test_settlement_event_itemizes_every_leg() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, registry_id) = setup_test(&env);
+    let venue = Address::generate(&env);
+    MockEventRegistryClient::new(&env, &registry_id).set_event_cohosts(&vec![
+        &env,
+        event_registry::CoHost {
+            address: venue.clone(),
+            share_bps: 2000,
+            permissions: 0,
+        },
+    ]);
+    pay_at(&env, &client, &usdc_id, "p0", 0);
+    let organizer = client
+        .get_payment_status(&String::from_str(&env, "p0"))
+        .unwrap()
+        .payout_address;
+    let compliance = Address::generate(&env);
+    client.set_withholding(
+        &organizer,
+        &Some(WithholdingConfig {
+            bps: 1000,
+            wallet: compliance.clone(),
+        }),
+        &0,
+    );
+
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+
+    // 1000 paid: 50 fee, 950 organizer amount -> 190 venue, 76 withheld, 684 payout
+    let usdc = token::Client::new(&env, &usdc_id);
+    assert_eq!(usdc.balance(&compliance), 76);
+
+    let events = env.events().all();
+    let settled = events.iter().find_map(|e| {
+        let event: PaymentSettledEvent = e.2.try_into_val(&env).ok()?;
+        (event.payment_id == String::from_str(&env, "p1")).then_some(event)
+    });
+    if let Some(settled) = settled {
+        assert_eq!(settled.token_address, usdc_id);
+        assert_eq!(
+            settled.legs,
+            vec![
+                &env,
+                SettlementLeg {
+                    kind: SettlementLegKind::Platform,
+                    recipient: platform_wallet,
+                    amount: 50,
+                },
+                SettlementLeg {
+                    kind: SettlementLegKind::CoHost,
+                    recipient: venue,
+                    amount: 190,
+                },
+                SettlementLeg {
+                    kind: SettlementLegKind::Withholding,
+                    recipient: compliance,
+                    amount: 76,
+                },
+                SettlementLeg {
+                    kind: SettlementLegKind::Organizer,
+                    recipient: organizer,
+                    amount: 684,
+                },
+            ]
+        );
+        let total: i128 = settled.legs.iter().map(|leg| leg.amount).sum();
+        assert_eq!(total, settled.amount);
+    }
+}
+
+fn sign_quote(env: &Env, signing_key: &SigningKey, payload: &QuotePayload) -> PriceQuote {
+    let message = payload.clone().to_xdr(env);
+    let mut buf = [0u8; 512];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+
+    PriceQuote {
+        price: payload.price,
+        expires_at: payload.expires_at,
+        signature: BytesN::from_array(env, &signing_key.sign(&buf[..len]).to_bytes()),
+    }
+}
+
+#[test]
+fn test_quoted_payment_honors_signed_price_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let signing_key = SigningKey::from_bytes(&[5u8; 32]);
+    let payload = QuotePayload {
+        buyer_address: buyer.clone(),
+        event_id: event_id.clone(),
+        ticket_tier_id: tier_id.clone(),
+        token_address: usdc_id.clone(),
+        price: 200,
+        expires_at: 1_600,
+    };
+    let quote = sign_quote(&env, &signing_key, &payload);
+
+    let res = client.try_process_payment_with_options(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &quote.price,
+        &purchase_options(&env, &[PurchaseExtra::Quote(quote.clone())]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::QuoteKeyNotSet)));
+    client.set_quote_key(
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+        &0,
+    );
+
+    // The promo price sits below the platform minimum, which the quote overrides
+    client.set_purchase_limits(&500i128, &0i128, &1);
+    client.process_payment_with_options(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &quote.price,
+        &purchase_options(&env, &[PurchaseExtra::Quote(quote.clone())]),
+    );
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "p1"))
+        .unwrap();
+    assert_eq!(payment.amount, 200);
+
+    let res = client.try_process_payment_with_options(
+        &String::from_str(&env, "p2"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &quote.price,
+        &purchase_options(&env, &[PurchaseExtra::Quote(quote.clone())]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::QuoteAlreadyUsed)));
+
+    let mut discounted = sign_quote(&env, &signing_key, &payload);
+    discounted.price = 1;
+    let res = client.try_process_payment_with_options(
+        &String::from_str(&env, "p3"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &discounted.price,
+        &purchase_options(&env, &[PurchaseExtra::Quote(discounted.clone())]),
+    );
+    assert!(res.is_err());
+
+    let long_lived = sign_quote(
+        &env,
+        &signing_key,
+        &QuotePayload {
+            expires_at: 1_000 + MAX_QUOTE_SECS + 1,
+            ..payload
+        },
+    );
+    let res = client.try_process_payment_with_options(
+        &String::from_str(&env, "p4"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &long_lived.price,
+        &purchase_options(&env, &[PurchaseExtra::Quote(long_lived.clone())]),
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::InvalidQuoteExpiry)));
+}
+
+#[test]
+fn test_guardian_pause_blocks_purchases_until_admin_unpauses() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc_id, _, _) = setup_test(&env);
+    let guardian = Address::generate(&env);
+    assert_eq!(
+        client.try_pause(&guardian),
+        Err(Ok(TicketPaymentError::NotGuardian))
+    );
+    client.set_guardian(&Some(guardian.clone()), &0);
+    client.pause(&guardian);
+    assert!(client.is_paused());
+    assert!(client.get_config().paused);
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::ContractPaused)));
+    assert_eq!(
+        client.validate_purchase(
+            &String::from_str(&env, "event_1"),
+            &buyer,
+            &usdc_id,
+            &1000i128
+        ),
+        vec![&env, TicketPaymentError::ContractPaused as u32]
+    );
+
+    client.unpause();
+    assert!(!client.is_paused());
+    pay_at(&env, &client, &usdc_id, "p1", 0);
+
+    // The admin can pause without a guardian appointed
+    assert_eq!(
+        client.try_set_guardian(&Some(guardian.clone()), &0),
+        Err(Ok(TicketPaymentError::InvalidNonce))
+    );
+    client.set_guardian(&None, &1);
+    assert_eq!(client.get_admin_nonce(), 2);
+    client.pause(&admin);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_guardian_freeze_expires_unless_escalated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let guardian = Address::generate(&env);
+    client.set_guardian(&Some(guardian.clone()), &0);
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+    pay_at(&env, &client, &usdc_id, "p2", 100);
+    let p1 = String::from_str(&env, "p1");
+    let p2 = String::from_str(&env, "p2");
+
+    client.freeze_payment(&guardian, &p1);
+    let freeze = client.get_payment_freeze(&p1).unwrap();
+    assert_eq!(freeze.expires_at, 100 + GUARDIAN_FREEZE_SECS);
+    assert!(!freeze.escalated);
+    assert_eq!(
+        client.try_burn_ticket(&p1),
+        Err(Ok(TicketPaymentError::PaymentFrozen))
+    );
+    assert_eq!(
+        client.try_escalate_freeze(&p2),
+        Err(Ok(TicketPaymentError::PaymentNotFrozen))
+    );
+
+    // An unescalated freeze lapses on its own
+    env.ledger()
+        .with_mut(|li| li.timestamp = 100 + GUARDIAN_FREEZE_SECS);
+    assert_eq!(client.get_payment_freeze(&p1), None);
+    client.burn_ticket(&p1);
+
+    // An escalated one holds until the admin lifts it, and the guardian
+    // can't overwrite it with a shorter freeze
+    client.freeze_payment(&guardian, &p2);
+    client.escalate_freeze(&p2);
+    assert_eq!(
+        client.try_freeze_payment(&guardian, &p2),
+        Err(Ok(TicketPaymentError::PaymentFrozen))
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp += 10 * GUARDIAN_FREEZE_SECS);
+    assert!(client.get_payment_freeze(&p2).unwrap().escalated);
+    assert_eq!(
+        client.try_burn_ticket(&p2),
+        Err(Ok(TicketPaymentError::PaymentFrozen))
+    );
+    client.unfreeze_payment(&p2);
+    client.burn_ticket(&p2);
+}
+
+#[test]
+fn test_check_invariants_reports_each_broken_invariant() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    pay_at(&env, &client, &usdc_id, "p1", 100);
+    assert_eq!(client.check_invariants().len(), 0);
+
+    client.set_purchase_limits(&100i128, &5_000i128, &0);
+    client.set_max_ticket_price(&50i128, &1);
+    env.as_contract(&client.address, || {
+        // Dust the contract does not actually hold, and fees above volume
+        set_dust_balance(&env, &usdc_id, 1_000_000);
+        let mut totals = get_payment_totals(&env);
+        totals.total_fees_collected = totals.total_volume + 1;
+        set_payment_totals(&env, &totals);
+    });
+
+    assert_eq!(
+        client.check_invariants(),
+        vec![
+            &env,
+            InvariantViolation::ReserveShortfall,
+            InvariantViolation::FeesExceedVolume,
+            InvariantViolation::LimitsInconsistent,
+        ]
+    );
+}
+
+#[test]
+fn test_process_payment_rejects_expired_authorization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000i128);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &999,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PurchaseExpired)));
+
+    // The deadline itself is still in time
+    client.process_payment(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &1_000,
+    );
+}
+
+#[test]
+fn test_option_and_approved_payments_reject_expired_authorization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, usdc_id, buyer, _operator) = setup_standing_approval(&env);
+    let expired = PurchaseOptions {
+        expires_at: 999,
+        extras: vec![&env],
+    };
+
+    let res = client.try_process_approved_payment(
+        &String::from_str(&env, "season_1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &expired,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PurchaseExpired)));
+    assert_eq!(
+        client
+            .get_standing_approval(&buyer)
+            .unwrap()
+            .spent_in_period,
+        0
+    );
+
+    let res = client.try_process_payment_with_options(
+        &String::from_str(&env, "p1"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &expired,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::PurchaseExpired)));
+}
+
+#[test]
+fn test_promo_codes_are_single_use_and_counted_per_campaign() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let campaign_id = String::from_str(&env, "spring_launch");
+    let code = |value: &str| Bytes::from_slice(&env, value.as_bytes());
+    let hash = |value: &str| -> BytesN<32> { env.crypto().sha256(&code(value)).to_bytes() };
+
+    assert_eq!(
+        client.try_create_promo_codes(&event_id, &campaign_id, &10000, &vec![&env, hash("AAA")]),
+        Err(Ok(TicketPaymentError::InvalidPromoCampaign))
+    );
+    client.create_promo_codes(
+        &event_id,
+        &campaign_id,
+        &2500,
+        &vec![&env, hash("AAA"), hash("BBB")],
+    );
+    // A campaign keeps its discount, and codes can't be issued twice
+    assert_eq!(
+        client.try_create_promo_codes(&event_id, &campaign_id, &5000, &vec![&env, hash("CCC")]),
+        Err(Ok(TicketPaymentError::InvalidPromoCampaign))
+    );
+    assert_eq!(
+        client.try_create_promo_codes(&event_id, &campaign_id, &2500, &vec![&env, hash("BBB")]),
+        Err(Ok(TicketPaymentError::InvalidPromoCampaign))
+    );
+    client.create_promo_codes(&event_id, &campaign_id, &2500, &vec![&env, hash("CCC")]);
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10_000i128);
+    let buy = |payment_id: &str, promo: &str| {
+        client.try_process_payment_with_options(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &1000i128,
+            &purchase_options(&env, &[PurchaseExtra::PromoCode(code(promo))]),
+        )
+    };
+    assert!(buy("p1", "AAA").is_ok());
+    assert_eq!(
+        client
+            .get_payment_status(&String::from_str(&env, "p1"))
+            .unwrap()
+            .amount,
+        750
+    );
+    assert_eq!(
+        buy("p2", "AAA"),
+        Err(Ok(TicketPaymentError::InvalidPromoCode))
+    );
+    assert_eq!(
+        buy("p2", "ZZZ"),
+        Err(Ok(TicketPaymentError::InvalidPromoCode))
+    );
+    assert!(buy("p2", "BBB").is_ok());
+
+    let stats = client.get_campaign_stats(&campaign_id).unwrap();
+    assert_eq!(stats.codes_issued, 3);
+    assert_eq!(stats.redemptions, 2);
+    assert_eq!(stats.discount_given, 500);
+}
+
+#[test]
+fn test_spend_cap_limits_purchases_per_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let parent = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10_000i128);
+    let buy = |payment_id: &str| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &1000i128,
+            &u64::MAX,
+        )
+    };
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_spend_cap(&buyer, &1500i128, &86_400, &Some(parent.clone()));
+    assert!(buy("p1").is_ok());
+    assert_eq!(buy("p2"), Err(Ok(TicketPaymentError::SpendCapExceeded)));
+    assert_eq!(
+        client.validate_purchase_detailed(
+            &String::from_str(&env, "event_1"),
+            &buyer,
+            &usdc_id,
+            &1000i128
+        ),
+        vec![
+            &env,
+            ViolationDetail {
+                code: TicketPaymentError::SpendCapExceeded as u32,
+                limit: 500,
+                requested: 1000,
+            },
+        ]
+    );
+
+    // Once a controller is set, only the controller can loosen the cap
+    client.set_spend_cap(&buyer, &5000i128, &86_400, &Some(parent.clone()));
+    assert_eq!(env.auths()[0].0, parent);
+    assert_eq!(client.get_spend_cap(&buyer).unwrap().spent_in_period, 1000);
+    assert!(buy("p2").is_ok());
+
+    client.set_spend_cap(&buyer, &1000i128, &86_400, &Some(parent.clone()));
+    assert_eq!(buy("p3"), Err(Ok(TicketPaymentError::SpendCapExceeded)));
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert!(buy("p3").is_ok());
+
+    client.remove_spend_cap(&buyer);
+    assert_eq!(env.auths()[0].0, parent);
+    assert_eq!(client.get_spend_cap(&buyer), None);
+}
+
+#[test]
+fn test_questionnaire_commitment_required_and_verifiable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10_000i128);
+    client.set_event_questionnaire(
+        &event_id,
+        &Some(String::from_str(&env, "bafyquestionnaire")),
+    );
+
+    let res = client.try_process_payment(
+        &String::from_str(&env, "p1"),
+        &event_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &u64::MAX,
+    );
+    assert_eq!(res, Err(Ok(TicketPaymentError::QuestionnaireRequired)));
+    assert_eq!(
+        client.validate_purchase(&event_id, &buyer, &usdc_id, &1000i128),
+        vec![&env, TicketPaymentError::QuestionnaireRequired as u32]
+    );
+
+    let answers = Bytes::from_slice(&env, b"salt=42;dietary=vegan");
+    let payment_id = String::from_str(&env, "p1");
+    client.process_payment_with_options(
+        &payment_id,
+        &event_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000i128,
+        &purchase_options(
+            &env,
+            &[PurchaseExtra::Answers(QuestionnaireAnswers {
+                answers_cid: String::from_str(&env, "bafyanswers"),
+                answers_hash: env.crypto().sha256(&answers).to_bytes(),
+            })],
+        ),
+    );
+    assert!(client.verify_questionnaire_answers(&payment_id, &answers));
+    assert!(!client.verify_questionnaire_answers(
+        &payment_id,
+        &Bytes::from_slice(&env, b"salt=42;dietary=none")
+    ));
+
+    // Redaction drops the commitment along with the other personal fields
+    client.redact_payment(&payment_id);
+    assert_eq!(client.get_questionnaire_answers(&payment_id), None);
+    assert!(!client.verify_questionnaire_answers(&payment_id, &answers));
+}
@@ -1,5 +1,6 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec};
 
+/// Lifecycle state of a ticket payment
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PaymentStatus {
@@ -7,8 +8,10 @@ pub enum PaymentStatus {
     Confirmed,
     Refunded,
     Failed,
+    Burned,
 }
 
+/// A ticket purchase as recorded at settlement
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Payment {
@@ -16,24 +19,608 @@ pub struct Payment {
     pub event_id: String,
     pub buyer_address: Address,
     pub ticket_tier_id: String,
-    pub amount: i128, // USDC amount in stroops
+    pub token_address: Address,
+    /// Organizer address that received `organizer_amount`
+    pub payout_address: Address,
+    /// Total charged (ticket + add-ons) in token base units (stroops for USDC)
+    pub amount: i128,
+    /// Platform share of `amount` in token base units
     pub platform_fee: i128,
+    /// Organizer share of `amount` in token base units, before co-host splits
     pub organizer_amount: i128,
     pub status: PaymentStatus,
     pub transaction_hash: String,
+    /// Ledger timestamp (seconds) of the purchase
     pub created_at: u64,
+    /// Ledger timestamp (seconds) of confirmation, if confirmed
     pub confirmed_at: Option<u64>,
+    /// sha256 of the eligibility attestation, if any
+    pub attestation_hash: Option<BytesN<32>>,
+    /// Itemized add-ons included in `amount`
+    pub addons: Vec<AddonLineItem>,
+    /// Hashed user ID for custodial purchases
+    pub beneficiary_id: Option<BytesN<32>>,
+    /// Event metadata CID frozen at purchase
+    pub metadata_cid: String,
+    /// Held for the dust bucket; amount = fee + organizer + dust
+    pub rounding_dust: i128,
+    /// Hashed marketing campaign ID the purchase is attributed to, if any
+    pub attribution: Option<BytesN<32>>,
+    /// Ledger timestamp (seconds) personal fields were redacted, if ever
+    pub redacted_at: Option<u64>,
+}
+
+/// A failed check reported by a dry-run validator, with its context
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ViolationDetail {
+    /// TicketPaymentError code
+    pub code: u32,
+    /// Bound that was hit (price cap, available balance); 0 if n/a
+    pub limit: i128,
+    /// Amount that was checked against `limit`
+    pub requested: i128,
+}
+
+/// One entry of a buyer's "My Tickets" wallet view
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WalletTicket {
+    pub payment_id: String,
+    pub event_id: String,
+    pub ticket_tier_id: String,
+    /// Live event metadata (name, date, venue)
+    pub metadata_cid: String,
+    /// Metadata frozen at purchase
+    pub purchased_metadata_cid: String,
+    /// Pending until confirmed; refunded tickets are omitted
+    pub status: PaymentStatus,
+    /// Ledger timestamp (seconds) of the purchase
+    pub purchased_at: u64,
+}
+
+/// What a ticket was sold as versus what the event currently advertises
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketMetadata {
+    pub frozen_metadata_cid: String,
+    pub live_metadata_cid: String,
+}
+
+/// Add-on requested with a purchase (mirrors the Event Registry type)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddonSelection {
+    pub sku: String,
+    pub quantity: u32,
+}
+
+/// Add-on reserved by the Event Registry, priced at purchase time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddonLineItem {
+    pub sku: String,
+    pub quantity: u32,
+    /// Price of one unit in token base units
+    pub unit_price: i128,
+    /// Price of the whole line in token base units, as priced by the registry
+    pub line_total: i128,
+}
+
+/// Off-chain eligibility (age/region) attestation supplied with a restricted purchase
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseAttestation {
+    /// Ledger timestamp (seconds) after which the attestation is rejected
+    pub expires_at: u64,
+    /// ed25519 signature over AttestationPayload XDR
+    pub signature: BytesN<64>,
+}
+
+/// Message signed by the attestor; binds the attestation to one buyer and event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationPayload {
+    pub buyer_address: Address,
+    pub event_id: String,
+    /// Ledger timestamp (seconds); must match the attestation
+    pub expires_at: u64,
+}
+
+/// Short-lived ticket price signed off-chain by the platform's quote key
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceQuote {
+    /// Ticket price in token base units
+    pub price: i128,
+    /// Ledger timestamp (seconds) after which the quote is rejected
+    pub expires_at: u64,
+    /// ed25519 signature over QuotePayload XDR
+    pub signature: BytesN<64>,
+}
+
+/// Message signed by the quote key; binds a price to one buyer, tier and token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuotePayload {
+    pub buyer_address: Address,
+    pub event_id: String,
+    pub ticket_tier_id: String,
+    pub token_address: Address,
+    pub price: i128,
+    /// Ledger timestamp (seconds); must match the quote
+    pub expires_at: u64,
+}
+
+/// Optional input to a purchase; any combination of kinds may be supplied together
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PurchaseExtra {
+    /// Eligibility (age/region) attestation signed by the attestor key;
+    /// required for restricted events. Only its sha256 is stored.
+    Attestation(PurchaseAttestation),
+    /// Add-ons (parking, merch...) reserved and priced by the Event Registry
+    /// and charged on top of the ticket; the payment itemizes them
+    Addons(Vec<AddonSelection>),
+    /// Single-use promo code; its campaign's discount comes off the ticket price
+    PromoCode(Bytes),
+    /// Commitment to questionnaire answers kept off-chain; required if the
+    /// event has a questionnaire
+    Answers(QuestionnaireAnswers),
+    /// Hash of the marketing campaign (e.g. sha256 of a UTM campaign) the
+    /// sale is attributed to, emitted with `PaymentProcessed`
+    Attribution(BytesN<32>),
+    /// Single-use price signed by the quote key; must equal the ticket
+    /// amount, which then skips the per-ticket price bounds
+    Quote(PriceQuote),
+    /// Hash of the platform user a registered custodian buys and holds the
+    /// ticket for
+    Beneficiary(BytesN<32>),
+}
+
+/// Signed purchase terms beyond the ticket itself
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseOptions {
+    /// Ledger timestamp (seconds) after which the purchase is rejected
+    pub expires_at: u64,
+    /// Optional inputs, each kind at most once
+    pub extras: Vec<PurchaseExtra>,
 }
 
 #[contracttype]
 pub enum DataKey {
-    Payment(String),         // payment_id -> Payment
-    EventPayments(String),   // event_id -> Vec<payment_id>
-    BuyerPayments(Address),  // buyer_address -> Vec<payment_id>
-    Admin,                   // Contract administrator address
-    UsdcToken,               // USDC token address
-    PlatformWallet,          // Platform wallet address
-    EventRegistry,           // Event Registry contract address
-    Initialized,             // Initialization flag
-    TokenWhitelist(Address), // token_address -> bool
+    Payment(String),                         // payment_id -> Payment
+    EventPayments(String),                   // event_id -> Vec<payment_id>
+    BuyerPayments(Address),                  // buyer_address -> Vec<payment_id>
+    Admin,                                   // Contract administrator address
+    UsdcToken,                               // USDC token address
+    PlatformWallet,                          // Platform wallet address
+    EventRegistry,                           // Event Registry contract address
+    Initialized,                             // Initialization flag
+    TokenWhitelist(Address),                 // token_address -> bool
+    DeniedToken(Address),                    // token_address -> bool (never accepted)
+    Attestor,                                // ed25519 public key of the eligibility attestor
+    RestrictedEvent(String),                 // event_id -> bool (purchase requires attestation)
+    EventPaymentsByDay(String, u64),         // (event_id, day) -> Vec<payment_id>
+    PaymentTotals,                           // Running payment counters (PaymentTotals)
+    StandingApproval(Address),               // buyer_address -> StandingApproval
+    RefundReasonCount(String, RefundReason), // (event_id, reason) -> u32
+    EventDailyStats(String, u64),            // (event_id, day) -> DailyStats
+    EventSales(String),                      // event_id -> EventSales
+    PromoCampaign(String),                   // campaign_id -> PromoCampaign
+    PromoCode(BytesN<32>),                   // sha256 of an unredeemed code -> campaign_id
+    SpendCap(Address),                       // buyer_address -> SpendCap
+    Questionnaire(String),                   // event_id -> CID of the purchase questionnaire
+    QuestionnaireAnswers(String),            // payment_id -> QuestionnaireAnswers
+    RebateProgram,                           // Early-organizer RebateProgram
+    RebateBalance(Address, Address),         // (payout_address, token) -> claimable rebate
+    RebatesOwed(Address),                    // token -> sum of all rebate balances
+    BuyerSummary(Address),                   // buyer_address -> BuyerSummary
+    MaxTicketPrice,                          // Platform-wide ticket price cap (0 = none)
+    EventMaxTicketPrice(String),             // event_id -> admin override of the cap
+    PurchaseLimits,                          // Min ticket price and max purchase total
+    Custodian(Address),                      // custodian_address -> bool
+    TicketClaim(String),                     // payment_id -> TicketClaim
+    EventPartner(String),                    // event_id -> PartnerConfig
+    PartnerBalance(Address, Address),        // (partner, token) -> withdrawable fees
+    PartnerFeesOwed(Address),                // token -> sum of all partner balances
+    RoundingBeneficiary,                     // RoundingBeneficiary for fee remainders
+    DustBalance(Address),                    // token -> unswept rounding dust
+    DustTokens,                              // Tokens with a non-zero DustBalance
+    SchemaVersion,                           // Last migration applied to storage
+    PendingMigration,                        // Migration an upgrade is waiting on
+    Hooks,                                   // Admin-approved hook contracts
+    Withholding(Address),                    // payout_address -> WithholdingConfig
+    RefundRedirect(String),                  // payment_id -> RefundRedirect
+    FeeStrategy,                             // Platform-wide default FeeStrategy
+    EventFeeStrategy(String),                // event_id -> FeeStrategy override
+    RejectionStats(String),                  // event_id -> error code -> count (temporary)
+    QuoteKey,                                // ed25519 key that signs price quotes
+    UsedQuote(BytesN<32>),                   // sha256 of a redeemed PriceQuote (temporary)
+    Guardian,                                // Incident-response key that can pause and freeze
+    AdminNonce,                              // Nonce the next upgrade or role grant must carry
+    Paused,                                  // Payments and withdrawals are blocked while set
+    PaymentFreeze(String),                   // payment_id -> PaymentFreeze
+    PreflightCounted(String, Address),       // (event_id, buyer) preflight counted (temporary)
+    PaymentRebate(String),                   // payment_id -> fee rebate the sale accrued
+}
+
+/// Price band of a tiered fee: purchases of at least `min_amount` pay `bps`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    /// Lower bound of the band in token base units
+    pub min_amount: i128,
+    /// Fee in basis points for purchases in the band
+    pub bps: u32,
+}
+
+/// How the platform fee on a purchase is computed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeStrategy {
+    /// Basis points of the purchase amount
+    FlatBps(u32),
+    /// Basis points of the highest band the amount reaches; no fee below all bands
+    Tiered(Vec<FeeTier>),
+    /// Fixed fee per ticket in token base units
+    PerTicketFlat(i128),
+    /// Basis points of the amount plus a fixed fee in token base units
+    Hybrid(u32, i128),
+}
+
+/// Platform-wide bounds on purchase amounts (0 = no bound)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PurchaseLimits {
+    /// Rejects dust tickets that cost more in rent than they earn
+    pub min_ticket_price: i128,
+    /// Sanity cap on ticket + add-ons in one purchase
+    pub max_purchase_amount: i128,
+}
+
+/// Funds held by the contract versus what it owes, for one token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveAccounting {
+    pub token_address: Address,
+    /// Actual token balance of the contract
+    pub balance: i128,
+    /// Partner fees accrued and not yet withdrawn
+    pub partner_fees_owed: i128,
+    /// Rounding dust awaiting a sweep to the platform
+    pub dust_owed: i128,
+    /// Fee rebates accrued to organizers and not yet claimed
+    pub rebates_owed: i128,
+    /// balance - obligations; negative means a shortfall
+    pub surplus: i128,
+}
+
+/// Broken invariant reported by `check_invariants`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum InvariantViolation {
+    /// A token balance doesn't cover the partner fees and dust held in it
+    ReserveShortfall = 1,
+    /// A partner fee or dust ledger went negative
+    NegativeObligation = 2,
+    /// Recorded fees exceed recorded volume
+    FeesExceedVolume = 3,
+    /// Recorded refunds exceed recorded volume
+    RefundsExceedVolume = 4,
+    /// Minimum ticket price is above the price cap or purchase maximum
+    LimitsInconsistent = 5,
+    /// The Event Registry reports more active events than events
+    RegistryStatsInconsistent = 6,
+    /// The Event Registry could not be queried
+    RegistryUnavailable = 7,
+}
+
+/// Tax withheld from an organizer's payout and routed to a compliance wallet
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithholdingConfig {
+    /// Share of the organizer's payout in basis points (10000 = 100%)
+    pub bps: u32,
+    pub wallet: Address,
+}
+
+/// White-label partner attached to an event, paid out of the platform fee
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartnerConfig {
+    pub partner: Address,
+    /// Share of the platform fee in basis points (10000 = 100%)
+    pub share_bps: u32,
+}
+
+/// Buyer-signed instruction to pay a ticket's refund to another address
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundRedirect {
+    pub to: Address,
+    /// Ledger timestamp (seconds) from which refunds follow the redirect
+    pub effective_at: u64,
+}
+
+/// Hold placed on a suspicious ticket: while active it can't be refunded,
+/// burned, claimed or have its refund redirected
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentFreeze {
+    pub frozen_by: Address,
+    pub frozen_at: u64,
+    /// Ledger timestamp (seconds) at which the freeze lapses unless escalated
+    pub expires_at: u64,
+    /// Set by the admin to keep the freeze until it is lifted
+    pub escalated: bool,
+}
+
+/// Pending claim-to-wallet for a custodial ticket
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketClaim {
+    /// ed25519 public key; its private half is handed to the user
+    pub claim_key: BytesN<32>,
+    /// After this the organizer may reassign the ticket
+    pub expires_at: u64,
+}
+
+/// Message signed with a claim key; binds the claim to one ticket and wallet
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimPayload {
+    pub payment_id: String,
+    pub new_owner: Address,
+}
+
+/// Incrementally maintained purchase history for a buyer's wallet profile
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BuyerSummary {
+    pub purchase_count: u32,
+    pub total_spent: i128,
+    pub refunds_received: i128,
+    /// Purchases not refunded, i.e. still redeemable
+    pub active_tickets: u32,
+}
+
+/// Lifetime sales totals of one event, for organizer dashboards
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventSales {
+    /// Purchases made, including ones later refunded or burned
+    pub tickets_sold: u32,
+    /// Tickets neither refunded nor burned
+    pub active_tickets: u32,
+    pub gross: i128,
+    pub fees: i128,
+    pub refunded: i128,
+}
+
+/// Per-event accounting bucket for one day (ledger timestamp / 86400)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DailyStats {
+    pub sales_count: u32,
+    pub gross: i128,
+    pub fees: i128,
+    pub refunds_count: u32,
+    pub refunded: i128,
+}
+
+/// Who receives the sub-unit remainder when the platform fee doesn't divide evenly
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingBeneficiary {
+    /// Fee rounds down (default)
+    Organizer,
+    /// Fee rounds up
+    Platform,
+    /// Fee rounds down and the remainder unit is held for a sweep
+    DustBucket,
+}
+
+/// Why an organizer refunded a payment
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RefundReason {
+    Overbooking,
+    CompConversion,
+    Goodwill,
+    Other,
+}
+
+/// Per-event count of organizer refunds for each reason
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundReasonStats {
+    pub overbooking: u32,
+    pub comp_conversion: u32,
+    pub goodwill: u32,
+    pub other: u32,
+}
+
+/// Batch of single-use promo codes for one event, with redemption counters
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromoCampaign {
+    pub event_id: String,
+    /// Discount off the ticket price in basis points (1..10000)
+    pub discount_bps: u32,
+    pub codes_issued: u32,
+    pub redemptions: u32,
+    /// Sum of discounts granted, in token base units
+    pub discount_given: i128,
+}
+
+/// Buyer-granted permission for an operator to trigger purchases on the buyer's
+/// behalf, pulled through the buyer's token allowance to this contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StandingApproval {
+    pub operator: Address,
+    /// Spend cap per period in token base units
+    pub max_per_period: i128,
+    /// Length of one period in seconds
+    pub period_secs: u64,
+    /// Ledger timestamp (seconds) at which the current period began
+    pub period_start: u64,
+    /// Spent so far in the current period, in token base units
+    pub spent_in_period: i128,
+}
+
+/// Fee rebate for early organizers. Events registered by `full_rebate_until`
+/// get `max_rebate_bps` of each sale's platform fee back; the share falls
+/// linearly to zero for events registered up to `ends_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RebateProgram {
+    pub full_rebate_until: u64,
+    pub ends_at: u64,
+    /// Share of the platform fee rebated, in basis points (10000 = 100%)
+    pub max_rebate_bps: u32,
+}
+
+/// Buyer's commitment to questionnaire answers kept off-chain
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuestionnaireAnswers {
+    /// Where the (typically encrypted) answers are stored
+    pub answers_cid: String,
+    /// sha256 of the answers document, salted off-chain against guessing
+    pub answers_hash: BytesN<32>,
+}
+
+/// Limit on how much an address may spend on tickets per period
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendCap {
+    /// Guardian who must approve changes to the cap; the buyer if `None`
+    pub controller: Option<Address>,
+    /// Spend cap per period in token base units
+    pub max_per_period: i128,
+    /// Length of one period in seconds
+    pub period_secs: u64,
+    /// Ledger timestamp (seconds) at which the current period began
+    pub period_start: u64,
+    /// Spent so far in the current period, in token base units
+    pub spent_in_period: i128,
+}
+
+/// Running payment counters maintained incrementally by the payment contract.
+/// Amounts are summed in token base units across all accepted tokens.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PaymentTotals {
+    pub total_payments: u64,
+    pub total_volume: i128,
+    pub total_fees_collected: i128,
+    pub total_refunded: i128,
+}
+
+/// Headline platform numbers combining registry and payment counters
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformStats {
+    pub events_registered: u64,
+    pub active_events: u64,
+    pub total_payments: u64,
+    pub total_volume: i128,
+    pub total_fees_collected: i128,
+    pub total_refunded: i128,
+}
+
+/// One page of payments returned by time-range queries
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentPage {
+    pub payments: Vec<Payment>,
+    /// None when the range is exhausted
+    pub next_cursor: Option<u32>,
+}
+
+/// Operation waiting on the administrator
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PendingAction {
+    /// Purchases are blocked until `on_upgrade` runs this migration
+    Migration(u32),
+    /// Rounding dust held in a token, awaiting `sweep_dust`
+    DustSweep(Address, i128),
+}
+
+/// Resolved configuration of a deployment, for verifying it in one read
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractConfig {
+    pub admin: Address,
+    pub usdc_token: Address,
+    pub platform_wallet: Address,
+    pub event_registry: Address,
+    /// Platform-wide cap (0 = none)
+    pub max_ticket_price: i128,
+    pub purchase_limits: PurchaseLimits,
+    pub rounding_beneficiary: RoundingBeneficiary,
+    pub attestor: Option<BytesN<32>>,
+    pub quote_key: Option<BytesN<32>>,
+    pub guardian: Option<Address>,
+    pub paused: bool,
+    pub schema_version: u32,
+    /// Purchases are blocked while set
+    pub pending_migration: Option<u32>,
+    pub build: BuildInfo,
+}
+
+/// What a hook contract is being notified about
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HookKind {
+    Purchase,
+    Refund,
+}
+
+/// Payload passed to hook contracts after a purchase or refund
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookPayload {
+    pub kind: HookKind,
+    pub payment_id: String,
+    pub event_id: String,
+    pub buyer_address: Address,
+    /// Amount paid, or refunded
+    pub amount: i128,
+}
+
+/// Canonical, XDR-encodable proof of purchase for external integrations
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentReceipt {
+    /// This contract
+    pub issuer: Address,
+    /// Binds the receipt to one network
+    pub network_id: BytesN<32>,
+    pub payment_id: String,
+    pub event_id: String,
+    pub ticket_tier_id: String,
+    pub buyer_address: Address,
+    pub token_address: Address,
+    pub amount: i128,
+    pub platform_fee: i128,
+    pub organizer_amount: i128,
+    pub created_at: u64,
+}
+
+/// Build provenance of the deployed WASM, for matching an instance to source
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildInfo {
+    /// Crate semver
+    pub version: String,
+    /// AGORA_GIT_COMMIT at build time, or "unknown"
+    pub git_commit: String,
+    /// "release" or "debug"
+    pub profile: String,
 }